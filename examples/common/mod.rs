@@ -0,0 +1,209 @@
+//! Camera setups shared by the examples and the headless tests in `tests/headless.rs`, so every setup shown in an example
+//! is also run without a window.
+
+// Each example only uses one of the scenarios.
+#![allow(dead_code)]
+
+use bevy::{prelude::*, render::camera::ScalingMode};
+use smooth_bevy_cameras::prelude::*;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Scenario {
+    LookTransform,
+    Fps,
+    Follow,
+    Observation,
+    Orbit,
+    OrbitOrthographic,
+    PanCam2d,
+    Rts,
+    Unreal,
+}
+
+impl Scenario {
+    pub const ALL: [Scenario; 9] = [
+        Scenario::LookTransform,
+        Scenario::Fps,
+        Scenario::Follow,
+        Scenario::Observation,
+        Scenario::Orbit,
+        Scenario::OrbitOrthographic,
+        Scenario::PanCam2d,
+        Scenario::Rts,
+        Scenario::Unreal,
+    ];
+
+    /// Adds the controller plugin of the scenario. The `LookTransformPlugin` is added by the caller.
+    pub fn add_plugins(self, app: &mut App) {
+        match self {
+            Scenario::LookTransform => app,
+            Scenario::Fps => app.add_plugin(FpsCameraPlugin::default()),
+            Scenario::Follow => app.add_plugin(FollowCameraPlugin::default()),
+            Scenario::Observation => app.add_plugin(ObservationCameraPlugin::default()),
+            Scenario::Orbit | Scenario::OrbitOrthographic => {
+                app.add_plugin(OrbitCameraPlugin::default())
+            }
+            Scenario::PanCam2d => app.add_plugin(PanCam2dPlugin::default()),
+            Scenario::Rts => app.add_plugin(RtsCameraPlugin::default()),
+            Scenario::Unreal => app.add_plugin(UnrealCameraPlugin::default()),
+        };
+    }
+
+    /// Spawns the camera of the scenario, and the entities it's attached to.
+    pub fn spawn_camera(self, commands: &mut Commands) {
+        let eye = Vec3::new(-2.0, 5.0, 5.0);
+        let target = Vec3::ZERO;
+
+        match self {
+            Scenario::LookTransform => {
+                commands
+                    .spawn(LookTransformBundle {
+                        transform: LookTransform {
+                            eye: Vec3::new(-2.0, 2.5, 5.0),
+                            target: Vec3::new(0.0, 0.5, 0.0),
+                            scale: 1.0,
+                            roll: 0.0,
+                        },
+                        smoother: Smoother::new(0.9),
+                    })
+                    .insert(Camera3dBundle {
+                        transform: Transform::from_xyz(-2.0, 2.5, 5.0)
+                            .looking_at(Vec3::new(0.0, 0.5, 0.0), Vec3::Y),
+                        ..default()
+                    });
+            }
+            Scenario::Fps => {
+                commands.spawn(FpsCameraBundle::new(
+                    FpsCameraController::default(),
+                    Camera3dBundle::default(),
+                    eye,
+                    target,
+                ));
+            }
+            Scenario::Follow => {
+                // Follows the cube.
+                let followed = commands
+                    .spawn(SpatialBundle::from_transform(Transform::from_xyz(
+                        0.0, 0.5, 0.0,
+                    )))
+                    .id();
+                commands.spawn(FollowCameraBundle::new(
+                    FollowCameraController::default(),
+                    Camera3dBundle::default(),
+                    followed,
+                    eye,
+                    target,
+                ));
+            }
+            Scenario::Observation => {
+                // A mount point, like the turret of a vehicle.
+                commands
+                    .spawn(SpatialBundle::from_transform(Transform::from_xyz(
+                        0.0, 1.5, 4.0,
+                    )))
+                    .with_children(|mount| {
+                        mount.spawn(ObservationCameraBundle::new(
+                            ObservationCameraController::default(),
+                            Camera3dBundle::default(),
+                        ));
+                    });
+            }
+            Scenario::Orbit => {
+                commands
+                    .spawn(Camera3dBundle::default())
+                    .insert(OrbitCameraBundle::new(
+                        OrbitCameraController::default(),
+                        eye,
+                        target,
+                    ));
+            }
+            Scenario::OrbitOrthographic => {
+                commands
+                    .spawn(Camera3dBundle {
+                        projection: OrthographicProjection {
+                            scale: 5.0,
+                            scaling_mode: ScalingMode::FixedVertical(2.0),
+                            ..Default::default()
+                        }
+                        .into(),
+                        ..Default::default()
+                    })
+                    .insert(OrbitCameraBundle::new(
+                        OrbitCameraController::default(),
+                        eye,
+                        target,
+                    ));
+            }
+            Scenario::PanCam2d => {
+                commands.spawn(PanCam2dBundle::new(
+                    PanCam2dController::default(),
+                    Camera2dBundle::default(),
+                    Vec2::ZERO,
+                ));
+            }
+            Scenario::Rts => {
+                commands.spawn(RtsCameraBundle::new(
+                    RtsCameraController::default(),
+                    Camera3dBundle::default(),
+                    Vec3::new(0.0, 8.0, 6.0),
+                    target,
+                ));
+            }
+            Scenario::Unreal => {
+                commands.spawn(UnrealCameraBundle::new(
+                    UnrealCameraController::default(),
+                    Camera3dBundle::default(),
+                    eye,
+                    target,
+                ));
+            }
+        }
+    }
+}
+
+/// A simple 3D scene: a ground plane, a cube and a light.
+pub fn spawn_scene(
+    commands: &mut Commands,
+    meshes: &mut Assets<Mesh>,
+    materials: &mut Assets<StandardMaterial>,
+) {
+    // plane
+    commands.spawn(PbrBundle {
+        mesh: meshes.add(Mesh::from(shape::Plane { size: 5.0 })),
+        material: materials.add(Color::rgb(0.3, 0.5, 0.3).into()),
+        ..Default::default()
+    });
+
+    // cube
+    commands.spawn(PbrBundle {
+        mesh: meshes.add(Mesh::from(shape::Cube { size: 1.0 })),
+        material: materials.add(Color::rgb(0.8, 0.7, 0.6).into()),
+        transform: Transform::from_xyz(0.0, 0.5, 0.0),
+        ..Default::default()
+    });
+
+    // light
+    commands.spawn(PointLightBundle {
+        transform: Transform::from_xyz(4.0, 8.0, 4.0),
+        ..Default::default()
+    });
+}
+
+/// A windowed app showing `scenario` in the simple 3D scene.
+pub fn example_app(scenario: Scenario) -> App {
+    let mut app = App::new();
+    app.insert_resource(Msaa { samples: 4 })
+        .add_plugins(DefaultPlugins)
+        .add_plugin(LookTransformPlugin);
+    scenario.add_plugins(&mut app);
+    app.add_startup_system(
+        move |mut commands: Commands,
+              mut meshes: ResMut<Assets<Mesh>>,
+              mut materials: ResMut<Assets<StandardMaterial>>| {
+            spawn_scene(&mut commands, &mut meshes, &mut materials);
+            scenario.spawn_camera(&mut commands);
+        },
+    );
+
+    app
+}
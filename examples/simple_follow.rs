@@ -0,0 +1,7 @@
+mod common;
+
+use common::{example_app, Scenario};
+
+fn main() {
+    example_app(Scenario::Follow).run();
+}
@@ -0,0 +1,163 @@
+use crate::{LookTransformSystem, SpeedFov};
+
+use bevy::{app::prelude::*, ecs::prelude::*, prelude::Projection};
+#[cfg(feature = "serialize")]
+use serde::{Deserialize, Serialize};
+
+/// Blends the FOV of `AspectFov` cameras when their aspect ratio changes.
+pub struct AspectFovPlugin;
+
+impl Plugin for AspectFovPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_system(aspect_fov_system.after(LookTransformSystem));
+    }
+}
+
+/// Which extent of the view stays fixed when the aspect ratio of a perspective camera changes.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(Deserialize, Serialize))]
+pub enum FovScaling {
+    /// The vertical FOV is fixed, so wider viewports see more horizontally. This is bevy's default behavior.
+    #[default]
+    HorPlus,
+    /// The horizontal FOV is fixed, so wider viewports see less vertically.
+    VertMinus,
+    /// The diagonal FOV is fixed, a compromise between the two.
+    Diagonal,
+}
+
+/// Adapts the FOV of a perspective camera to its aspect ratio according to `scaling`. The camera has a vertical FOV of
+/// `reference_fov` at `reference_aspect`.
+///
+/// Changes of the aspect ratio (e.g. when resizing the window) blend into the new FOV with `smoothing_weight`, like the
+/// `Smoother`'s `lag_weight`.
+#[derive(Clone, Component, Copy, Debug)]
+#[cfg_attr(feature = "serialize", derive(Deserialize, Serialize))]
+pub struct AspectFov {
+    pub scaling: FovScaling,
+    pub reference_fov: f32,
+    pub reference_aspect: f32,
+    pub smoothing_weight: f32,
+}
+
+impl Default for AspectFov {
+    fn default() -> Self {
+        Self {
+            scaling: FovScaling::HorPlus,
+            reference_fov: std::f32::consts::FRAC_PI_4,
+            reference_aspect: 16.0 / 9.0,
+            smoothing_weight: 0.8,
+        }
+    }
+}
+
+impl AspectFov {
+    /// The vertical FOV for a viewport with `aspect` ratio.
+    pub fn vertical_fov(&self, aspect: f32) -> f32 {
+        let half_tan = (0.5 * self.reference_fov).tan();
+        let half_tan = match self.scaling {
+            FovScaling::HorPlus => half_tan,
+            FovScaling::VertMinus => half_tan * self.reference_aspect / aspect,
+            FovScaling::Diagonal => {
+                half_tan * (1.0 + self.reference_aspect * self.reference_aspect).sqrt()
+                    / (1.0 + aspect * aspect).sqrt()
+            }
+        };
+
+        2.0 * half_tan.atan()
+    }
+}
+
+/// Cameras with a `SpeedFov` apply their `AspectFov` themselves.
+pub(crate) fn aspect_fov_system(
+    mut cameras: Query<(&AspectFov, &mut Projection), Without<SpeedFov>>,
+) {
+    for (aspect_fov, mut projection) in cameras.iter_mut() {
+        let perspective = match projection.as_ref() {
+            Projection::Perspective(perspective) => perspective,
+            Projection::Orthographic(_) => continue,
+        };
+        let target = aspect_fov.vertical_fov(perspective.aspect_ratio);
+        let weight = aspect_fov.smoothing_weight;
+        let mut fov = perspective.fov * weight + target * (1.0 - weight);
+        if (fov - target).abs() < 1e-4 {
+            fov = target;
+        }
+        if fov == perspective.fov {
+            continue;
+        }
+
+        if let Projection::Perspective(perspective) = projection.as_mut() {
+            perspective.fov = fov;
+        }
+    }
+}
+
+// ████████╗███████╗███████╗████████╗
+// ╚══██╔══╝██╔════╝██╔════╝╚══██╔══╝
+//    ██║   █████╗  ███████╗   ██║
+//    ██║   ██╔══╝  ╚════██║   ██║
+//    ██║   ███████╗███████║   ██║
+//    ╚═╝   ╚══════╝╚══════╝   ╚═╝
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::assert_relative_eq;
+
+    fn horizontal_fov(vertical_fov: f32, aspect: f32) -> f32 {
+        2.0 * ((0.5 * vertical_fov).tan() * aspect).atan()
+    }
+
+    fn aspect_fov(scaling: FovScaling) -> AspectFov {
+        AspectFov {
+            scaling,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_reference_aspect_keeps_reference_fov() {
+        for scaling in [
+            FovScaling::HorPlus,
+            FovScaling::VertMinus,
+            FovScaling::Diagonal,
+        ] {
+            let aspect_fov = aspect_fov(scaling);
+            assert_relative_eq!(
+                aspect_fov.vertical_fov(aspect_fov.reference_aspect),
+                aspect_fov.reference_fov,
+                epsilon = 1e-6
+            );
+        }
+    }
+
+    #[test]
+    fn test_hor_plus_keeps_vertical_fov() {
+        let aspect_fov = aspect_fov(FovScaling::HorPlus);
+        assert_relative_eq!(
+            aspect_fov.vertical_fov(21.0 / 9.0),
+            aspect_fov.reference_fov
+        );
+    }
+
+    #[test]
+    fn test_vert_minus_keeps_horizontal_fov() {
+        let aspect_fov = aspect_fov(FovScaling::VertMinus);
+        let ultrawide = 21.0 / 9.0;
+        assert_relative_eq!(
+            horizontal_fov(aspect_fov.vertical_fov(ultrawide), ultrawide),
+            horizontal_fov(aspect_fov.reference_fov, aspect_fov.reference_aspect),
+            epsilon = 1e-6
+        );
+    }
+
+    #[test]
+    fn test_diagonal_is_between_hor_plus_and_vert_minus() {
+        let ultrawide = 21.0 / 9.0;
+        let diagonal = aspect_fov(FovScaling::Diagonal).vertical_fov(ultrawide);
+        assert!(diagonal < aspect_fov(FovScaling::HorPlus).vertical_fov(ultrawide));
+        assert!(diagonal > aspect_fov(FovScaling::VertMinus).vertical_fov(ultrawide));
+    }
+}
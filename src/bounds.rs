@@ -0,0 +1,113 @@
+use crate::LookTransform;
+
+use bevy::{ecs::prelude::*, math::prelude::*};
+
+/// The region a `CameraBounds` keeps points in.
+pub enum BoundsRegion {
+    Aabb {
+        min: Vec3,
+        max: Vec3,
+    },
+    /// Returns the closest point of the region to a point, e.g. to keep the camera inside a polygonal map outline.
+    Custom(Box<dyn Fn(Vec3) -> Vec3 + Send + Sync>),
+}
+
+impl BoundsRegion {
+    pub fn clamp(&self, point: Vec3) -> Vec3 {
+        match self {
+            BoundsRegion::Aabb { min, max } => point.clamp(*min, *max),
+            BoundsRegion::Custom(clamp) => clamp(point),
+        }
+    }
+}
+
+/// Keeps the `LookTransform` on the same entity inside a region, e.g. the playable area of a map. Applied by the
+/// `LookTransformSystem` before smoothing, so controllers stop at the edge instead of drifting out of view.
+#[derive(Component)]
+pub struct CameraBounds {
+    pub region: BoundsRegion,
+    /// Keeps the target inside the region. The eye is moved along with it, so panning stops at the edge without turning
+    /// the view.
+    pub clamp_target: bool,
+    /// Keeps the eye inside the region. Only the eye moves, so the camera keeps looking at its target.
+    pub clamp_eye: bool,
+}
+
+impl CameraBounds {
+    /// Keeps the target inside the box from `min` to `max`.
+    pub fn aabb(min: Vec3, max: Vec3) -> Self {
+        Self {
+            region: BoundsRegion::Aabb {
+                min: min.min(max),
+                max: min.max(max),
+            },
+            clamp_target: true,
+            clamp_eye: false,
+        }
+    }
+
+    /// Keeps the target inside the region whose closest point to a point is returned by `clamp`.
+    pub fn custom(clamp: impl Fn(Vec3) -> Vec3 + Send + Sync + 'static) -> Self {
+        Self {
+            region: BoundsRegion::Custom(Box::new(clamp)),
+            clamp_target: true,
+            clamp_eye: false,
+        }
+    }
+
+    pub fn with_clamp_eye(mut self, clamp_eye: bool) -> Self {
+        self.clamp_eye = clamp_eye;
+        self
+    }
+
+    pub fn with_clamp_target(mut self, clamp_target: bool) -> Self {
+        self.clamp_target = clamp_target;
+        self
+    }
+
+    /// Returns `transform` with its eye and/or target moved into the region.
+    pub fn apply(&self, mut transform: LookTransform) -> LookTransform {
+        if self.clamp_target {
+            let correction = self.region.clamp(transform.target) - transform.target;
+            transform += correction;
+        }
+        if self.clamp_eye {
+            transform.eye = self.region.clamp(transform.eye);
+        }
+
+        transform
+    }
+}
+
+// ████████╗███████╗███████╗████████╗
+// ╚══██╔══╝██╔════╝██╔════╝╚══██╔══╝
+//    ██║   █████╗  ███████╗   ██║
+//    ██║   ██╔══╝  ╚════██║   ██║
+//    ██║   ███████╗███████║   ██║
+//    ╚═╝   ╚══════╝╚══════╝   ╚═╝
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_clamped_target_carries_eye() {
+        let bounds = CameraBounds::aabb(Vec3::splat(-10.0), Vec3::splat(10.0));
+        let transform = LookTransform::new(Vec3::new(12.0, 5.0, 20.0), Vec3::new(12.0, 0.0, 15.0));
+        let clamped = bounds.apply(transform);
+        assert_eq!(clamped.target, Vec3::new(10.0, 0.0, 10.0));
+        assert_eq!(clamped.eye, Vec3::new(10.0, 5.0, 15.0));
+        assert_eq!(clamped.look_direction(), transform.look_direction());
+    }
+
+    #[test]
+    fn test_clamped_eye_keeps_target() {
+        let bounds = CameraBounds::custom(|p| Vec3::new(p.x, p.y.min(3.0), p.z))
+            .with_clamp_target(false)
+            .with_clamp_eye(true);
+        let transform = LookTransform::new(Vec3::new(0.0, 5.0, 5.0), Vec3::ZERO);
+        let clamped = bounds.apply(transform);
+        assert_eq!(clamped.eye, Vec3::new(0.0, 3.0, 5.0));
+        assert_eq!(clamped.target, Vec3::ZERO);
+    }
+}
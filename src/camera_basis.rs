@@ -0,0 +1,58 @@
+use crate::{CoordinateSystem, LookTransformSystem};
+
+use bevy::{app::prelude::*, ecs::prelude::*, math::prelude::*, transform::components::Transform};
+
+/// Updates the `CameraBasis` of cameras after smoothing.
+pub struct CameraBasisPlugin;
+
+impl Plugin for CameraBasisPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<CoordinateSystem>()
+            .add_system(camera_basis_system.after(LookTransformSystem));
+    }
+}
+
+/// The yaw-only basis of the smoothed camera on the ground plane of the `CoordinateSystem`, for camera-relative character
+/// movement. Add this component to a camera and it is updated every frame after smoothing.
+#[derive(Clone, Component, Copy, Debug)]
+pub struct CameraBasis {
+    /// The horizontal direction the camera faces.
+    pub forward: Vec3,
+    /// The horizontal direction to the right of the camera.
+    pub right: Vec3,
+}
+
+impl Default for CameraBasis {
+    fn default() -> Self {
+        Self {
+            forward: Vec3::NEG_Z,
+            right: Vec3::X,
+        }
+    }
+}
+
+impl CameraBasis {
+    /// Converts a 2D movement input (x = right, y = forward) into a world-space direction on the ground plane.
+    pub fn relative_movement(&self, input: Vec2) -> Vec3 {
+        input.x * self.right + input.y * self.forward
+    }
+}
+
+pub(crate) fn camera_basis_system(
+    coordinates: Res<CoordinateSystem>,
+    mut cameras: Query<(&Transform, &mut CameraBasis)>,
+) {
+    for (transform, mut basis) in cameras.iter_mut() {
+        let forward = transform.forward();
+        let world_up = coordinates.up();
+        // When looking straight up or down, the forward direction has no horizontal component, so fall back to up/down.
+        let flat_forward = coordinates.horizontal(forward).try_normalize().or_else(|| {
+            let up = transform.up();
+            (-forward.dot(world_up).signum() * coordinates.horizontal(up)).try_normalize()
+        });
+        if let Some(forward) = flat_forward {
+            basis.forward = forward;
+            basis.right = forward.cross(world_up);
+        }
+    }
+}
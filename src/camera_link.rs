@@ -0,0 +1,199 @@
+use crate::{LookTransform, LookTransformSystem};
+
+use bevy::{app::prelude::*, ecs::prelude::*};
+
+/// Mirrors moves between the cameras of each `CameraLinkGroup`.
+pub struct CameraLinkPlugin;
+
+impl Plugin for CameraLinkPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_system(camera_link_system.before(LookTransformSystem));
+    }
+}
+
+/// What a camera of a `CameraLinkGroup` mirrors from the camera being manipulated.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct LinkChannels {
+    /// The distance from the eye to the target, and the orthographic scale.
+    pub zoom: bool,
+    /// The look direction and roll, turning the eye around the camera's own target.
+    pub rotation: bool,
+    /// The target, moving the eye along with it.
+    pub target: bool,
+}
+
+impl LinkChannels {
+    pub const ZOOM: Self = Self {
+        zoom: true,
+        rotation: false,
+        target: false,
+    };
+    pub const ROTATION: Self = Self {
+        zoom: false,
+        rotation: true,
+        target: false,
+    };
+    pub const POSE: Self = Self {
+        zoom: true,
+        rotation: true,
+        target: true,
+    };
+
+    /// Returns `follower` with the channels of `leader`.
+    pub fn apply(&self, leader: LookTransform, follower: LookTransform) -> LookTransform {
+        let mut linked = follower;
+        if self.target {
+            linked += leader.target - linked.target;
+        }
+        if self.rotation {
+            if let Some(direction) = leader.look_direction() {
+                linked.eye = linked.target - linked.radius() * direction;
+            }
+            linked.roll = leader.roll;
+        }
+        if self.zoom {
+            if let Some(direction) = linked.look_direction() {
+                linked.eye = linked.target - leader.radius() * direction;
+            }
+            linked.scale = leader.scale;
+        }
+
+        linked
+    }
+}
+
+/// Links the `LookTransform` of this camera to the other cameras with the same `id`, e.g. for comparison viewers showing
+/// two scenes side by side. When one camera of the group moves, the others mirror its `channels` (each camera picks what
+/// it mirrors), so their controllers carry on from the mirrored pose.
+#[derive(Clone, Copy, Component, Debug)]
+pub struct CameraLinkGroup {
+    pub id: u32,
+    pub channels: LinkChannels,
+    /// The transform after the last sync, to find the camera that moved since.
+    synced: Option<LookTransform>,
+}
+
+impl CameraLinkGroup {
+    pub fn new(id: u32, channels: LinkChannels) -> Self {
+        Self {
+            id,
+            channels,
+            synced: None,
+        }
+    }
+}
+
+/// Whether `transform` moved from `synced`, ignoring the rounding of controllers that recompute the eye every frame.
+fn moved(synced: &LookTransform, transform: &LookTransform) -> bool {
+    let epsilon = 1e-5 * (1.0 + transform.radius());
+    !transform.eye.abs_diff_eq(synced.eye, epsilon)
+        || !transform.target.abs_diff_eq(synced.target, epsilon)
+        || (transform.scale - synced.scale).abs() > epsilon
+        || (transform.roll - synced.roll).abs() > 1e-5
+}
+
+pub(crate) fn camera_link_system(
+    mut cameras: Query<(Entity, &mut CameraLinkGroup, &mut LookTransform)>,
+) {
+    // The first camera of each group that moved since the last sync leads it.
+    let mut leaders: Vec<(u32, Entity, LookTransform)> = Vec::new();
+    for (entity, link, transform) in cameras.iter() {
+        if leaders.iter().any(|(id, _, _)| *id == link.id) {
+            continue;
+        }
+        if link
+            .synced
+            .map_or(false, |synced| moved(&synced, transform))
+        {
+            leaders.push((link.id, entity, *transform));
+        }
+    }
+
+    for (entity, mut link, mut transform) in cameras.iter_mut() {
+        if let Some((_, leader, pose)) = leaders.iter().find(|(id, _, _)| *id == link.id) {
+            if *leader != entity {
+                let linked = link.channels.apply(*pose, *transform);
+                // Only write when moved, so the transform isn't marked as changed every frame.
+                if linked != *transform {
+                    *transform = linked;
+                }
+            }
+        }
+        if link.synced != Some(*transform) {
+            link.synced = Some(*transform);
+        }
+    }
+}
+
+// ████████╗███████╗███████╗████████╗
+// ╚══██╔══╝██╔════╝██╔════╝╚══██╔══╝
+//    ██║   █████╗  ███████╗   ██║
+//    ██║   ██╔══╝  ╚════██║   ██║
+//    ██║   ███████╗███████║   ██║
+//    ╚═╝   ╚══════╝╚══════╝   ╚═╝
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use bevy::math::prelude::*;
+
+    #[test]
+    fn test_zoom_keeps_follower_direction() {
+        let leader = LookTransform::new(Vec3::new(0.0, 0.0, 10.0), Vec3::ZERO);
+        let follower = LookTransform::new(Vec3::new(3.0, 0.0, 0.0), Vec3::new(1.0, 0.0, 0.0));
+        let linked = LinkChannels::ZOOM.apply(leader, follower);
+        assert_eq!(linked.target, follower.target);
+        assert!(linked.eye.abs_diff_eq(Vec3::new(11.0, 0.0, 0.0), 1e-5));
+    }
+
+    #[test]
+    fn test_rotation_keeps_follower_distance() {
+        let leader = LookTransform::new(Vec3::new(0.0, 10.0, 0.1), Vec3::ZERO);
+        let follower = LookTransform::new(Vec3::new(0.0, 0.0, 2.0), Vec3::new(5.0, 0.0, 0.0));
+        let linked = LinkChannels::ROTATION.apply(leader, follower);
+        assert_eq!(linked.target, follower.target);
+        assert!((linked.radius() - follower.radius()).abs() < 1e-4);
+        assert!(linked
+            .look_direction()
+            .unwrap()
+            .abs_diff_eq(leader.look_direction().unwrap(), 1e-5));
+    }
+
+    #[test]
+    fn test_pose_copies_leader() {
+        let leader = LookTransform {
+            eye: Vec3::new(1.0, 2.0, 3.0),
+            target: Vec3::new(0.0, 1.0, 0.0),
+            scale: 4.0,
+            roll: 0.5,
+        };
+        let follower = LookTransform::new(Vec3::new(-5.0, 0.0, 0.0), Vec3::ZERO);
+        let linked = LinkChannels::POSE.apply(leader, follower);
+        assert!(linked.eye.abs_diff_eq(leader.eye, 1e-5));
+        assert_eq!(linked.target, leader.target);
+        assert_eq!(linked.scale, leader.scale);
+        assert_eq!(linked.roll, leader.roll);
+    }
+
+    #[test]
+    fn test_roll_moves_the_group() {
+        let mut app = App::new();
+        app.add_system(camera_link_system);
+        let transform = LookTransform::new(Vec3::new(0.0, 0.0, 5.0), Vec3::ZERO);
+        let leader = app
+            .world
+            .spawn((CameraLinkGroup::new(0, LinkChannels::ROTATION), transform))
+            .id();
+        let follower = app
+            .world
+            .spawn((CameraLinkGroup::new(0, LinkChannels::ROTATION), transform))
+            .id();
+        app.update();
+
+        app.world.get_mut::<LookTransform>(leader).unwrap().roll = 0.25;
+        app.update();
+
+        assert_eq!(app.world.get::<LookTransform>(follower).unwrap().roll, 0.25);
+    }
+}
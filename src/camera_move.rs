@@ -0,0 +1,109 @@
+use crate::{LookTransform, LookTransformSystem, Smoother};
+
+use bevy::{app::prelude::*, ecs::prelude::*};
+use std::{
+    future::Future,
+    pin::Pin,
+    sync::{Arc, Mutex},
+    task::{Context, Poll, Waker},
+};
+
+/// Completes the `CameraMoveHandle`s of `CameraMoveWaiters` once their cameras settle.
+pub struct CameraMovePlugin;
+
+impl Plugin for CameraMovePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_system(camera_move_system.after(LookTransformSystem));
+    }
+}
+
+/// Resolves once a camera has finished a programmatic move, i.e. its smoothed transform has settled on its `LookTransform`.
+/// It can be polled with `is_done` or awaited from async tasks.
+#[derive(Clone, Debug, Default)]
+pub struct CameraMoveHandle {
+    state: Arc<Mutex<CameraMoveState>>,
+}
+
+#[derive(Debug, Default)]
+struct CameraMoveState {
+    done: bool,
+    waker: Option<Waker>,
+}
+
+impl CameraMoveHandle {
+    pub fn is_done(&self) -> bool {
+        self.state.lock().unwrap().done
+    }
+
+    pub(crate) fn complete(&self) {
+        let mut state = self.state.lock().unwrap();
+        state.done = true;
+        if let Some(waker) = state.waker.take() {
+            waker.wake();
+        }
+    }
+}
+
+impl Future for CameraMoveHandle {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        let mut state = self.state.lock().unwrap();
+        if state.done {
+            Poll::Ready(())
+        } else {
+            state.waker = Some(cx.waker().clone());
+            Poll::Pending
+        }
+    }
+}
+
+/// Hands out `CameraMoveHandle`s for the camera on the same entity. Request a handle right after sending the controller
+/// events (or modifying the `LookTransform`) that start a move.
+#[derive(Component, Default)]
+pub struct CameraMoveWaiters {
+    pending: Vec<(CameraMoveHandle, u32)>,
+}
+
+impl CameraMoveWaiters {
+    pub fn wait(&mut self) -> CameraMoveHandle {
+        let handle = CameraMoveHandle::default();
+        self.pending.push((handle.clone(), 0));
+
+        handle
+    }
+}
+
+/// How close (in world units) the smoothed eye and target must be to the `LookTransform` for a move to be finished.
+const SETTLE_DISTANCE: f32 = 1e-3;
+
+/// The move may only start once the controller has processed its events, which can take a frame.
+const MIN_FRAMES: u32 = 2;
+
+pub(crate) fn camera_move_system(
+    mut cameras: Query<(&LookTransform, Option<&Smoother>, &mut CameraMoveWaiters)>,
+) {
+    for (look_transform, smoother, mut waiters) in cameras.iter_mut() {
+        if waiters.pending.is_empty() {
+            continue;
+        }
+
+        let settled = match smoother.and_then(Smoother::smoothed_transform) {
+            Some(smoothed) => {
+                smoothed.eye.distance(look_transform.eye) < SETTLE_DISTANCE
+                    && smoothed.target.distance(look_transform.target) < SETTLE_DISTANCE
+            }
+            None => true,
+        };
+
+        waiters.pending.retain_mut(|(handle, frames)| {
+            *frames += 1;
+            if settled && *frames >= MIN_FRAMES {
+                handle.complete();
+                false
+            } else {
+                true
+            }
+        });
+    }
+}
@@ -0,0 +1,397 @@
+use crate::{controllers::CameraController, Easing, LookTransform, LookTransformSystem};
+
+use bevy::{app::prelude::*, ecs::prelude::*, math::prelude::*, time::Time};
+
+/// Plays `CameraPath`s with `CameraPathPlayer`s.
+pub struct CameraPathPlugin;
+
+impl Plugin for CameraPathPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<PathPlaybackEvent>()
+            .add_event::<CameraPathEvent>()
+            .add_system(camera_path_system.before(LookTransformSystem));
+    }
+}
+
+/// The eye and target of a camera at a point in time.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct CameraKeyframe {
+    pub time: f32,
+    pub eye: Vec3,
+    pub target: Vec3,
+    /// Easing of the segment from this keyframe to the next.
+    pub easing: Easing,
+}
+
+impl CameraKeyframe {
+    /// A keyframe with linear easing.
+    pub fn new(time: f32, eye: Vec3, target: Vec3) -> Self {
+        Self {
+            time,
+            eye,
+            target,
+            easing: Easing::Linear,
+        }
+    }
+}
+
+/// How a `CameraPath` moves between its keyframes.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum PathInterpolation {
+    /// Straight lines, turning sharply at the keyframes.
+    #[default]
+    Linear,
+    /// A Catmull-Rom spline through the keyframes, so the camera doesn't change direction abruptly at them. The
+    /// tangents take the keyframe times into account, so the speed is continuous too.
+    CatmullRom,
+}
+
+/// A camera animation as keyframes sorted by time.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct CameraPath {
+    pub interpolation: PathInterpolation,
+    keyframes: Vec<CameraKeyframe>,
+}
+
+impl CameraPath {
+    pub fn new(mut keyframes: Vec<CameraKeyframe>) -> Self {
+        keyframes.sort_by(|a, b| a.time.partial_cmp(&b.time).unwrap());
+
+        Self {
+            interpolation: PathInterpolation::Linear,
+            keyframes,
+        }
+    }
+
+    pub fn with_interpolation(mut self, interpolation: PathInterpolation) -> Self {
+        self.interpolation = interpolation;
+        self
+    }
+
+    pub fn keyframes(&self) -> &[CameraKeyframe] {
+        &self.keyframes
+    }
+
+    /// The time of the last keyframe.
+    pub fn duration(&self) -> f32 {
+        self.keyframes.last().map_or(0.0, |k| k.time)
+    }
+
+    /// Returns the (eye, target) at `time`, interpolating between keyframes.
+    pub fn sample(&self, time: f32) -> Option<(Vec3, Vec3)> {
+        let first = self.keyframes.first()?;
+        if time <= first.time {
+            return Some((first.eye, first.target));
+        }
+        for i in 1..self.keyframes.len() {
+            let (a, b) = (self.keyframes[i - 1], self.keyframes[i]);
+            if time <= b.time {
+                let t = if b.time > a.time {
+                    a.easing.apply((time - a.time) / (b.time - a.time))
+                } else {
+                    1.0
+                };
+                return Some(match self.interpolation {
+                    PathInterpolation::Linear => (a.eye.lerp(b.eye, t), a.target.lerp(b.target, t)),
+                    PathInterpolation::CatmullRom => (
+                        self.spline_point(i, t, |k| k.eye),
+                        self.spline_point(i, t, |k| k.target),
+                    ),
+                });
+            }
+        }
+        let last = self.keyframes.last()?;
+
+        Some((last.eye, last.target))
+    }
+
+    /// The point at `t` in `[0, 1]` of the spline segment ending at keyframe `end`, for the point picked by `point`.
+    fn spline_point(&self, end: usize, t: f32, point: impl Fn(&CameraKeyframe) -> Vec3) -> Vec3 {
+        let keyframes = &self.keyframes;
+        let (a, b) = (&keyframes[end - 1], &keyframes[end]);
+        // The velocity at a keyframe, from its neighbors (or the segment itself at the ends of the path).
+        let velocity = |i: usize| {
+            let before = &keyframes[i.saturating_sub(1)];
+            let after = &keyframes[(i + 1).min(keyframes.len() - 1)];
+            let duration = after.time - before.time;
+            if duration > 0.0 {
+                (point(after) - point(before)) / duration
+            } else {
+                Vec3::ZERO
+            }
+        };
+        let duration = b.time - a.time;
+        let (start_tangent, end_tangent) = (duration * velocity(end - 1), duration * velocity(end));
+
+        // Cubic Hermite basis.
+        let (t2, t3) = (t * t, t * t * t);
+        (2.0 * t3 - 3.0 * t2 + 1.0) * point(a)
+            + (t3 - 2.0 * t2 + t) * start_tangent
+            + (-2.0 * t3 + 3.0 * t2) * point(b)
+            + (t3 - t2) * end_tangent
+    }
+}
+
+/// Plays a `CameraPath` on the `LookTransform` of the same entity, with controls for an external timeline UI.
+///
+/// While playing, the camera's controller is disabled, and it is enabled again when playback finishes or is paused, so
+/// the controller carries on from where the path left the camera. If the player is removed while playing, enable the
+/// controller yourself.
+#[derive(Component)]
+pub struct CameraPathPlayer {
+    pub path: CameraPath,
+    /// Playback speed; negative values play in reverse.
+    pub speed: f32,
+    /// Disables the camera's controller during playback. When unset, disable the controller yourself so it doesn't
+    /// fight over the `LookTransform`.
+    pub suspend_controller: bool,
+    time: f32,
+    playing: bool,
+    suspended: bool,
+}
+
+impl CameraPathPlayer {
+    /// A paused player at the start of `path`.
+    pub fn new(path: CameraPath) -> Self {
+        Self {
+            path,
+            speed: 1.0,
+            suspend_controller: true,
+            time: 0.0,
+            playing: false,
+            suspended: false,
+        }
+    }
+
+    pub fn play(&mut self) {
+        self.playing = true;
+    }
+
+    pub fn pause(&mut self) {
+        self.playing = false;
+    }
+
+    pub fn is_playing(&self) -> bool {
+        self.playing
+    }
+
+    /// Jumps to `time`, clamped to the duration of the path.
+    pub fn seek(&mut self, time: f32) {
+        self.time = time.clamp(0.0, self.path.duration());
+    }
+
+    /// The current playback time.
+    pub fn time(&self) -> f32 {
+        self.time
+    }
+
+    /// Flips the playback direction.
+    pub fn reverse(&mut self) {
+        self.speed = -self.speed;
+    }
+
+    /// The playback progress in `[0, 1]`.
+    pub fn progress(&self) -> f32 {
+        let duration = self.path.duration();
+        if duration > 0.0 {
+            self.time / duration
+        } else {
+            1.0
+        }
+    }
+
+    fn apply(&mut self, command: PathPlaybackCommand) {
+        match command {
+            PathPlaybackCommand::Play => self.play(),
+            PathPlaybackCommand::Pause => self.pause(),
+            PathPlaybackCommand::Seek(time) => self.seek(time),
+            PathPlaybackCommand::SetSpeed(speed) => self.speed = speed,
+            PathPlaybackCommand::Reverse => self.reverse(),
+        }
+    }
+
+    /// Whether `hand_off` would disable or enable a controller that is currently `enabled`.
+    fn needs_hand_off(&self, enabled: bool) -> bool {
+        (self.playing && self.suspend_controller && enabled) || (!self.playing && self.suspended)
+    }
+
+    /// Disables `controller` when playback starts, and enables it again when playback stops.
+    fn hand_off(&mut self, controller: &mut impl CameraController) {
+        if self.needs_hand_off(controller.enabled()) {
+            controller.set_enabled(!self.playing);
+            self.suspended = self.playing;
+        }
+    }
+}
+
+/// Playback controls for a `CameraPathPlayer`, as an alternative to calling its methods.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum PathPlaybackCommand {
+    Play,
+    Pause,
+    Seek(f32),
+    SetSpeed(f32),
+    Reverse,
+}
+
+/// Sends a `PathPlaybackCommand` to the player on `camera`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PathPlaybackEvent {
+    pub camera: Entity,
+    pub command: PathPlaybackCommand,
+}
+
+/// Progress of a `CameraPathPlayer`, for timeline UIs.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum CameraPathEvent {
+    /// The playback time changed, by playing or seeking.
+    Progress {
+        camera: Entity,
+        time: f32,
+        progress: f32,
+    },
+    /// Playback reached the end (or the start, when playing in reverse) and paused.
+    Finished { camera: Entity },
+}
+
+/// Hands control of the camera back and forth between the `CameraPathPlayer` and the controller `C`.
+pub(crate) fn camera_path_handoff_system<C: CameraController>(
+    mut cameras: Query<(&mut CameraPathPlayer, &mut C)>,
+) {
+    for (mut player, mut controller) in cameras.iter_mut() {
+        if player.needs_hand_off(controller.enabled()) {
+            // Not a playback change, so don't trigger the player's change detection (which samples the path again).
+            player.bypass_change_detection().hand_off(&mut *controller);
+        }
+    }
+}
+
+pub(crate) fn camera_path_system(
+    time: Res<Time>,
+    mut playback_events: EventReader<PathPlaybackEvent>,
+    mut path_events: EventWriter<CameraPathEvent>,
+    mut cameras: Query<(Entity, &mut CameraPathPlayer, &mut LookTransform)>,
+) {
+    for event in playback_events.iter() {
+        if let Ok((_, mut player, _)) = cameras.get_mut(event.camera) {
+            player.apply(event.command);
+        }
+    }
+
+    for (camera, mut player, mut transform) in cameras.iter_mut() {
+        let previous_time = player.time;
+        if player.playing {
+            let duration = player.path.duration();
+            player.time = (player.time + player.speed * time.delta_seconds()).clamp(0.0, duration);
+
+            let at_end = if player.speed >= 0.0 {
+                player.time >= duration
+            } else {
+                player.time <= 0.0
+            };
+            if at_end {
+                player.playing = false;
+                path_events.send(CameraPathEvent::Finished { camera });
+            }
+        }
+
+        if player.time != previous_time || player.is_changed() {
+            path_events.send(CameraPathEvent::Progress {
+                camera,
+                time: player.time,
+                progress: player.progress(),
+            });
+            if let Some((eye, target)) = player.path.sample(player.time) {
+                transform.eye = eye;
+                transform.target = target;
+            }
+        }
+    }
+}
+
+// ████████╗███████╗███████╗████████╗
+// ╚══██╔══╝██╔════╝██╔════╝╚══██╔══╝
+//    ██║   █████╗  ███████╗   ██║
+//    ██║   ██╔══╝  ╚════██║   ██║
+//    ██║   ███████╗███████║   ██║
+//    ╚═╝   ╚══════╝╚══════╝   ╚═╝
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::controllers::orbit::OrbitCameraController;
+
+    use approx::assert_relative_eq;
+
+    fn keyframe(time: f32, x: f32) -> CameraKeyframe {
+        CameraKeyframe::new(time, Vec3::new(x, 0.0, 0.0), Vec3::ZERO)
+    }
+
+    #[test]
+    fn test_sample_interpolates_between_keyframes() {
+        let path = CameraPath::new(vec![keyframe(2.0, 10.0), keyframe(0.0, 0.0)]);
+        assert_relative_eq!(path.duration(), 2.0);
+        assert_relative_eq!(path.sample(1.0).unwrap().0.x, 5.0);
+        assert_relative_eq!(path.sample(-1.0).unwrap().0.x, 0.0);
+        assert_relative_eq!(path.sample(3.0).unwrap().0.x, 10.0);
+    }
+
+    #[test]
+    fn test_catmull_rom_passes_keyframes_smoothly() {
+        let path = CameraPath::new(vec![
+            keyframe(0.0, 0.0),
+            CameraKeyframe::new(1.0, Vec3::new(1.0, 2.0, 0.0), Vec3::ZERO),
+            keyframe(3.0, 4.0),
+        ])
+        .with_interpolation(PathInterpolation::CatmullRom);
+        for keyframe in path.keyframes() {
+            assert!(path
+                .sample(keyframe.time)
+                .unwrap()
+                .0
+                .abs_diff_eq(keyframe.eye, 1e-5));
+        }
+
+        // The velocity is continuous at the middle keyframe.
+        let h = 1e-3;
+        let eye = |time: f32| path.sample(time).unwrap().0;
+        let before = (eye(1.0) - eye(1.0 - h)) / h;
+        let after = (eye(1.0 + h) - eye(1.0)) / h;
+        assert!(before.abs_diff_eq(after, 0.05));
+    }
+
+    #[test]
+    fn test_controller_is_handed_back_after_playback() {
+        let mut player = CameraPathPlayer::new(CameraPath::new(vec![
+            keyframe(0.0, 0.0),
+            keyframe(2.0, 10.0),
+        ]));
+        let mut controller = OrbitCameraController::default();
+        player.play();
+        player.hand_off(&mut controller);
+        assert!(!controller.enabled);
+
+        player.pause();
+        player.hand_off(&mut controller);
+        assert!(controller.enabled);
+
+        // A controller disabled by the app stays disabled.
+        controller.enabled = false;
+        player.play();
+        player.hand_off(&mut controller);
+        player.pause();
+        player.hand_off(&mut controller);
+        assert!(!controller.enabled);
+    }
+
+    #[test]
+    fn test_seek_clamps_to_duration() {
+        let mut player = CameraPathPlayer::new(CameraPath::new(vec![
+            keyframe(0.0, 0.0),
+            keyframe(2.0, 10.0),
+        ]));
+        player.seek(5.0);
+        assert_relative_eq!(player.time(), 2.0);
+        assert_relative_eq!(player.progress(), 1.0);
+    }
+}
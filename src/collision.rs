@@ -0,0 +1,171 @@
+use bevy::{
+    ecs::prelude::*,
+    math::prelude::*,
+    reflect::{FromReflect, Reflect},
+};
+#[cfg(feature = "serialize")]
+use serde::{Deserialize, Serialize};
+
+/// Where a swept sphere first touches an obstacle.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct CollisionHit {
+    /// How far along the displacement (in `[0, 1]`) the sphere touches the obstacle.
+    pub fraction: f32,
+    /// The surface normal of the obstacle at the contact.
+    pub normal: Vec3,
+}
+
+/// The physics hook used by the free-cam controllers to collide with the world. This crate doesn't depend on a physics
+/// engine, so the app provides a function that sweeps a sphere of `radius` from `start` by `displacement` against colliders
+/// matching `mask` and returns the first hit.
+///
+/// Without this resource, cameras move freely.
+#[derive(Resource)]
+pub struct CameraCollisionHook {
+    #[allow(clippy::type_complexity)]
+    sweep: Box<dyn Fn(Vec3, Vec3, f32, u32) -> Option<CollisionHit> + Send + Sync>,
+}
+
+impl CameraCollisionHook {
+    pub fn new(
+        sweep: impl Fn(Vec3, Vec3, f32, u32) -> Option<CollisionHit> + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            sweep: Box::new(sweep),
+        }
+    }
+
+    pub fn sweep(
+        &self,
+        start: Vec3,
+        displacement: Vec3,
+        radius: f32,
+        mask: u32,
+    ) -> Option<CollisionHit> {
+        (self.sweep)(start, displacement, radius, mask)
+    }
+}
+
+/// Per-controller collision settings. Collision requires a `CameraCollisionHook` resource.
+#[derive(Clone, Copy, Debug, FromReflect, PartialEq, Reflect)]
+#[cfg_attr(feature = "serialize", derive(Deserialize, Serialize))]
+pub struct CollisionSettings {
+    /// Radius of the sphere swept around the eye.
+    pub radius: f32,
+    /// Which colliders block the camera, interpreted by the `CameraCollisionHook`.
+    pub mask: u32,
+}
+
+impl Default for CollisionSettings {
+    fn default() -> Self {
+        Self {
+            radius: 0.2,
+            mask: u32::MAX,
+        }
+    }
+}
+
+/// Returns the eye moved by `displacement`, sliding along obstacles (by projecting the rest of the motion onto the surface)
+/// instead of passing through or stopping dead.
+pub(crate) fn move_eye(
+    hook: Option<&CameraCollisionHook>,
+    settings: Option<CollisionSettings>,
+    eye: Vec3,
+    displacement: Vec3,
+) -> Vec3 {
+    let (hook, settings) = match (hook, settings) {
+        (Some(hook), Some(settings)) => (hook, settings),
+        _ => return eye + displacement,
+    };
+
+    // A few slides are enough to handle corners.
+    const MAX_SLIDES: usize = 3;
+
+    let mut position = eye;
+    let mut remaining = displacement;
+    for _ in 0..MAX_SLIDES {
+        if remaining.length_squared() < 1e-12 {
+            break;
+        }
+        match hook.sweep(position, remaining, settings.radius, settings.mask) {
+            Some(hit) => {
+                let fraction = hit.fraction.clamp(0.0, 1.0);
+                position += fraction * remaining;
+                remaining *= 1.0 - fraction;
+                remaining -= hit.normal * remaining.dot(hit.normal);
+            }
+            None => {
+                return position + remaining;
+            }
+        }
+    }
+
+    position
+}
+
+// ████████╗███████╗███████╗████████╗
+// ╚══██╔══╝██╔════╝██╔════╝╚══██╔══╝
+//    ██║   █████╗  ███████╗   ██║
+//    ██║   ██╔══╝  ╚════██║   ██║
+//    ██║   ███████╗███████║   ██║
+//    ╚═╝   ╚══════╝╚══════╝   ╚═╝
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Sweeps against half-spaces given as (normal, offset), with the free side at `normal.dot(p) >= offset`.
+    fn planes_hook(planes: &'static [(Vec3, f32)]) -> CameraCollisionHook {
+        CameraCollisionHook::new(move |start, displacement, radius, _mask| {
+            planes
+                .iter()
+                .filter_map(|&(normal, offset)| {
+                    let approach = displacement.dot(normal);
+                    if approach >= 0.0 {
+                        return None;
+                    }
+                    let fraction = (offset + radius - start.dot(normal)) / approach;
+                    (0.0..=1.0)
+                        .contains(&fraction)
+                        .then_some(CollisionHit { fraction, normal })
+                })
+                .min_by(|a, b| a.fraction.total_cmp(&b.fraction))
+        })
+    }
+
+    #[test]
+    fn test_moves_freely_without_hook() {
+        let eye = Vec3::new(0.0, 1.0, 0.0);
+        let displacement = Vec3::new(2.0, -2.0, 0.0);
+        assert_eq!(
+            move_eye(None, Some(CollisionSettings::default()), eye, displacement),
+            eye + displacement
+        );
+    }
+
+    #[test]
+    fn test_slides_along_plane() {
+        let hook = planes_hook(&[(Vec3::Y, 0.0)]);
+        let eye = move_eye(
+            Some(&hook),
+            Some(CollisionSettings::default()),
+            Vec3::new(0.0, 1.0, 0.0),
+            Vec3::new(2.0, -2.0, 0.0),
+        );
+        // The sphere stops on the floor and keeps the horizontal part of the motion.
+        assert!(eye.abs_diff_eq(Vec3::new(2.0, 0.2, 0.0), 1e-5));
+    }
+
+    #[test]
+    fn test_slides_into_corner() {
+        let hook = planes_hook(&[(Vec3::Y, 0.0), (Vec3::NEG_X, -1.5)]);
+        let eye = move_eye(
+            Some(&hook),
+            Some(CollisionSettings::default()),
+            Vec3::new(0.0, 1.0, 0.0),
+            Vec3::new(2.0, -2.0, 1.0),
+        );
+        // Blocked by the floor and then the wall, the eye slides along the edge between them.
+        assert!(eye.abs_diff_eq(Vec3::new(1.3, 0.2, 1.0), 1e-5));
+    }
+}
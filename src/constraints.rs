@@ -0,0 +1,166 @@
+use crate::{CameraFeedbackEvent, CameraModifierAnchor, LookTransform, LookTransformSystem};
+
+use bevy::{
+    app::prelude::*, ecs::prelude::*, math::prelude::*, transform::components::GlobalTransform,
+};
+
+/// Restricts `LookCone` cameras to their cones. The `ObservationCameraPlugin` adds it if needed.
+pub struct LookConePlugin;
+
+impl Plugin for LookConePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<CameraFeedbackEvent>().add_system(
+            look_cone_system
+                .label(CameraModifierAnchor::Constraints)
+                .before(LookTransformSystem),
+        );
+    }
+}
+
+/// The direction a `LookCone` is centered on.
+#[derive(Clone, Copy, Debug)]
+pub enum ConeReference {
+    /// A fixed world-space direction.
+    Direction(Vec3),
+    /// The forward (-Z) direction of an entity, e.g. a vehicle the camera sits in.
+    EntityForward(Entity),
+}
+
+/// Restricts the look direction (from eye to target) of a `LookTransform` to a cone around a reference direction, like a
+/// cockpit camera that can only turn so far. The eye is kept fixed and the target is moved.
+///
+/// Within `soft_edge` radians of the limit, outward motion is progressively damped so the limit is not hit abruptly.
+#[derive(Clone, Component, Copy, Debug)]
+pub struct LookCone {
+    pub reference: ConeReference,
+    pub half_angle: f32,
+    pub soft_edge: f32,
+    last_angle: Option<f32>,
+}
+
+impl LookCone {
+    pub fn new(reference: ConeReference, half_angle: f32, soft_edge: f32) -> Self {
+        Self {
+            reference,
+            half_angle,
+            soft_edge,
+            last_angle: None,
+        }
+    }
+
+    /// Returns the constrained direction for `direction`, relative to the (unit) `reference` direction, and whether the limit
+    /// was newly hit.
+    fn constrain(&mut self, reference: Vec3, direction: Vec3) -> (Vec3, bool) {
+        let angle = reference.angle_between(direction);
+        let last_angle = self.last_angle.unwrap_or(angle);
+
+        let mut new_angle = angle;
+        if angle > last_angle && self.soft_edge > 0.0 {
+            let resistance = ((self.half_angle - last_angle) / self.soft_edge).clamp(0.0, 1.0);
+            new_angle = last_angle + (angle - last_angle) * resistance;
+        }
+        new_angle = new_angle.min(self.half_angle);
+        self.last_angle = Some(new_angle);
+        let hit_limit = new_angle >= self.half_angle && last_angle < self.half_angle;
+
+        if new_angle == angle {
+            return (direction, hit_limit);
+        }
+        let constrained = match reference.cross(direction).try_normalize() {
+            Some(axis) => Quat::from_axis_angle(axis, new_angle) * reference,
+            // Pointing exactly opposite the reference; any axis will do.
+            None => {
+                Quat::from_axis_angle(reference.any_orthonormal_vector(), new_angle) * reference
+            }
+        };
+
+        (constrained, hit_limit)
+    }
+}
+
+pub(crate) fn look_cone_system(
+    mut feedback_events: EventWriter<CameraFeedbackEvent>,
+    mut cameras: Query<(Entity, &mut LookCone, &mut LookTransform)>,
+    references: Query<&GlobalTransform>,
+) {
+    for (camera, mut cone, mut transform) in cameras.iter_mut() {
+        let reference = match cone.reference {
+            ConeReference::Direction(direction) => direction.try_normalize(),
+            ConeReference::EntityForward(entity) => references
+                .get(entity)
+                .ok()
+                .and_then(|t| t.forward().try_normalize()),
+        };
+        let (reference, direction) = match (reference, transform.look_direction()) {
+            (Some(reference), Some(direction)) => (reference, direction),
+            _ => continue,
+        };
+
+        let (constrained, hit_limit) = cone.constrain(reference, direction);
+        if hit_limit {
+            feedback_events.send(CameraFeedbackEvent::ConstraintHit { camera });
+        }
+        if constrained != direction {
+            transform.target = transform.eye + transform.radius() * constrained;
+        }
+    }
+}
+
+// ████████╗███████╗███████╗████████╗
+// ╚══██╔══╝██╔════╝██╔════╝╚══██╔══╝
+//    ██║   █████╗  ███████╗   ██║
+//    ██║   ██╔══╝  ╚════██║   ██║
+//    ██║   ███████╗███████║   ██║
+//    ╚═╝   ╚══════╝╚══════╝   ╚═╝
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::assert_relative_eq;
+
+    fn direction_at(angle: f32) -> Vec3 {
+        Quat::from_rotation_y(angle) * Vec3::NEG_Z
+    }
+
+    #[test]
+    fn test_direction_inside_cone_is_kept() {
+        let mut cone = LookCone::new(ConeReference::Direction(Vec3::NEG_Z), 0.5, 0.0);
+        let direction = direction_at(0.3);
+        assert_eq!(cone.constrain(Vec3::NEG_Z, direction), (direction, false));
+    }
+
+    #[test]
+    fn test_direction_is_clamped_to_cone() {
+        let mut cone = LookCone::new(ConeReference::Direction(Vec3::NEG_Z), 0.5, 0.0);
+        cone.constrain(Vec3::NEG_Z, direction_at(0.3));
+        let (constrained, hit_limit) = cone.constrain(Vec3::NEG_Z, direction_at(1.0));
+        assert!(hit_limit);
+        assert!(constrained.abs_diff_eq(direction_at(0.5), 1e-5));
+
+        // Staying at the limit doesn't report it again.
+        let (_, hit_limit) = cone.constrain(Vec3::NEG_Z, direction_at(1.0));
+        assert!(!hit_limit);
+
+        // Pointing opposite the reference still gives a direction on the cone.
+        let mut cone = LookCone::new(ConeReference::Direction(Vec3::NEG_Z), 0.5, 0.0);
+        let (constrained, _) = cone.constrain(Vec3::NEG_Z, Vec3::Z);
+        assert_relative_eq!(Vec3::NEG_Z.angle_between(constrained), 0.5, epsilon = 1e-5);
+    }
+
+    #[test]
+    fn test_soft_edge_damps_outward_motion() {
+        let mut cone = LookCone::new(ConeReference::Direction(Vec3::NEG_Z), 0.5, 0.2);
+        cone.constrain(Vec3::NEG_Z, direction_at(0.4));
+
+        // Halfway into the soft edge, only half of the motion is applied.
+        let (constrained, hit_limit) = cone.constrain(Vec3::NEG_Z, direction_at(0.45));
+        assert!(!hit_limit);
+        let angle = Vec3::NEG_Z.angle_between(constrained);
+        assert_relative_eq!(angle, 0.425, epsilon = 1e-4);
+
+        // Moving back in is not damped.
+        let direction = direction_at(0.1);
+        assert_eq!(cone.constrain(Vec3::NEG_Z, direction).0, direction);
+    }
+}
@@ -1,3 +1,35 @@
+use crate::{
+    controllers::{
+        blockers::CameraInputBlockers,
+        precision::{PrecisionMode, PrecisionScaled},
+    },
+    CoordinateSystem, LookAngles, LookTransform,
+};
+
+use bevy::{
+    ecs::{
+        component::Component,
+        entity::Entity,
+        event::{EventReader, Events},
+        schedule::ShouldRun,
+        system::Res,
+    },
+    input::{
+        gamepad::{
+            Gamepad, GamepadAxis, GamepadAxisType, GamepadButton, GamepadButtonType, Gamepads,
+        },
+        keyboard::KeyCode,
+        mouse::{MouseButton, MouseMotion, MouseWheel},
+        touch::Touches,
+        Axis, Input,
+    },
+    math::prelude::*,
+    reflect::{FromReflect, Reflect},
+};
+#[cfg(feature = "serialize")]
+use serde::{Deserialize, Serialize};
+use std::hash::Hash;
+
 #[macro_use]
 mod macros {
     #[macro_export]
@@ -12,6 +44,662 @@ mod macros {
     });
 }
 
+pub mod blockers;
+pub mod bookmarks;
+pub mod calibration;
+pub mod defaults;
+pub mod devices;
+pub mod follow;
 pub mod fps;
+pub mod info;
+pub mod momentum;
+pub mod observation;
 pub mod orbit;
+pub mod pan_cam_2d;
+pub mod precision;
+pub mod prediction;
+pub mod quad_view;
+pub mod rts;
+#[cfg(feature = "settings_asset")]
+pub mod settings;
+pub mod state;
+pub mod tween;
 pub mod unreal;
+
+/// Common interface of the built-in controllers.
+pub trait CameraController: Component {
+    fn enabled(&self) -> bool;
+
+    fn set_enabled(&mut self, enabled: bool);
+}
+
+/// A `ControlEvent` of one of the controllers addressed to a specific camera, for apps with several enabled cameras of the
+/// same kind, like split-screen. Untargeted `ControlEvent`s go to the first enabled camera.
+///
+/// The default input maps send targeted events to the camera that accepts their `InputDevices`.
+#[derive(Clone, Debug)]
+pub struct TargetedControlEvent<E> {
+    pub camera: Entity,
+    pub event: E,
+}
+
+/// Pairs this frame's control events with the camera they are for, sending untargeted events to `first_enabled`. The
+/// events are scaled down while `precision` is active.
+pub(crate) fn routed_control_events<E: Clone + PrecisionScaled + Send + Sync + 'static>(
+    events: &mut EventReader<E>,
+    targeted_events: &mut EventReader<TargetedControlEvent<E>>,
+    first_enabled: Option<Entity>,
+    precision: Option<&PrecisionMode>,
+) -> Vec<(Entity, E)> {
+    let mut routed: Vec<(Entity, E)> = events
+        .iter()
+        .filter_map(|event| Some((first_enabled?, event.clone())))
+        .collect();
+    routed.extend(
+        targeted_events
+            .iter()
+            .map(|targeted| (targeted.camera, targeted.event.clone())),
+    );
+    if let Some(factor) = PrecisionMode::active_factor(precision) {
+        for (_, event) in &mut routed {
+            *event = event.scaled(factor);
+        }
+    }
+
+    routed
+}
+
+/// Run criteria of the default input maps, so the controllers can also be driven by `ControlEvent`s in a headless app
+/// (e.g. a server replaying cinematics) that doesn't add bevy's `InputPlugin`. Input is also skipped while
+/// `CameraInputBlockers` has a reason pushed, and with the `egui` feature, while egui wants the pointer or keyboard (e.g.
+/// while a slider is dragged or a text field is focused).
+pub(crate) fn input_available(
+    blockers: Option<Res<CameraInputBlockers>>,
+    keyboard: Option<Res<Input<KeyCode>>>,
+    mouse_buttons: Option<Res<Input<MouseButton>>>,
+    mouse_motion: Option<Res<Events<MouseMotion>>>,
+    mouse_wheel: Option<Res<Events<MouseWheel>>>,
+    #[cfg(feature = "egui")] mut egui: Option<bevy::ecs::system::ResMut<bevy_egui::EguiContext>>,
+) -> ShouldRun {
+    if blockers.map_or(false, |b| b.is_blocked()) {
+        return ShouldRun::No;
+    }
+    #[cfg(feature = "egui")]
+    if let Some(ctx) = egui
+        .as_mut()
+        .and_then(|egui| egui.try_ctx_for_window_mut(bevy::window::WindowId::primary()))
+    {
+        if ctx.wants_pointer_input() || ctx.wants_keyboard_input() {
+            return ShouldRun::No;
+        }
+    }
+
+    if keyboard.is_some()
+        && mouse_buttons.is_some()
+        && mouse_motion.is_some()
+        && mouse_wheel.is_some()
+    {
+        ShouldRun::Yes
+    } else {
+        ShouldRun::No
+    }
+}
+
+/// Run criteria of the gamepad input maps. Like `input_available`, input is skipped while `CameraInputBlockers` has a
+/// reason pushed.
+pub(crate) fn gamepad_available(
+    blockers: Option<Res<CameraInputBlockers>>,
+    gamepads: Option<Res<Gamepads>>,
+    axes: Option<Res<Axis<GamepadAxis>>>,
+    button_axes: Option<Res<Axis<GamepadButton>>>,
+) -> ShouldRun {
+    if blockers.map_or(false, |b| b.is_blocked()) {
+        return ShouldRun::No;
+    }
+
+    let connected = gamepads.map_or(false, |g| g.iter().next().is_some());
+    if connected && axes.is_some() && button_axes.is_some() {
+        ShouldRun::Yes
+    } else {
+        ShouldRun::No
+    }
+}
+
+/// Run criteria of the touch input maps. Like `input_available`, input is skipped while `CameraInputBlockers` has a reason
+/// pushed.
+pub(crate) fn touch_available(
+    blockers: Option<Res<CameraInputBlockers>>,
+    touches: Option<Res<Touches>>,
+) -> ShouldRun {
+    if blockers.map_or(false, |b| b.is_blocked()) {
+        return ShouldRun::No;
+    }
+
+    if touches.is_some() {
+        ShouldRun::Yes
+    } else {
+        ShouldRun::No
+    }
+}
+
+/// Drops the cursor motion entirely if it is shorter than `deadband` pixels.
+pub(crate) fn apply_deadband(cursor_delta: Vec2, deadband: f32) -> Vec2 {
+    if cursor_delta.length() < deadband {
+        Vec2::ZERO
+    } else {
+        cursor_delta
+    }
+}
+
+/// Returns the `stored` look angles of a controller if they still match `look_vector`, or recomputes them from
+/// `look_vector` if the `LookTransform` or the `CoordinateSystem` was changed externally. Recomputing the angles every
+/// frame accumulates drift.
+pub(crate) fn synced_look_angles(
+    stored: Option<LookAngles>,
+    look_vector: Vec3,
+    coordinates: &CoordinateSystem,
+) -> LookAngles {
+    match stored {
+        Some(angles)
+            if angles.coordinate_system() == *coordinates
+                && look_vector.abs_diff_eq(angles.unit_vector(), 1e-4) =>
+        {
+            angles
+        }
+        _ => LookAngles::from_vector_in(look_vector, *coordinates),
+    }
+}
+
+/// Points a first-person `transform` in the direction of `angles`, keeping its eye and radius.
+pub(crate) fn set_first_person_angles(transform: &mut LookTransform, angles: LookAngles) {
+    transform.target = transform.eye + transform.radius() * angles.unit_vector();
+}
+
+/// How a drag gesture is started and stopped by its button.
+#[derive(Clone, Copy, Debug, Default, Eq, FromReflect, PartialEq, Reflect)]
+#[cfg_attr(feature = "serialize", derive(Deserialize, Serialize))]
+pub enum InteractionMode {
+    /// The gesture is active for as long as the button is held down.
+    #[default]
+    Hold,
+    /// The gesture starts when the button is clicked and stops when it is clicked again.
+    Toggle,
+}
+
+impl InteractionMode {
+    /// Returns whether the gesture bound to `button` is active. `toggled` is the per-gesture state used in `Toggle` mode.
+    pub fn is_active<T>(self, input: &Input<T>, button: T, toggled: &mut bool) -> bool
+    where
+        T: Copy + Eq + Hash + Send + Sync + 'static,
+    {
+        match self {
+            InteractionMode::Hold => input.pressed(button),
+            InteractionMode::Toggle => {
+                if input.just_pressed(button) {
+                    *toggled = !*toggled;
+                }
+                *toggled
+            }
+        }
+    }
+}
+
+/// Input filtering for pen and tablet devices, which report hover motion and small jitter as mouse events.
+#[derive(Clone, Copy, Debug, FromReflect, PartialEq, Reflect)]
+#[cfg_attr(feature = "serialize", derive(Deserialize, Serialize))]
+pub struct PenInput {
+    /// The mouse button reported by the pen's barrel button. The Unreal controller treats it like the middle mouse
+    /// button; the orbit controller only uses the jitter filtering, and keeps the button's own binding.
+    pub barrel_button: MouseButton,
+    /// Cursor motion shorter than this many pixels per frame is ignored.
+    pub jitter_threshold: f32,
+}
+
+impl Default for PenInput {
+    fn default() -> Self {
+        Self {
+            barrel_button: MouseButton::Right,
+            jitter_threshold: 1.5,
+        }
+    }
+}
+
+impl PenInput {
+    /// Drops the cursor motion entirely if it is below the jitter threshold.
+    pub fn filter_motion(&self, cursor_delta: Vec2) -> Vec2 {
+        apply_deadband(cursor_delta, self.jitter_threshold)
+    }
+}
+
+/// Constrains a drag to its dominant axis while the modifier is held, like shift-dragging in image editors. The axis is
+/// chosen once the drag has moved `threshold` pixels, and kept until the drag ends.
+#[derive(Clone, Copy, Debug, FromReflect, PartialEq, Reflect)]
+#[cfg_attr(feature = "serialize", derive(Deserialize, Serialize))]
+pub struct AxisLock {
+    pub modifier: KeyCode,
+    pub threshold: f32,
+}
+
+impl Default for AxisLock {
+    fn default() -> Self {
+        Self {
+            modifier: KeyCode::LShift,
+            threshold: 4.0,
+        }
+    }
+}
+
+/// The axis chosen by an `AxisLock` for the current drag, kept by the input maps.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct AxisLockState {
+    /// Motion held back until the axis is chosen.
+    pending: Vec2,
+    /// The unit vector of the chosen axis.
+    axis: Option<Vec2>,
+}
+
+impl AxisLockState {
+    /// Returns `cursor_delta` constrained to the locked axis while `locking` (the modifier is held).
+    pub fn filter(&mut self, settings: &AxisLock, locking: bool, cursor_delta: Vec2) -> Vec2 {
+        if !locking {
+            self.reset();
+            return cursor_delta;
+        }
+        if let Some(axis) = self.axis {
+            return cursor_delta * axis;
+        }
+
+        self.pending += cursor_delta;
+        if self.pending.length() < settings.threshold {
+            return Vec2::ZERO;
+        }
+        let axis = if self.pending.x.abs() >= self.pending.y.abs() {
+            Vec2::X
+        } else {
+            Vec2::Y
+        };
+        self.axis = Some(axis);
+
+        std::mem::take(&mut self.pending) * axis
+    }
+
+    /// Forgets the axis, e.g. when the drag ends.
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+}
+
+/// Gamepad bindings of a controller: the right stick looks or orbits, the left stick translates and the triggers zoom (or
+/// move vertically, for first-person controllers). Rates are per second at full deflection, so they don't depend on the
+/// frame rate.
+#[derive(Clone, Copy, Debug, FromReflect, PartialEq, Reflect)]
+#[cfg_attr(feature = "serialize", derive(Deserialize, Serialize))]
+pub struct GamepadInput {
+    /// Stick and trigger deflections below this fraction are ignored. The remaining range is rescaled to start at zero,
+    /// so small deflections still move the camera slowly.
+    pub deadzone: f32,
+    /// Radians per second of (yaw, pitch) from the right stick.
+    pub look_sensitivity: Vec2,
+    /// Translation speed from the left stick, in the controller's translation units per second.
+    pub translate_sensitivity: f32,
+    /// Zoom (or vertical movement) speed from the triggers, in the controller's zoom units per second.
+    pub zoom_sensitivity: f32,
+}
+
+impl Default for GamepadInput {
+    fn default() -> Self {
+        Self {
+            deadzone: 0.15,
+            look_sensitivity: Vec2::splat(2.0),
+            translate_sensitivity: 5.0,
+            zoom_sensitivity: 1.5,
+        }
+    }
+}
+
+/// The deflections of a gamepad's sticks and triggers, after the deadzone.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct GamepadSticks {
+    pub left_stick: Vec2,
+    pub right_stick: Vec2,
+    pub left_trigger: f32,
+    pub right_trigger: f32,
+}
+
+impl GamepadSticks {
+    pub fn read(
+        gamepad: Gamepad,
+        deadzone: f32,
+        axes: &Axis<GamepadAxis>,
+        button_axes: &Axis<GamepadButton>,
+    ) -> Self {
+        let axis = |axis_type| {
+            axes.get(GamepadAxis::new(gamepad, axis_type))
+                .unwrap_or(0.0)
+        };
+        let trigger = |button_type| {
+            let value = button_axes
+                .get(GamepadButton::new(gamepad, button_type))
+                .unwrap_or(0.0);
+            apply_deadzone(Vec2::new(value, 0.0), deadzone).x
+        };
+
+        Self {
+            left_stick: apply_deadzone(
+                Vec2::new(
+                    axis(GamepadAxisType::LeftStickX),
+                    axis(GamepadAxisType::LeftStickY),
+                ),
+                deadzone,
+            ),
+            right_stick: apply_deadzone(
+                Vec2::new(
+                    axis(GamepadAxisType::RightStickX),
+                    axis(GamepadAxisType::RightStickY),
+                ),
+                deadzone,
+            ),
+            left_trigger: trigger(GamepadButtonType::LeftTrigger2),
+            right_trigger: trigger(GamepadButtonType::RightTrigger2),
+        }
+    }
+
+    pub fn is_idle(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+/// Applies a radial deadzone to a stick, rescaling the rest of its range to start at zero.
+fn apply_deadzone(stick: Vec2, deadzone: f32) -> Vec2 {
+    let length = stick.length();
+    if length <= deadzone || deadzone >= 1.0 {
+        return Vec2::ZERO;
+    }
+    let rescaled = ((length - deadzone) / (1.0 - deadzone)).min(1.0);
+
+    stick * (rescaled / length)
+}
+
+/// Touch gestures of a controller: dragging one finger rotates, dragging two fingers pans and pinching zooms. Finger motion
+/// is scaled by the controller's mouse sensitivities, so a one-finger drag turns as far as the same drag with the mouse.
+///
+/// Touch input goes to the camera that accepts the keyboard and mouse.
+#[derive(Clone, Copy, Debug, FromReflect, PartialEq, Reflect)]
+#[cfg_attr(feature = "serialize", derive(Deserialize, Serialize))]
+pub struct TouchControls {
+    /// Scales one-finger drags relative to mouse drags.
+    pub rotate_scale: f32,
+    /// Scales two-finger drags relative to mouse drags.
+    pub pan_scale: f32,
+    /// At `1.0`, pinching zooms by the change of the distance between the fingers, so the content follows them.
+    pub pinch_zoom_scale: f32,
+}
+
+impl Default for TouchControls {
+    fn default() -> Self {
+        Self {
+            rotate_scale: 1.0,
+            pan_scale: 1.0,
+            pinch_zoom_scale: 1.0,
+        }
+    }
+}
+
+/// What the fingers touching the screen did this frame.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum TouchGesture {
+    /// No fingers, or more than two, which are left to the app.
+    None,
+    /// One finger moved by this many pixels.
+    Drag(Vec2),
+    /// Two fingers: the motion of their midpoint, and the distance between them before and after this frame.
+    TwoFinger {
+        pan: Vec2,
+        previous_distance: f32,
+        distance: f32,
+    },
+}
+
+impl TouchGesture {
+    pub fn from_touches(touches: &Touches) -> Self {
+        let mut fingers = touches.iter();
+        match (fingers.next(), fingers.next(), fingers.next()) {
+            (Some(finger), None, _) => Self::Drag(finger.position() - finger.previous_position()),
+            (Some(a), Some(b), None) => Self::two_finger(
+                [a.previous_position(), b.previous_position()],
+                [a.position(), b.position()],
+            ),
+            _ => Self::None,
+        }
+    }
+
+    fn two_finger(previous: [Vec2; 2], current: [Vec2; 2]) -> Self {
+        Self::TwoFinger {
+            pan: 0.5 * ((current[0] + current[1]) - (previous[0] + previous[1])),
+            previous_distance: previous[0].distance(previous[1]),
+            distance: current[0].distance(current[1]),
+        }
+    }
+
+    /// The zoom factor of the eye-target distance for a pinch, scaled by `scale`. Spreading the fingers zooms in.
+    pub fn pinch_zoom(previous_distance: f32, distance: f32, scale: f32) -> f32 {
+        if previous_distance > 0.0 && distance > 0.0 {
+            (previous_distance / distance).powf(scale)
+        } else {
+            1.0
+        }
+    }
+}
+
+/// A combination of mouse buttons held together, e.g. left + right for CAD-style chords. A chord is only held while exactly
+/// its buttons are pressed, so a left + right chord and a left chord can be bound to different gestures.
+#[derive(Clone, Copy, Debug, Default, Eq, FromReflect, Hash, PartialEq, Reflect)]
+#[cfg_attr(feature = "serialize", derive(Deserialize, Serialize))]
+pub struct MouseChord {
+    pub left: bool,
+    pub right: bool,
+    pub middle: bool,
+}
+
+impl MouseChord {
+    pub const NONE: Self = Self::new(false, false, false);
+    pub const LEFT: Self = Self::new(true, false, false);
+    pub const RIGHT: Self = Self::new(false, true, false);
+    pub const MIDDLE: Self = Self::new(false, false, true);
+    pub const LEFT_RIGHT: Self = Self::new(true, true, false);
+
+    pub const fn new(left: bool, right: bool, middle: bool) -> Self {
+        Self {
+            left,
+            right,
+            middle,
+        }
+    }
+
+    /// The buttons currently pressed.
+    pub fn pressed(buttons: &Input<MouseButton>) -> Self {
+        Self::new(
+            buttons.pressed(MouseButton::Left),
+            buttons.pressed(MouseButton::Right),
+            buttons.pressed(MouseButton::Middle),
+        )
+    }
+
+    pub fn is_empty(self) -> bool {
+        self == Self::NONE
+    }
+
+    /// Whether every button of `other` is also part of `self`.
+    pub fn contains(self, other: Self) -> bool {
+        (self.left || !other.left) && (self.right || !other.right) && (self.middle || !other.middle)
+    }
+
+    fn button_count(self) -> usize {
+        [self.left, self.right, self.middle]
+            .iter()
+            .filter(|b| **b)
+            .count()
+    }
+}
+
+/// Debounces chords of several buttons. Buttons of a chord are rarely released on the same frame, so once a chord of
+/// several buttons loses one of them, no chord is reported until all buttons are released. Otherwise releasing a left +
+/// right zoom chord would briefly trigger the gesture bound to the button released last.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct ChordTracker {
+    active: MouseChord,
+    releasing: bool,
+}
+
+impl ChordTracker {
+    /// Returns the chord that is held given the `pressed` buttons.
+    pub fn update(&mut self, pressed: MouseChord) -> MouseChord {
+        if pressed.is_empty() {
+            self.releasing = false;
+        } else if !self.releasing
+            && self.active.button_count() > 1
+            && self.active.contains(pressed)
+            && self.active != pressed
+        {
+            self.releasing = true;
+        }
+        self.active = if self.releasing {
+            MouseChord::NONE
+        } else {
+            pressed
+        };
+
+        self.active
+    }
+}
+
+/// Mouse drag gestures bound to chords, e.g. the classic CAD bindings of `Default`: left drag orbits, middle drag pans and
+/// left + right drag zooms.
+#[derive(Clone, Copy, Debug, FromReflect, PartialEq, Reflect)]
+#[cfg_attr(feature = "serialize", derive(Deserialize, Serialize))]
+pub struct MouseChordBindings {
+    pub orbit: MouseChord,
+    pub pan: MouseChord,
+    pub zoom: MouseChord,
+    /// Fraction of the distance zoomed per pixel of vertical drag. Dragging up zooms in.
+    pub zoom_sensitivity: f32,
+}
+
+impl Default for MouseChordBindings {
+    fn default() -> Self {
+        Self {
+            orbit: MouseChord::LEFT,
+            pan: MouseChord::MIDDLE,
+            zoom: MouseChord::LEFT_RIGHT,
+            zoom_sensitivity: 0.005,
+        }
+    }
+}
+
+impl MouseChordBindings {
+    /// The gestures bound to the held chord, in order (orbit, pan, zoom).
+    pub fn active_gestures(&self, held: MouseChord) -> (bool, bool, bool) {
+        if held.is_empty() {
+            return (false, false, false);
+        }
+
+        (held == self.orbit, held == self.pan, held == self.zoom)
+    }
+}
+
+// ████████╗███████╗███████╗████████╗
+// ╚══██╔══╝██╔════╝██╔════╝╚══██╔══╝
+//    ██║   █████╗  ███████╗   ██║
+//    ██║   ██╔══╝  ╚════██║   ██║
+//    ██║   ███████╗███████║   ██║
+//    ╚═╝   ╚══════╝╚══════╝   ╚═╝
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_axis_lock_keeps_dominant_axis() {
+        let settings = AxisLock::default();
+        let mut state = AxisLockState::default();
+        // Held back until the threshold is reached, then released along the dominant axis.
+        assert_eq!(
+            state.filter(&settings, true, Vec2::new(2.0, 1.0)),
+            Vec2::ZERO
+        );
+        assert_eq!(
+            state.filter(&settings, true, Vec2::new(2.0, -1.0)),
+            Vec2::new(4.0, 0.0)
+        );
+        assert_eq!(
+            state.filter(&settings, true, Vec2::new(1.0, 10.0)),
+            Vec2::new(1.0, 0.0)
+        );
+
+        // Releasing the modifier unlocks the drag.
+        assert_eq!(
+            state.filter(&settings, false, Vec2::new(1.0, 10.0)),
+            Vec2::new(1.0, 10.0)
+        );
+    }
+
+    #[test]
+    fn test_chords_are_matched_exactly() {
+        let bindings = MouseChordBindings::default();
+        assert_eq!(
+            bindings.active_gestures(MouseChord::LEFT),
+            (true, false, false)
+        );
+        assert_eq!(
+            bindings.active_gestures(MouseChord::LEFT_RIGHT),
+            (false, false, true)
+        );
+        assert_eq!(
+            bindings.active_gestures(MouseChord::RIGHT),
+            (false, false, false)
+        );
+        assert_eq!(
+            bindings.active_gestures(MouseChord::NONE),
+            (false, false, false)
+        );
+    }
+
+    #[test]
+    fn test_releasing_a_chord_does_not_trigger_its_buttons() {
+        let mut tracker = ChordTracker::default();
+        assert_eq!(tracker.update(MouseChord::LEFT), MouseChord::LEFT);
+        assert_eq!(
+            tracker.update(MouseChord::LEFT_RIGHT),
+            MouseChord::LEFT_RIGHT
+        );
+        assert_eq!(tracker.update(MouseChord::RIGHT), MouseChord::NONE);
+        assert_eq!(tracker.update(MouseChord::RIGHT), MouseChord::NONE);
+        assert_eq!(tracker.update(MouseChord::NONE), MouseChord::NONE);
+        assert_eq!(tracker.update(MouseChord::RIGHT), MouseChord::RIGHT);
+    }
+
+    #[test]
+    fn test_deadzone_rescales_remaining_range() {
+        assert_eq!(apply_deadzone(Vec2::new(0.1, 0.0), 0.2), Vec2::ZERO);
+        assert!(apply_deadzone(Vec2::new(0.6, 0.0), 0.2).abs_diff_eq(Vec2::new(0.5, 0.0), 1e-6));
+        assert!(apply_deadzone(Vec2::new(0.0, -1.0), 0.2).abs_diff_eq(-Vec2::Y, 1e-6));
+    }
+
+    #[test]
+    fn test_two_finger_gesture() {
+        let gesture = TouchGesture::two_finger(
+            [Vec2::new(0.0, 0.0), Vec2::new(10.0, 0.0)],
+            [Vec2::new(2.0, 4.0), Vec2::new(22.0, 4.0)],
+        );
+        assert_eq!(
+            gesture,
+            TouchGesture::TwoFinger {
+                pan: Vec2::new(7.0, 4.0),
+                previous_distance: 10.0,
+                distance: 20.0,
+            }
+        );
+        assert_eq!(TouchGesture::pinch_zoom(10.0, 20.0, 1.0), 0.5);
+        assert_eq!(TouchGesture::pinch_zoom(0.0, 20.0, 1.0), 1.0);
+    }
+}
@@ -0,0 +1,85 @@
+use bevy::ecs::prelude::*;
+use std::{borrow::Cow, collections::HashMap};
+
+/// Blocks the default input maps of all controllers while any reason is pushed, e.g. while a modal dialog is open, a gizmo
+/// is dragged or a cutscene plays.
+///
+/// Reasons are counted, so independent systems can block and unblock input without clobbering each other: input stays
+/// blocked until every `push` has been matched by a `pop` with the same reason.
+#[derive(Debug, Default, Resource)]
+pub struct CameraInputBlockers {
+    reasons: HashMap<Cow<'static, str>, usize>,
+}
+
+impl CameraInputBlockers {
+    pub fn push(&mut self, reason: impl Into<Cow<'static, str>>) {
+        *self.reasons.entry(reason.into()).or_default() += 1;
+    }
+
+    /// Removes one `push` of `reason`. Returns `false` if `reason` wasn't pushed.
+    pub fn pop(&mut self, reason: &str) -> bool {
+        let count = match self.reasons.get_mut(reason) {
+            Some(count) => count,
+            None => return false,
+        };
+        *count -= 1;
+        if *count == 0 {
+            self.reasons.remove(reason);
+        }
+
+        true
+    }
+
+    pub fn is_blocked(&self) -> bool {
+        !self.reasons.is_empty()
+    }
+
+    pub fn is_blocked_by(&self, reason: &str) -> bool {
+        self.reasons.contains_key(reason)
+    }
+
+    /// The reasons currently blocking input, for debugging.
+    pub fn reasons(&self) -> impl Iterator<Item = &str> {
+        self.reasons.keys().map(|reason| reason.as_ref())
+    }
+
+    pub fn clear(&mut self) {
+        self.reasons.clear();
+    }
+}
+
+// ████████╗███████╗███████╗████████╗
+// ╚══██╔══╝██╔════╝██╔════╝╚══██╔══╝
+//    ██║   █████╗  ███████╗   ██║
+//    ██║   ██╔══╝  ╚════██║   ██║
+//    ██║   ███████╗███████║   ██║
+//    ╚═╝   ╚══════╝╚══════╝   ╚═╝
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_reasons_are_independent() {
+        let mut blockers = CameraInputBlockers::default();
+        blockers.push("dialog");
+        blockers.push("gizmo");
+        assert!(blockers.pop("dialog"));
+        assert!(blockers.is_blocked());
+        assert!(blockers.is_blocked_by("gizmo"));
+        assert!(blockers.pop("gizmo"));
+        assert!(!blockers.is_blocked());
+    }
+
+    #[test]
+    fn test_same_reason_is_counted() {
+        let mut blockers = CameraInputBlockers::default();
+        blockers.push("dialog");
+        blockers.push(String::from("dialog"));
+        assert!(blockers.pop("dialog"));
+        assert!(blockers.is_blocked());
+        assert!(blockers.pop("dialog"));
+        assert!(!blockers.is_blocked());
+        assert!(!blockers.pop("dialog"));
+    }
+}
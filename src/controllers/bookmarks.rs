@@ -0,0 +1,214 @@
+use super::{
+    fps::FpsCameraController, orbit::OrbitCameraController, unreal::UnrealCameraController,
+    CameraController,
+};
+use crate::{Easing, LookTransform, LookTransformEase, LookTransformSystem, Smoother};
+
+use bevy::{app::prelude::*, ecs::prelude::*};
+use std::collections::HashMap;
+
+/// Adds the `CameraBookmarks` resource and handles `SaveBookmark` and `RecallBookmark` for the `BookmarkedCamera`.
+#[derive(Default)]
+pub struct CameraBookmarksPlugin;
+
+impl Plugin for CameraBookmarksPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<CameraBookmarks>()
+            .add_event::<SaveBookmark>()
+            .add_event::<RecallBookmark>()
+            .add_system(camera_bookmarks_system.before(LookTransformSystem));
+    }
+}
+
+/// Marks the camera that `SaveBookmark` and `RecallBookmark` act on. The first one is used if several are marked.
+#[derive(Clone, Component, Copy, Debug, Default)]
+pub struct BookmarkedCamera;
+
+/// Saves the view of the `BookmarkedCamera` in a slot of the `CameraBookmarks`, replacing what was saved there.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct SaveBookmark(pub u8);
+
+/// Restores the view saved in a slot of the `CameraBookmarks`. Empty slots are ignored.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct RecallBookmark(pub u8);
+
+/// A saved view, with the settings and state of the camera's controller when it was saved.
+#[derive(Clone, Copy, Debug)]
+pub struct CameraBookmark {
+    pub view: LookTransform,
+    pub fps: Option<FpsCameraController>,
+    pub orbit: Option<OrbitCameraController>,
+    pub unreal: Option<UnrealCameraController>,
+}
+
+impl CameraBookmark {
+    /// A bookmark of `view` that leaves the controller as it is when recalled.
+    pub fn new(view: LookTransform) -> Self {
+        Self {
+            view,
+            fps: None,
+            orbit: None,
+            unreal: None,
+        }
+    }
+}
+
+/// Numbered saved views, like the numpad views of 3D modeling tools.
+#[derive(Clone, Debug, Resource)]
+pub struct CameraBookmarks {
+    /// Seconds of moving to a recalled view. `0.0` cuts, skipping the camera's smoothing.
+    pub transition: f32,
+    pub easing: Easing,
+    slots: HashMap<u8, CameraBookmark>,
+}
+
+impl Default for CameraBookmarks {
+    fn default() -> Self {
+        Self {
+            transition: 0.5,
+            easing: Easing::EaseInOut,
+            slots: HashMap::new(),
+        }
+    }
+}
+
+impl CameraBookmarks {
+    pub fn get(&self, slot: u8) -> Option<&CameraBookmark> {
+        self.slots.get(&slot)
+    }
+
+    /// Saves `bookmark` in `slot`, e.g. restored from a previous session. Returns what was saved there before.
+    pub fn insert(&mut self, slot: u8, bookmark: CameraBookmark) -> Option<CameraBookmark> {
+        self.slots.insert(slot, bookmark)
+    }
+
+    pub fn remove(&mut self, slot: u8) -> Option<CameraBookmark> {
+        self.slots.remove(&slot)
+    }
+
+    /// The occupied slots, in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = (u8, &CameraBookmark)> {
+        self.slots.iter().map(|(slot, bookmark)| (*slot, bookmark))
+    }
+}
+
+/// Returns `saved` with the per-entity state of `current` that bookmarks don't restore.
+fn restored<C: CameraController + Copy>(current: &C, saved: &C) -> C {
+    let mut restored = *saved;
+    restored.set_enabled(current.enabled());
+    restored
+}
+
+#[allow(clippy::type_complexity)]
+fn camera_bookmarks_system(
+    mut commands: Commands,
+    mut bookmarks: ResMut<CameraBookmarks>,
+    mut save_events: EventReader<SaveBookmark>,
+    mut recall_events: EventReader<RecallBookmark>,
+    mut cameras: Query<
+        (
+            Entity,
+            &mut LookTransform,
+            Option<&mut Smoother>,
+            Option<&mut FpsCameraController>,
+            Option<&mut OrbitCameraController>,
+            Option<&mut UnrealCameraController>,
+        ),
+        With<BookmarkedCamera>,
+    >,
+) {
+    let (entity, mut transform, smoother, fps, orbit, unreal) = match cameras.iter_mut().next() {
+        Some(camera) => camera,
+        None => return,
+    };
+
+    for SaveBookmark(slot) in save_events.iter() {
+        let bookmark = CameraBookmark {
+            view: *transform,
+            fps: fps.as_deref().copied(),
+            orbit: orbit.as_deref().copied(),
+            unreal: unreal.as_deref().copied(),
+        };
+        bookmarks.insert(*slot, bookmark);
+    }
+
+    // Only the last recall of the frame matters.
+    let bookmark = match recall_events
+        .iter()
+        .filter_map(|RecallBookmark(slot)| bookmarks.get(*slot))
+        .next_back()
+    {
+        Some(bookmark) => *bookmark,
+        None => return,
+    };
+
+    if let (Some(mut controller), Some(saved)) = (fps, bookmark.fps) {
+        *controller = restored(&*controller, &saved);
+    }
+    if let (Some(mut controller), Some(saved)) = (orbit, bookmark.orbit) {
+        *controller = restored(&*controller, &saved);
+    }
+    if let (Some(mut controller), Some(saved)) = (unreal, bookmark.unreal) {
+        *controller = restored(&*controller, &saved);
+    }
+
+    let view = bookmark.view;
+    if bookmarks.transition > 0.0 {
+        let mut ease = transform.ease_to(
+            view.eye,
+            view.target,
+            bookmarks.transition,
+            bookmarks.easing,
+        );
+        ease.to.scale = view.scale;
+        ease.to.roll = view.roll;
+        commands.entity(entity).insert(ease);
+    } else {
+        commands.entity(entity).remove::<LookTransformEase>();
+        *transform = view;
+        if let Some(mut smoother) = smoother {
+            smoother.reset();
+        }
+    }
+}
+
+// ████████╗███████╗███████╗████████╗
+// ╚══██╔══╝██╔════╝██╔════╝╚══██╔══╝
+//    ██║   █████╗  ███████╗   ██║
+//    ██║   ██╔══╝  ╚════██║   ██║
+//    ██║   ███████╗███████║   ██║
+//    ╚═╝   ╚══════╝╚══════╝   ╚═╝
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use bevy::math::prelude::*;
+
+    #[test]
+    fn test_recall_keeps_enabled() {
+        let current = OrbitCameraController {
+            enabled: false,
+            ..Default::default()
+        };
+        let saved = OrbitCameraController {
+            enabled: true,
+            min_radius: 3.0,
+            ..Default::default()
+        };
+        let restored = restored(&current, &saved);
+        assert!(!restored.enabled);
+        assert_eq!(restored.min_radius, 3.0);
+    }
+
+    #[test]
+    fn test_slots() {
+        let mut bookmarks = CameraBookmarks::default();
+        let view = LookTransform::new(Vec3::Z, Vec3::ZERO);
+        assert!(bookmarks.insert(1, CameraBookmark::new(view)).is_none());
+        assert_eq!(bookmarks.get(1).unwrap().view, view);
+        assert!(bookmarks.get(2).is_none());
+        assert!(bookmarks.remove(1).is_some());
+        assert_eq!(bookmarks.iter().count(), 0);
+    }
+}
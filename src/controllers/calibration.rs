@@ -0,0 +1,260 @@
+//! Calibrates the mouse rotation sensitivity of the controllers: the user drags across the viewport a few times, and the
+//! sensitivity is chosen so that a drag across the full viewport width rotates by `rotation_per_viewport`.
+//!
+//! Drags are measured in the units of `MouseMotion`, which the controllers rotate by, and normalized by how far the cursor
+//! moved, so drags that don't span the exact viewport width still count.
+
+use super::{blockers::CameraInputBlockers, defaults::CameraControllerDefaults};
+
+use bevy::{
+    app::prelude::*,
+    ecs::prelude::*,
+    input::{mouse::MouseMotion, prelude::*},
+    math::prelude::*,
+    window::Windows,
+};
+use std::f32::consts::PI;
+
+/// The reason pushed to `CameraInputBlockers` while a calibration runs.
+pub const CALIBRATION_BLOCKER: &str = "sensitivity_calibration";
+
+pub struct SensitivityCalibrationPlugin;
+
+impl Plugin for SensitivityCalibrationPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<SensitivityCalibration>()
+            .init_resource::<CameraControllerDefaults>()
+            .init_resource::<CameraInputBlockers>()
+            .add_event::<CalibrationEvent>()
+            .add_system(calibration_system);
+    }
+}
+
+/// Drives a calibration. Settings screens call `start`, show `progress`, and read the result from `CalibrationEvent` or
+/// `proposed_sensitivity`. Camera input is blocked while it runs.
+#[derive(Clone, Debug, Resource)]
+pub struct SensitivityCalibration {
+    /// Radians that a drag across the full viewport width should rotate.
+    pub rotation_per_viewport: f32,
+    /// The number of drags averaged.
+    pub drags: usize,
+    pub button: MouseButton,
+    /// Drags shorter than this fraction of the viewport width are rejected as too imprecise.
+    pub min_drag_fraction: f32,
+    /// When set, the result is written to the rotation sensitivities in `CameraControllerDefaults`.
+    pub apply_to_defaults: bool,
+    state: CalibrationState,
+    measurements: Vec<f32>,
+    proposed_sensitivity: Option<f32>,
+}
+
+impl Default for SensitivityCalibration {
+    fn default() -> Self {
+        Self {
+            rotation_per_viewport: PI,
+            drags: 3,
+            button: MouseButton::Left,
+            min_drag_fraction: 0.5,
+            apply_to_defaults: false,
+            state: CalibrationState::Idle,
+            measurements: Vec::new(),
+            proposed_sensitivity: None,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+enum CalibrationState {
+    Idle,
+    WaitingForDrag,
+    Dragging { start_x: f32, motion: f32 },
+}
+
+impl SensitivityCalibration {
+    /// Starts over, discarding any measured drags.
+    pub fn start(&mut self) {
+        self.state = CalibrationState::WaitingForDrag;
+        self.measurements.clear();
+    }
+
+    pub fn cancel(&mut self) {
+        self.state = CalibrationState::Idle;
+        self.measurements.clear();
+    }
+
+    pub fn is_running(&self) -> bool {
+        !matches!(self.state, CalibrationState::Idle)
+    }
+
+    /// The number of measured drags, and the number required.
+    pub fn progress(&self) -> (usize, usize) {
+        (self.measurements.len(), self.drags)
+    }
+
+    /// The sensitivity, in radians per unit of mouse motion, of the last finished calibration.
+    pub fn proposed_sensitivity(&self) -> Option<f32> {
+        self.proposed_sensitivity
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub enum CalibrationEvent {
+    /// A drag was measured, spanning `units_per_viewport` units of mouse motion per viewport width.
+    DragMeasured {
+        index: usize,
+        units_per_viewport: f32,
+    },
+    /// A drag was too short or left the window, and must be repeated.
+    DragRejected,
+    /// All drags were measured. `sensitivity` is in radians per unit of mouse motion.
+    Finished { sensitivity: f32 },
+}
+
+/// Units of mouse motion per viewport width, from a drag that moved the cursor `cursor_dx` pixels.
+fn units_per_viewport(motion: f32, cursor_dx: f32, viewport_width: f32) -> f32 {
+    (motion / cursor_dx).abs() * viewport_width
+}
+
+/// The sensitivity that rotates `rotation_per_viewport` for the mean of `measurements`.
+fn sensitivity_from_measurements(rotation_per_viewport: f32, measurements: &[f32]) -> Option<f32> {
+    if measurements.is_empty() {
+        return None;
+    }
+    let mean = measurements.iter().sum::<f32>() / measurements.len() as f32;
+
+    (mean > 0.0).then(|| rotation_per_viewport / mean)
+}
+
+/// Scales `sensitivity` so its X component is `x`, keeping the ratio between the axes.
+fn with_x_sensitivity(sensitivity: Vec2, x: f32) -> Vec2 {
+    if sensitivity.x > 0.0 {
+        sensitivity * (x / sensitivity.x)
+    } else {
+        Vec2::splat(x)
+    }
+}
+
+pub fn calibration_system(
+    mut calibration: ResMut<SensitivityCalibration>,
+    mut defaults: ResMut<CameraControllerDefaults>,
+    mut blockers: ResMut<CameraInputBlockers>,
+    mut events: EventWriter<CalibrationEvent>,
+    mut mouse_motion_events: EventReader<MouseMotion>,
+    mouse_buttons: Res<Input<MouseButton>>,
+    windows: Res<Windows>,
+) {
+    let running = calibration.is_running();
+    if running != blockers.is_blocked_by(CALIBRATION_BLOCKER) {
+        if running {
+            blockers.push(CALIBRATION_BLOCKER);
+        } else {
+            blockers.pop(CALIBRATION_BLOCKER);
+        }
+    }
+    if !running {
+        return;
+    }
+
+    let motion: f32 = mouse_motion_events.iter().map(|e| e.delta.x).sum();
+    let window = match windows.get_primary() {
+        Some(window) => window,
+        None => return,
+    };
+    let cursor = window.cursor_position();
+
+    let state = calibration.state;
+    match state {
+        CalibrationState::Idle => (),
+        CalibrationState::WaitingForDrag => {
+            if let Some(cursor) = cursor.filter(|_| mouse_buttons.just_pressed(calibration.button))
+            {
+                calibration.state = CalibrationState::Dragging {
+                    start_x: cursor.x,
+                    motion: 0.0,
+                };
+            }
+        }
+        CalibrationState::Dragging {
+            start_x,
+            motion: drag_motion,
+        } => {
+            let drag_motion = drag_motion + motion;
+            if mouse_buttons.pressed(calibration.button) {
+                calibration.state = CalibrationState::Dragging {
+                    start_x,
+                    motion: drag_motion,
+                };
+                return;
+            }
+
+            calibration.state = CalibrationState::WaitingForDrag;
+            let cursor_dx = cursor.map(|cursor| cursor.x - start_x);
+            let min_dx = calibration.min_drag_fraction * window.width();
+            let cursor_dx = match cursor_dx {
+                Some(dx) if dx.abs() >= min_dx.max(1.0) && drag_motion != 0.0 => dx,
+                _ => {
+                    events.send(CalibrationEvent::DragRejected);
+                    return;
+                }
+            };
+
+            let units_per_viewport = units_per_viewport(drag_motion, cursor_dx, window.width());
+            calibration.measurements.push(units_per_viewport);
+            events.send(CalibrationEvent::DragMeasured {
+                index: calibration.measurements.len() - 1,
+                units_per_viewport,
+            });
+            if calibration.measurements.len() < calibration.drags {
+                return;
+            }
+
+            calibration.state = CalibrationState::Idle;
+            let sensitivity = sensitivity_from_measurements(
+                calibration.rotation_per_viewport,
+                &calibration.measurements,
+            );
+            calibration.proposed_sensitivity = sensitivity;
+            if let Some(sensitivity) = sensitivity {
+                if calibration.apply_to_defaults {
+                    defaults.fps.mouse_rotate_sensitivity =
+                        with_x_sensitivity(defaults.fps.mouse_rotate_sensitivity, sensitivity);
+                    defaults.unreal.rotate_sensitivity =
+                        with_x_sensitivity(defaults.unreal.rotate_sensitivity, sensitivity);
+                }
+                events.send(CalibrationEvent::Finished { sensitivity });
+            }
+        }
+    }
+}
+
+// ████████╗███████╗███████╗████████╗
+// ╚══██╔══╝██╔════╝██╔════╝╚══██╔══╝
+//    ██║   █████╗  ███████╗   ██║
+//    ██║   ██╔══╝  ╚════██║   ██║
+//    ██║   ███████╗███████║   ██║
+//    ╚═╝   ╚══════╝╚══════╝   ╚═╝
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn test_partial_drag_is_normalized_to_viewport() {
+        // Half the viewport, reported as 600 units of motion.
+        assert_relative_eq!(units_per_viewport(-600.0, -400.0, 800.0), 1200.0);
+    }
+
+    #[test]
+    fn test_full_viewport_drag_rotates_target_amount() {
+        let sensitivity = sensitivity_from_measurements(PI, &[1100.0, 1300.0]).unwrap();
+        assert_relative_eq!(sensitivity * 1200.0, PI);
+        assert!(sensitivity_from_measurements(PI, &[]).is_none());
+    }
+
+    #[test]
+    fn test_with_x_sensitivity_keeps_axis_ratio() {
+        let sensitivity = with_x_sensitivity(Vec2::new(0.002, 0.001), 0.004);
+        assert_relative_eq!(sensitivity.y, 0.002);
+    }
+}
@@ -0,0 +1,105 @@
+use super::{
+    fps::FpsCameraController, orbit::OrbitCameraController, unreal::UnrealCameraController,
+};
+use crate::Smoother;
+
+use bevy::{ecs::prelude::*, reflect::TypeUuid};
+#[cfg(feature = "serialize")]
+use serde::{Deserialize, Serialize};
+
+/// App-wide controller settings, e.g. from a user settings screen. Controllers opt in to following these with a
+/// `ControllerOverrides` component, which can override individual fields per entity.
+#[derive(Clone, Copy, Debug, Default, Resource, TypeUuid)]
+#[cfg_attr(feature = "serialize", derive(Deserialize, Serialize))]
+#[uuid = "5b0b4c1e-4f7a-4d8e-9a43-2f0c6d1e8b71"]
+#[cfg_attr(feature = "serialize", serde(default))]
+pub struct CameraControllerDefaults {
+    pub fps: FpsCameraController,
+    pub orbit: OrbitCameraController,
+    pub unreal: UnrealCameraController,
+}
+
+/// A controller whose settings can be taken from `CameraControllerDefaults`.
+pub trait DefaultableController: Component + Copy {
+    /// The default settings for this controller type.
+    fn from_defaults(defaults: &CameraControllerDefaults) -> Self;
+
+    /// Copies the settings of `settings` into `self`, leaving per-entity state like `enabled` untouched.
+    fn apply_settings(&mut self, settings: &Self);
+
+    /// The lag weight of the camera's `Smoother`, which follows the defaults too.
+    fn smoothing_weight(&self) -> f32;
+}
+
+/// Makes the controller `C` on the same entity follow `CameraControllerDefaults`. The override function is applied on top of
+/// the defaults every time they change.
+#[derive(Component)]
+pub struct ControllerOverrides<C: DefaultableController> {
+    overrides: Box<dyn Fn(&mut C) + Send + Sync>,
+}
+
+impl<C: DefaultableController> ControllerOverrides<C> {
+    pub fn new(overrides: impl Fn(&mut C) + Send + Sync + 'static) -> Self {
+        Self {
+            overrides: Box::new(overrides),
+        }
+    }
+
+    /// Follow the defaults without overriding anything.
+    pub fn none() -> Self {
+        Self::new(|_| ())
+    }
+}
+
+#[allow(clippy::type_complexity)]
+pub fn apply_controller_defaults_system<C: DefaultableController>(
+    defaults: Res<CameraControllerDefaults>,
+    mut controllers: Query<(
+        &mut C,
+        Option<&mut Smoother>,
+        &ControllerOverrides<C>,
+        ChangeTrackers<ControllerOverrides<C>>,
+    )>,
+) {
+    for (mut controller, smoother, overrides, overrides_tracker) in controllers.iter_mut() {
+        if !defaults.is_changed() && !overrides_tracker.is_changed() {
+            continue;
+        }
+
+        let mut settings = C::from_defaults(&defaults);
+        (overrides.overrides)(&mut settings);
+        controller.apply_settings(&settings);
+        if let Some(mut smoother) = smoother {
+            smoother.set_lag_weight(controller.smoothing_weight());
+        }
+    }
+}
+
+// ████████╗███████╗███████╗████████╗
+// ╚══██╔══╝██╔════╝██╔════╝╚══██╔══╝
+//    ██║   █████╗  ███████╗   ██║
+//    ██║   ██╔══╝  ╚════██║   ██║
+//    ██║   ███████╗███████║   ██║
+//    ╚═╝   ╚══════╝╚══════╝   ╚═╝
+
+#[cfg(all(test, feature = "serialize"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip_ron_and_json() {
+        let mut defaults = CameraControllerDefaults::default();
+        defaults.fps.translate_sensitivity = 7.5;
+        defaults.orbit.mouse_wheel_zoom_sensitivity = 0.4;
+        defaults.unreal.smoothing_weight = 0.5;
+
+        let ron = ron::to_string(&defaults).unwrap();
+        let from_ron: CameraControllerDefaults = ron::from_str(&ron).unwrap();
+        assert_eq!(ron::to_string(&from_ron).unwrap(), ron);
+
+        let json = serde_json::to_string(&defaults).unwrap();
+        let from_json: CameraControllerDefaults = serde_json::from_str(&json).unwrap();
+        assert_eq!(serde_json::to_string(&from_json).unwrap(), json);
+        assert_eq!(from_json.fps.translate_sensitivity, 7.5);
+    }
+}
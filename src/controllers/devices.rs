@@ -0,0 +1,63 @@
+use bevy::{ecs::prelude::*, input::gamepad::Gamepad};
+
+/// Assigns input devices to a camera for local multiplayer, so each player's devices only move their own camera.
+///
+/// The default input maps control the first enabled camera that accepts their device. Cameras without this component
+/// accept all devices, and cameras with `InputDevices::none()` are skipped by the default input maps.
+#[derive(Clone, Component, Copy, Debug, Default, Eq, PartialEq)]
+pub struct InputDevices {
+    pub keyboard_mouse: bool,
+    pub gamepad: Option<Gamepad>,
+}
+
+impl InputDevices {
+    pub fn keyboard_mouse() -> Self {
+        Self {
+            keyboard_mouse: true,
+            gamepad: None,
+        }
+    }
+
+    pub fn gamepad(gamepad: Gamepad) -> Self {
+        Self {
+            keyboard_mouse: false,
+            gamepad: Some(gamepad),
+        }
+    }
+
+    /// Opts a single camera out of the default input maps, like the plugins' `override_input_system` does for all of
+    /// them, e.g. for a game view driven purely by custom events next to an editor camera. Send it
+    /// `TargetedControlEvent`s, since untargeted `ControlEvent`s go to the first enabled camera.
+    pub fn none() -> Self {
+        Self::default()
+    }
+}
+
+pub(crate) fn accepts_keyboard_mouse(devices: Option<&InputDevices>) -> bool {
+    devices.map_or(true, |d| d.keyboard_mouse)
+}
+
+pub(crate) fn accepts_gamepad(devices: Option<&InputDevices>, gamepad: Gamepad) -> bool {
+    devices.map_or(true, |d| d.gamepad == Some(gamepad))
+}
+
+// ████████╗███████╗███████╗████████╗
+// ╚══██╔══╝██╔════╝██╔════╝╚══██╔══╝
+//    ██║   █████╗  ███████╗   ██║
+//    ██║   ██╔══╝  ╚════██║   ██║
+//    ██║   ███████╗███████║   ██║
+//    ╚═╝   ╚══════╝╚══════╝   ╚═╝
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_none_accepts_no_device() {
+        let none = InputDevices::none();
+        assert!(!accepts_keyboard_mouse(Some(&none)));
+        assert!(!accepts_gamepad(Some(&none), Gamepad::new(0)));
+        assert!(accepts_keyboard_mouse(None));
+        assert!(accepts_gamepad(None, Gamepad::new(0)));
+    }
+}
@@ -0,0 +1,458 @@
+use crate::{
+    camera_path_handoff_system,
+    controllers::{
+        apply_deadband,
+        blockers::CameraInputBlockers,
+        devices::{accepts_keyboard_mouse, InputDevices},
+        input_available,
+        precision::PrecisionMode,
+        routed_control_events,
+        state::{and_in_controller_state, in_controller_state, state_activation, StateActivation},
+        CameraController, TargetedControlEvent,
+    },
+    CoordinateSystem, LookAngles, LookTransform, LookTransformBundle, Smoother,
+};
+
+use bevy::{
+    app::prelude::*,
+    ecs::{bundle::Bundle, prelude::*, schedule::StateData},
+    input::{
+        mouse::{MouseMotion, MouseScrollUnit, MouseWheel},
+        prelude::*,
+    },
+    math::prelude::*,
+    prelude::Camera3dBundle,
+    reflect::{FromReflect, Reflect},
+    time::Time,
+    transform::components::{GlobalTransform, Transform},
+};
+#[cfg(feature = "serialize")]
+use serde::{Deserialize, Serialize};
+
+#[derive(Default)]
+pub struct FollowCameraPlugin {
+    pub override_input_system: bool,
+    state_activation: Option<StateActivation>,
+}
+
+impl FollowCameraPlugin {
+    pub fn new(override_input_system: bool) -> Self {
+        Self {
+            override_input_system,
+            state_activation: None,
+        }
+    }
+
+    /// Only runs the controls and input maps while the app is in `state`. The smoothers are reset when it is entered.
+    pub fn run_in_state<S: StateData>(mut self, state: S) -> Self {
+        self.state_activation = Some(state_activation::<FollowCameraController, S>(state));
+        self
+    }
+}
+
+impl Plugin for FollowCameraPlugin {
+    fn build(&self, app: &mut App) {
+        let app = app
+            .register_type::<FollowCameraController>()
+            .register_type::<TargetPrediction>()
+            .add_system_to_stage(CoreStage::PreUpdate, on_controller_enabled_changed)
+            .add_system_to_stage(
+                CoreStage::PreUpdate,
+                camera_path_handoff_system::<FollowCameraController>
+                    .before(on_controller_enabled_changed),
+            )
+            .add_system(
+                control_system.with_run_criteria(in_controller_state::<FollowCameraController>),
+            )
+            .init_resource::<CameraInputBlockers>()
+            .init_resource::<CoordinateSystem>()
+            .add_event::<ControlEvent>()
+            .add_event::<TargetedControlEvent<ControlEvent>>();
+
+        if let Some(state_activation) = &self.state_activation {
+            state_activation(app);
+        }
+
+        if !self.override_input_system {
+            app.add_system(default_input_map.with_run_criteria(
+                input_available.pipe(and_in_controller_state::<FollowCameraController>),
+            ));
+        }
+    }
+}
+
+#[derive(Bundle)]
+pub struct FollowCameraBundle {
+    controller: FollowCameraController,
+    #[bundle]
+    look_transform: LookTransformBundle,
+    #[bundle]
+    camera: Camera3dBundle,
+}
+
+impl FollowCameraBundle {
+    /// Follows `followed`, starting at `eye` looking at `target`. The view direction is kept while the eye moves to
+    /// `controller.distance` from the followed entity.
+    pub fn new(
+        mut controller: FollowCameraController,
+        mut camera: Camera3dBundle,
+        followed: Entity,
+        eye: Vec3,
+        target: Vec3,
+    ) -> Self {
+        // Make sure the transform is consistent with the controller to start.
+        camera.transform = Transform::from_translation(eye).looking_at(target, Vec3::Y);
+        controller.followed = Some(followed);
+
+        Self {
+            controller,
+            look_transform: LookTransformBundle {
+                transform: LookTransform::new(eye, target),
+                smoother: Smoother::new(controller.smoothing_weight),
+            },
+            camera,
+        }
+    }
+}
+
+/// A third person camera that keeps the target on a followed entity, e.g. the player character, and orbits around it.
+#[derive(Clone, Component, Copy, Debug, Reflect)]
+#[cfg_attr(feature = "serialize", derive(Deserialize, Serialize))]
+#[reflect(Component)]
+#[cfg_attr(feature = "serialize", serde(default))]
+pub struct FollowCameraController {
+    pub enabled: bool,
+    /// The entity to follow. Without one (or while it has no `GlobalTransform`), the target stays where it is.
+    #[cfg_attr(feature = "serialize", serde(skip))]
+    #[reflect(ignore)]
+    pub followed: Option<Entity>,
+    /// Added to the followed entity's translation, e.g. to look at the character's head instead of its feet. Given in
+    /// bevy's Y-up convention and mapped into the `CoordinateSystem`, so the default of one unit up stays up in Z-up
+    /// worlds.
+    pub target_offset: Vec3,
+    /// Extrapolates the followed entity's motion, for bodies whose transform is interpolated after the camera updates.
+    pub target_prediction: Option<TargetPrediction>,
+    /// The eye-to-target distance. Zooming changes it within `min_distance` and `max_distance`.
+    pub distance: f32,
+    pub min_distance: f32,
+    pub max_distance: f32,
+    /// Limits (in radians) of the eye's elevation above the target.
+    pub min_pitch: f32,
+    pub max_pitch: f32,
+    /// Radians of (yaw, pitch) per pixel of mouse motion.
+    pub mouse_rotate_sensitivity: Vec2,
+    pub mouse_wheel_zoom_sensitivity: f32,
+    pub pixels_per_line: f32,
+    /// When set, the view only rotates while this button is held. Otherwise, all mouse motion rotates it, which suits a
+    /// grabbed cursor.
+    pub rotate_button: Option<MouseButton>,
+    pub smoothing_weight: f32,
+    /// Mouse motion shorter than this many pixels per frame is ignored, so jitter of a resting high-DPI mouse doesn't keep
+    /// the camera from settling.
+    pub mouse_deadband: f32,
+}
+
+impl Default for FollowCameraController {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            followed: None,
+            target_offset: Vec3::Y,
+            target_prediction: None,
+            distance: 5.0,
+            min_distance: 1.0,
+            max_distance: 20.0,
+            min_pitch: -20f32.to_radians(),
+            max_pitch: 70f32.to_radians(),
+            mouse_rotate_sensitivity: Vec2::splat(0.003),
+            mouse_wheel_zoom_sensitivity: 0.1,
+            pixels_per_line: 53.0,
+            rotate_button: None,
+            smoothing_weight: 0.8,
+            mouse_deadband: 0.0,
+        }
+    }
+}
+
+/// Leads the target by the velocity of the followed entity. Physics bodies that are interpolated (or extrapolated) between
+/// physics steps are often moved after the camera has updated, so the camera shows them a frame late and they appear to
+/// jitter against the background; leading the target by that frame keeps the camera and the body visually locked.
+#[derive(Clone, Copy, Debug, FromReflect, PartialEq, Reflect)]
+#[cfg_attr(feature = "serialize", derive(Deserialize, Serialize))]
+pub struct TargetPrediction {
+    /// Seconds to extrapolate ahead, typically one frame.
+    pub seconds_ahead: f32,
+    /// How fast the velocity estimate follows the measured velocity, per second. Lower values filter out uneven motion,
+    /// e.g. of bodies that only move on fixed physics steps.
+    pub response: f32,
+    #[cfg_attr(feature = "serialize", serde(skip))]
+    #[reflect(ignore)]
+    last_position: Option<Vec3>,
+    #[cfg_attr(feature = "serialize", serde(skip))]
+    #[reflect(ignore)]
+    velocity: Vec3,
+}
+
+impl Default for TargetPrediction {
+    fn default() -> Self {
+        Self {
+            seconds_ahead: 1.0 / 60.0,
+            response: 20.0,
+            last_position: None,
+            velocity: Vec3::ZERO,
+        }
+    }
+}
+
+impl TargetPrediction {
+    pub fn new(seconds_ahead: f32) -> Self {
+        Self {
+            seconds_ahead,
+            ..Default::default()
+        }
+    }
+
+    /// The estimated velocity of the followed entity, in world units per second.
+    pub fn velocity(&self) -> Vec3 {
+        self.velocity
+    }
+
+    /// Measures the velocity from the followed entity at `position` after `dt` seconds, and returns the position
+    /// extrapolated by `seconds_ahead`.
+    pub fn predict(&mut self, position: Vec3, dt: f32) -> Vec3 {
+        if let Some(last_position) = self.last_position.filter(|_| dt > 0.0) {
+            let measured = (position - last_position) / dt;
+            let blend = 1.0 - (-self.response * dt).exp();
+            self.velocity += (measured - self.velocity) * blend;
+        }
+        self.last_position = Some(position);
+
+        position + self.seconds_ahead * self.velocity
+    }
+
+    /// Forgets the motion, e.g. when the followed entity teleports.
+    pub fn reset(&mut self) {
+        self.last_position = None;
+        self.velocity = Vec3::ZERO;
+    }
+}
+
+impl CameraController for FollowCameraController {
+    fn enabled(&self) -> bool {
+        self.enabled
+    }
+
+    fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub enum ControlEvent {
+    /// Rotates the eye around the followed entity by (yaw, pitch) radians.
+    Orbit(Vec2),
+    /// Multiplies the distance.
+    Zoom(f32),
+}
+
+define_on_controller_enabled_changed!(FollowCameraController);
+
+pub fn default_input_map(
+    mut events: EventWriter<TargetedControlEvent<ControlEvent>>,
+    mut mouse_wheel_reader: EventReader<MouseWheel>,
+    mut mouse_motion_events: EventReader<MouseMotion>,
+    mouse_buttons: Res<Input<MouseButton>>,
+    controllers: Query<(Entity, &FollowCameraController, Option<&InputDevices>)>,
+) {
+    // The keyboard and mouse can only control one camera at a time.
+    let (camera, controller) = if let Some((camera, controller, _)) = controllers
+        .iter()
+        .find(|c| c.1.enabled && accepts_keyboard_mouse(c.2))
+    {
+        (camera, controller)
+    } else {
+        return;
+    };
+    let mut send = |event| events.send(TargetedControlEvent { camera, event });
+
+    let mut cursor_delta = Vec2::ZERO;
+    for event in mouse_motion_events.iter() {
+        cursor_delta += event.delta;
+    }
+    let cursor_delta = apply_deadband(cursor_delta, controller.mouse_deadband);
+    let rotating = controller
+        .rotate_button
+        .map_or(true, |button| mouse_buttons.pressed(button));
+    if rotating && cursor_delta != Vec2::ZERO {
+        send(ControlEvent::Orbit(
+            controller.mouse_rotate_sensitivity * cursor_delta,
+        ));
+    }
+
+    let mut scalar = 1.0;
+    for event in mouse_wheel_reader.iter() {
+        let scroll_amount = match event.unit {
+            MouseScrollUnit::Line => event.y,
+            MouseScrollUnit::Pixel => event.y / controller.pixels_per_line,
+        };
+        scalar *= 1.0 - scroll_amount * controller.mouse_wheel_zoom_sensitivity;
+    }
+    if scalar != 1.0 {
+        send(ControlEvent::Zoom(scalar));
+    }
+}
+
+pub fn control_system(
+    time: Res<Time>,
+    coordinates: Res<CoordinateSystem>,
+    mut events: EventReader<ControlEvent>,
+    mut targeted_events: EventReader<TargetedControlEvent<ControlEvent>>,
+    precision: Option<Res<PrecisionMode>>,
+    mut cameras: Query<(Entity, &mut FollowCameraController, &mut LookTransform)>,
+    followed: Query<&GlobalTransform>,
+) {
+    let first_enabled = cameras.iter().find(|c| c.1.enabled).map(|c| c.0);
+    let events = routed_control_events(
+        &mut events,
+        &mut targeted_events,
+        first_enabled,
+        precision.as_deref(),
+    );
+
+    for (camera, mut controller, mut transform) in cameras.iter_mut() {
+        if !controller.enabled {
+            continue;
+        }
+        let camera_events = events.iter().filter(|e| e.0 == camera).map(|e| &e.1);
+        let followed_position = controller
+            .followed
+            .and_then(|entity| followed.get(entity).ok())
+            .map(GlobalTransform::translation);
+        // Only runtime state of the controller changes, so don't trigger change detection.
+        let controller = controller.bypass_change_detection();
+        let followed_position = match (&mut controller.target_prediction, followed_position) {
+            (Some(prediction), Some(position)) => {
+                Some(prediction.predict(position, time.delta_seconds()))
+            }
+            (Some(prediction), None) => {
+                prediction.reset();
+                None
+            }
+            (None, position) => position,
+        };
+        let new_transform = control_camera(
+            controller,
+            *transform,
+            camera_events,
+            followed_position,
+            &coordinates,
+        );
+        // Only write when moved, so the transform isn't marked as changed every frame.
+        if new_transform != *transform {
+            *transform = new_transform;
+        }
+    }
+}
+
+/// Applies `events` to `transform`, following the entity at `followed_position`, and returns the new transform, without
+/// any ECS plumbing. The distance of the controller is updated in place.
+pub fn control_camera<'a>(
+    controller: &mut FollowCameraController,
+    transform: LookTransform,
+    events: impl IntoIterator<Item = &'a ControlEvent>,
+    followed_position: Option<Vec3>,
+    coordinates: &CoordinateSystem,
+) -> LookTransform {
+    let mut look_angles = match (transform.eye - transform.target).try_normalize() {
+        Some(direction) => LookAngles::from_vector_in(direction, *coordinates),
+        None => LookAngles::new(*coordinates),
+    };
+    let mut distance = controller.distance;
+    for event in events {
+        match event {
+            ControlEvent::Orbit(delta) => {
+                look_angles.add_yaw(-delta.x);
+                look_angles.add_pitch(delta.y);
+            }
+            ControlEvent::Zoom(scalar) => {
+                distance *= scalar;
+            }
+        }
+    }
+    look_angles.set_pitch(
+        look_angles
+            .get_pitch()
+            .clamp(controller.min_pitch, controller.max_pitch),
+    );
+    let distance = distance.clamp(controller.min_distance, controller.max_distance);
+    controller.distance = distance;
+
+    let target = match followed_position {
+        Some(position) => position + coordinates.to_world(controller.target_offset),
+        None => transform.target,
+    };
+    LookTransform {
+        eye: target + distance * look_angles.unit_vector(),
+        target,
+        scale: transform.scale,
+        roll: transform.roll,
+    }
+}
+
+// ████████╗███████╗███████╗████████╗
+// ╚══██╔══╝██╔════╝██╔════╝╚══██╔══╝
+//    ██║   █████╗  ███████╗   ██║
+//    ██║   ██╔══╝  ╚════██║   ██║
+//    ██║   ███████╗███████║   ██║
+//    ╚═╝   ╚══════╝╚══════╝   ╚═╝
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_prediction_leads_constant_motion() {
+        let mut prediction = TargetPrediction {
+            seconds_ahead: 0.1,
+            response: 1000.0,
+            ..Default::default()
+        };
+        // Nothing to extrapolate from yet.
+        assert_eq!(prediction.predict(Vec3::ZERO, 0.1), Vec3::ZERO);
+
+        let mut position = Vec3::ZERO;
+        for _ in 0..10 {
+            position += Vec3::new(0.2, 0.0, 0.0);
+            prediction.predict(position, 0.1);
+        }
+        assert!(prediction
+            .velocity()
+            .abs_diff_eq(Vec3::new(2.0, 0.0, 0.0), 1e-4));
+        let predicted = prediction.predict(position + Vec3::new(0.2, 0.0, 0.0), 0.1);
+        assert!(predicted.abs_diff_eq(position + Vec3::new(0.4, 0.0, 0.0), 1e-4));
+
+        prediction.reset();
+        assert_eq!(prediction.velocity(), Vec3::ZERO);
+    }
+
+    #[test]
+    fn test_pitch_limit_z_up() {
+        let mut controller = FollowCameraController::default();
+        let transform = LookTransform::new(Vec3::new(0.0, -5.0, 1.0), Vec3::new(0.0, 0.0, 1.0));
+        let events = [ControlEvent::Orbit(Vec2::new(0.0, 10.0))];
+        let followed = Vec3::new(1.0, 2.0, 0.0);
+        let transform = control_camera(
+            &mut controller,
+            transform,
+            &events,
+            Some(followed),
+            &CoordinateSystem::Z_UP,
+        );
+
+        // The target is above the followed entity, and the eye is raised to the pitch limit along Z.
+        assert!(transform.target.abs_diff_eq(followed + Vec3::Z, 1e-5));
+        let offset = transform.eye - transform.target;
+        let elevation = (offset.z / offset.length()).asin();
+        assert!((elevation - controller.max_pitch).abs() < 1e-4);
+    }
+}
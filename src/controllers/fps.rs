@@ -1,37 +1,104 @@
-use crate::{LookAngles, LookTransform, LookTransformBundle, Smoother};
+use crate::{
+    camera_path_handoff_system,
+    controllers::{
+        apply_deadband,
+        blockers::CameraInputBlockers,
+        defaults::{
+            apply_controller_defaults_system, CameraControllerDefaults, DefaultableController,
+        },
+        devices::{accepts_gamepad, accepts_keyboard_mouse, InputDevices},
+        gamepad_available, input_available,
+        precision::PrecisionMode,
+        prediction::{InputPrediction, MotionPredictor},
+        routed_control_events, set_first_person_angles,
+        state::{and_in_controller_state, in_controller_state, state_activation, StateActivation},
+        synced_look_angles,
+        tween::{tween_controller_system, TweenableController},
+        CameraController, GamepadInput, GamepadSticks, TargetedControlEvent,
+    },
+    move_eye, CameraCollisionHook, CollisionSettings, CoordinateSystem, LookAngles, LookTransform,
+    LookTransformBundle, Smoother,
+};
 
 use bevy::{
     app::prelude::*,
-    ecs::{bundle::Bundle, prelude::*},
+    ecs::{bundle::Bundle, prelude::*, schedule::StateData},
     input::{mouse::MouseMotion, prelude::*},
     math::prelude::*,
     prelude::Camera3dBundle,
+    reflect::Reflect,
+    time::Time,
     transform::components::Transform,
 };
+#[cfg(feature = "serialize")]
 use serde::{Deserialize, Serialize};
 
 #[derive(Default)]
 pub struct FpsCameraPlugin {
     pub override_input_system: bool,
+    state_activation: Option<StateActivation>,
 }
 
 impl FpsCameraPlugin {
     pub fn new(override_input_system: bool) -> Self {
         Self {
             override_input_system,
+            state_activation: None,
         }
     }
+
+    /// Only runs the controls and input maps while the app is in `state`. The smoothers are reset when it is entered.
+    pub fn run_in_state<S: StateData>(mut self, state: S) -> Self {
+        self.state_activation = Some(state_activation::<FpsCameraController, S>(state));
+        self
+    }
 }
 
 impl Plugin for FpsCameraPlugin {
     fn build(&self, app: &mut App) {
         let app = app
+            .register_type::<FpsCameraController>()
+            .register_type::<CollisionSettings>()
+            .register_type::<GamepadInput>()
+            .register_type::<InputPrediction>()
             .add_system_to_stage(CoreStage::PreUpdate, on_controller_enabled_changed)
-            .add_system(control_system)
-            .add_event::<ControlEvent>();
+            .add_system_to_stage(
+                CoreStage::PreUpdate,
+                camera_path_handoff_system::<FpsCameraController>
+                    .before(on_controller_enabled_changed),
+            )
+            .add_system_to_stage(CoreStage::PreUpdate, on_controller_low_latency_changed)
+            .add_system(
+                control_system.with_run_criteria(in_controller_state::<FpsCameraController>),
+            )
+            .add_system(tween_controller_system::<FpsCameraController>)
+            .add_system_to_stage(
+                CoreStage::PreUpdate,
+                apply_controller_defaults_system::<FpsCameraController>,
+            )
+            .init_resource::<CameraControllerDefaults>()
+            .init_resource::<CoordinateSystem>()
+            .init_resource::<CameraInputBlockers>()
+            .add_event::<ControlEvent>()
+            .add_event::<TargetedControlEvent<ControlEvent>>();
+
+        if let Some(state_activation) = &self.state_activation {
+            state_activation(app);
+        }
 
         if !self.override_input_system {
-            app.add_system(default_input_map);
+            app.add_system_to_stage(
+                CoreStage::PreUpdate,
+                low_latency_rotation_system.with_run_criteria(
+                    input_available.pipe(and_in_controller_state::<FpsCameraController>),
+                ),
+            )
+            .add_system(default_input_map.with_run_criteria(
+                input_available.pipe(and_in_controller_state::<FpsCameraController>),
+            ))
+            .add_system(gamepad_input_map.with_run_criteria(
+                gamepad_available.pipe(and_in_controller_state::<FpsCameraController>),
+            ));
         }
     }
 }
@@ -46,6 +113,8 @@ pub struct FpsCameraBundle {
 }
 
 impl FpsCameraBundle {
+    /// The camera starts upright in bevy's Y-up convention. The `LookTransformPlugin` turns it upright in the app's
+    /// `CoordinateSystem` on the first update.
     pub fn new(
         controller: FpsCameraController,
         mut camera: Camera3dBundle,
@@ -67,12 +136,43 @@ impl FpsCameraBundle {
 }
 
 /// Your typical first-person camera controller.
-#[derive(Clone, Component, Copy, Debug, Deserialize, Serialize)]
+#[derive(Clone, Component, Copy, Debug, Reflect)]
+#[cfg_attr(feature = "serialize", derive(Deserialize, Serialize))]
+#[reflect(Component)]
+#[cfg_attr(feature = "serialize", serde(default))]
 pub struct FpsCameraController {
     pub enabled: bool,
     pub mouse_rotate_sensitivity: Vec2,
     pub translate_sensitivity: f32,
     pub smoothing_weight: f32,
+    /// Mouse motion shorter than this many pixels per frame is ignored, so jitter of a resting high-DPI mouse doesn't keep
+    /// the camera from settling.
+    pub mouse_deadband: f32,
+    /// Applies mouse-look to the `LookTransform` in `PreUpdate` of the same frame and skips smoothing of the rotation.
+    pub low_latency_rotation: bool,
+    /// How far the eye drops when crouching.
+    pub crouch_height: f32,
+    /// How fast (in units per second) the eye moves between standing and crouching height.
+    pub crouch_speed: f32,
+    /// Whether the camera is crouching or moving toward crouching height.
+    #[cfg_attr(feature = "serialize", serde(skip))]
+    #[reflect(ignore)]
+    pub crouched: bool,
+    /// The current vertical offset of the eye from standing height, e.g. for viewmodels to follow.
+    #[cfg_attr(feature = "serialize", serde(skip))]
+    #[reflect(ignore)]
+    pub eye_height_offset: f32,
+    /// Canonical look angles, only resynchronized from the `LookTransform` when it is changed externally.
+    #[cfg_attr(feature = "serialize", serde(skip))]
+    #[reflect(ignore)]
+    pub look_angles: Option<LookAngles>,
+    /// Slide along obstacles instead of moving through them. Requires a `CameraCollisionHook` resource.
+    pub collision: Option<CollisionSettings>,
+    /// Right stick: look, left stick: move, triggers: move down/up. Translation speeds are in units per second.
+    pub gamepad: GamepadInput,
+    /// Leads mouse-look by the predicted motion of the next frame, hiding a frame of latency on low frame rates. Not
+    /// applied in low-latency mode, which has no latency to hide.
+    pub input_prediction: Option<InputPrediction>,
 }
 
 impl Default for FpsCameraController {
@@ -82,43 +182,175 @@ impl Default for FpsCameraController {
             mouse_rotate_sensitivity: Vec2::splat(0.002),
             translate_sensitivity: 0.5,
             smoothing_weight: 0.9,
+            mouse_deadband: 0.0,
+            low_latency_rotation: false,
+            crouch_height: 0.5,
+            crouch_speed: 3.0,
+            crouched: false,
+            eye_height_offset: 0.0,
+            look_angles: None,
+            collision: None,
+            gamepad: GamepadInput {
+                look_sensitivity: Vec2::splat(2.5),
+                translate_sensitivity: 5.0,
+                zoom_sensitivity: 3.0,
+                ..Default::default()
+            },
+            input_prediction: None,
         }
     }
 }
 
+impl FpsCameraController {
+    /// The (yaw, pitch) of the camera in radians. While the eye and target coincide, these are the last angles the
+    /// controller stored, if any.
+    pub fn yaw_pitch(
+        &self,
+        transform: &LookTransform,
+        coordinates: &CoordinateSystem,
+    ) -> Option<(f32, f32)> {
+        let angles = match transform.look_direction() {
+            Some(look_vector) => synced_look_angles(self.look_angles, look_vector, coordinates),
+            None => self.look_angles?,
+        };
+
+        Some((angles.get_yaw(), angles.get_pitch()))
+    }
+
+    /// Points the camera exactly at (`yaw`, `pitch`) in radians. The pitch is clamped to avoid looking straight up or down.
+    pub fn set_yaw_pitch(
+        &mut self,
+        transform: &mut LookTransform,
+        coordinates: &CoordinateSystem,
+        yaw: f32,
+        pitch: f32,
+    ) {
+        let mut angles = LookAngles::new(*coordinates);
+        angles.set_yaw(yaw);
+        angles.set_pitch(pitch);
+        set_first_person_angles(transform, angles);
+        self.look_angles = Some(angles);
+    }
+}
+
+impl CameraController for FpsCameraController {
+    fn enabled(&self) -> bool {
+        self.enabled
+    }
+
+    fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+    }
+}
+
+impl DefaultableController for FpsCameraController {
+    fn from_defaults(defaults: &CameraControllerDefaults) -> Self {
+        defaults.fps
+    }
+
+    fn apply_settings(&mut self, settings: &Self) {
+        let (enabled, crouched, eye_height_offset, look_angles) = (
+            self.enabled,
+            self.crouched,
+            self.eye_height_offset,
+            self.look_angles,
+        );
+        *self = *settings;
+        self.enabled = enabled;
+        self.crouched = crouched;
+        self.eye_height_offset = eye_height_offset;
+        self.look_angles = look_angles;
+    }
+
+    fn smoothing_weight(&self) -> f32 {
+        self.smoothing_weight
+    }
+}
+
+impl TweenableController for FpsCameraController {
+    fn tween_parameters(&mut self, start: &Self, end: &Self, t: f32) {
+        self.mouse_rotate_sensitivity = start
+            .mouse_rotate_sensitivity
+            .lerp(end.mouse_rotate_sensitivity, t);
+        self.translate_sensitivity = start.translate_sensitivity
+            + (end.translate_sensitivity - start.translate_sensitivity) * t;
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
 pub enum ControlEvent {
     Rotate(Vec2),
     TranslateEye(Vec3),
+    /// Moves the eye to this point.
+    SetEye(Vec3),
+    /// Sets the angles of the look direction.
+    SetYawPitch {
+        yaw: f32,
+        pitch: f32,
+    },
+    /// Turns the camera to look at this point.
+    SetTarget(Vec3),
+    /// Starts moving the eye toward crouching (`true`) or standing (`false`) height.
+    Crouch(bool),
 }
 
 define_on_controller_enabled_changed!(FpsCameraController);
 
+fn on_controller_low_latency_changed(
+    mut smoothers: Query<(&mut Smoother, &FpsCameraController), Changed<FpsCameraController>>,
+) {
+    for (mut smoother, controller) in smoothers.iter_mut() {
+        smoother.set_smooth_rotation(!controller.low_latency_rotation);
+    }
+}
+
 pub fn default_input_map(
-    mut events: EventWriter<ControlEvent>,
+    time: Res<Time>,
+    mut events: EventWriter<TargetedControlEvent<ControlEvent>>,
     keyboard: Res<Input<KeyCode>>,
     mut mouse_motion_events: EventReader<MouseMotion>,
-    controllers: Query<&FpsCameraController>,
+    controllers: Query<(Entity, &FpsCameraController, Option<&InputDevices>)>,
+    mut predictor: Local<MotionPredictor>,
 ) {
-    // Can only control one camera at a time.
-    let controller = if let Some(controller) = controllers.iter().find(|c| c.enabled) {
-        controller
+    // The keyboard and mouse can only control one camera at a time.
+    let (camera, controller) = if let Some((camera, controller, _)) = controllers
+        .iter()
+        .find(|c| c.1.enabled && accepts_keyboard_mouse(c.2))
+    {
+        (camera, controller)
     } else {
         return;
     };
+    let mut send = |event| events.send(TargetedControlEvent { camera, event });
     let FpsCameraController {
         translate_sensitivity,
         mouse_rotate_sensitivity,
+        low_latency_rotation,
+        mouse_deadband,
+        input_prediction,
         ..
     } = *controller;
 
-    let mut cursor_delta = Vec2::ZERO;
-    for event in mouse_motion_events.iter() {
-        cursor_delta += event.delta;
-    }
+    // In low-latency mode, mouse-look has already been applied by `low_latency_rotation_system`.
+    if !low_latency_rotation {
+        let mut cursor_delta = Vec2::ZERO;
+        for event in mouse_motion_events.iter() {
+            cursor_delta += event.delta;
+        }
+        let mut cursor_delta = apply_deadband(cursor_delta, mouse_deadband);
+        match input_prediction {
+            Some(settings) => {
+                cursor_delta = predictor.predict(&settings, cursor_delta, time.delta_seconds())
+            }
+            None => predictor.reset(),
+        }
 
-    events.send(ControlEvent::Rotate(
-        mouse_rotate_sensitivity * cursor_delta,
-    ));
+        if cursor_delta != Vec2::ZERO {
+            send(ControlEvent::Rotate(
+                mouse_rotate_sensitivity * cursor_delta,
+            ));
+        }
+    }
 
     for (key, dir) in [
         (KeyCode::W, Vec3::Z),
@@ -132,31 +364,180 @@ pub fn default_input_map(
     .cloned()
     {
         if keyboard.pressed(key) {
-            events.send(ControlEvent::TranslateEye(translate_sensitivity * dir));
+            send(ControlEvent::TranslateEye(translate_sensitivity * dir));
         }
     }
+
+    send(ControlEvent::Crouch(keyboard.pressed(KeyCode::C)));
 }
 
-pub fn control_system(
-    mut events: EventReader<ControlEvent>,
-    mut cameras: Query<(&FpsCameraController, &mut LookTransform)>,
+pub fn gamepad_input_map(
+    time: Res<Time>,
+    mut events: EventWriter<TargetedControlEvent<ControlEvent>>,
+    gamepads: Res<Gamepads>,
+    axes: Res<Axis<GamepadAxis>>,
+    button_axes: Res<Axis<GamepadButton>>,
+    controllers: Query<(Entity, &FpsCameraController, Option<&InputDevices>)>,
+) {
+    let dt = time.delta_seconds();
+    for gamepad in gamepads.iter() {
+        // Each gamepad controls the first enabled camera that accepts it.
+        let (camera, controller) = if let Some((camera, controller, _)) = controllers
+            .iter()
+            .find(|c| c.1.enabled && accepts_gamepad(c.2, gamepad))
+        {
+            (camera, controller)
+        } else {
+            continue;
+        };
+        let mut send = |event| events.send(TargetedControlEvent { camera, event });
+        let GamepadInput {
+            deadzone,
+            look_sensitivity,
+            translate_sensitivity,
+            zoom_sensitivity,
+        } = controller.gamepad;
+
+        let sticks = GamepadSticks::read(gamepad, deadzone, &axes, &button_axes);
+        if sticks.is_idle() {
+            continue;
+        }
+
+        let look = sticks.right_stick;
+        if look != Vec2::ZERO {
+            // Pushing the stick up looks up, like moving the mouse up.
+            send(ControlEvent::Rotate(
+                dt * look_sensitivity * Vec2::new(look.x, -look.y),
+            ));
+        }
+
+        let vertical = sticks.right_trigger - sticks.left_trigger;
+        let translation = dt
+            * Vec3::new(
+                -translate_sensitivity * sticks.left_stick.x,
+                zoom_sensitivity * vertical,
+                translate_sensitivity * sticks.left_stick.y,
+            );
+        if translation != Vec3::ZERO {
+            send(ControlEvent::TranslateEye(translation));
+        }
+    }
+}
+
+/// Rotates the camera directly from the mouse motion of this frame, without going through `ControlEvent`s.
+pub fn low_latency_rotation_system(
+    coordinates: Res<CoordinateSystem>,
+    mut mouse_motion_events: EventReader<MouseMotion>,
+    mut cameras: Query<(
+        &mut FpsCameraController,
+        &mut LookTransform,
+        Option<&InputDevices>,
+    )>,
 ) {
-    // Can only control one camera at a time.
-    let mut transform = if let Some((_, transform)) = cameras.iter_mut().find(|c| c.0.enabled) {
-        transform
+    // The mouse can only control one camera at a time.
+    let (mut controller, mut transform) = if let Some((controller, transform, _)) = cameras
+        .iter_mut()
+        .find(|c| c.0.enabled && accepts_keyboard_mouse(c.2))
+    {
+        (controller, transform)
     } else {
         return;
     };
+    if !controller.low_latency_rotation {
+        return;
+    }
+
+    let mut cursor_delta = Vec2::ZERO;
+    for event in mouse_motion_events.iter() {
+        cursor_delta += event.delta;
+    }
+    let cursor_delta = apply_deadband(cursor_delta, controller.mouse_deadband);
+    if cursor_delta == Vec2::ZERO {
+        return;
+    }
+    let delta = controller.mouse_rotate_sensitivity * cursor_delta;
+
+    let mut look_angles = match transform.look_direction() {
+        Some(look_vector) => synced_look_angles(controller.look_angles, look_vector, &coordinates),
+        None => match controller.look_angles {
+            Some(look_angles) => look_angles,
+            None => return,
+        },
+    };
+    look_angles.add_yaw(-delta.x);
+    look_angles.add_pitch(-delta.y);
+    look_angles.assert_not_looking_up();
+
+    set_first_person_angles(&mut transform, look_angles);
+    controller.bypass_change_detection().look_angles = Some(look_angles);
+}
+
+pub fn control_system(
+    time: Res<Time>,
+    coordinates: Res<CoordinateSystem>,
+    collision_hook: Option<Res<CameraCollisionHook>>,
+    mut events: EventReader<ControlEvent>,
+    mut targeted_events: EventReader<TargetedControlEvent<ControlEvent>>,
+    precision: Option<Res<PrecisionMode>>,
+    mut cameras: Query<(Entity, &mut FpsCameraController, &mut LookTransform)>,
+) {
+    let first_enabled = cameras.iter().find(|c| c.1.enabled).map(|c| c.0);
+    let events = routed_control_events(
+        &mut events,
+        &mut targeted_events,
+        first_enabled,
+        precision.as_deref(),
+    );
+
+    for (camera, mut controller, mut transform) in cameras.iter_mut() {
+        if !controller.enabled {
+            continue;
+        }
+        let camera_events = events.iter().filter(|e| e.0 == camera).map(|e| &e.1);
+        // Only runtime state of the controller changes, so don't trigger change detection.
+        let new_transform = control_camera(
+            controller.bypass_change_detection(),
+            *transform,
+            camera_events,
+            time.delta_seconds(),
+            collision_hook.as_deref(),
+            &coordinates,
+        );
+        // Only write when moved, so the transform isn't marked as changed every frame.
+        if new_transform != *transform {
+            *transform = new_transform;
+        }
+    }
+}
+
+/// Applies `events` to `transform` and returns the new transform, without any ECS plumbing, e.g. to unit test custom
+/// input maps. The runtime state of the controller (crouching and the stored look angles) is updated in place.
+pub fn control_camera<'a>(
+    controller: &mut FpsCameraController,
+    mut transform: LookTransform,
+    events: impl IntoIterator<Item = &'a ControlEvent>,
+    dt: f32,
+    collision_hook: Option<&CameraCollisionHook>,
+    coordinates: &CoordinateSystem,
+) -> LookTransform {
+    let mut crouched = controller.crouched;
 
-    let look_vector = transform.look_direction().unwrap();
-    let mut look_angles = LookAngles::from_vector(look_vector);
+    // Without a look direction (the eye is on the target), carry on with the last known angles.
+    let mut look_angles = match transform.look_direction() {
+        Some(look_vector) => synced_look_angles(controller.look_angles, look_vector, coordinates),
+        None => match controller.look_angles {
+            Some(look_angles) => look_angles,
+            None => return transform,
+        },
+    };
 
+    // The movement axes are turned by the yaw in the Y-up convention of the look angles.
     let yaw_rot = Quat::from_axis_angle(Vec3::Y, look_angles.get_yaw());
-    let rot_x = yaw_rot * Vec3::X;
-    let rot_y = yaw_rot * Vec3::Y;
-    let rot_z = yaw_rot * Vec3::Z;
+    let rot_x = coordinates.to_world(yaw_rot * Vec3::X);
+    let rot_y = coordinates.to_world(yaw_rot * Vec3::Y);
+    let rot_z = coordinates.to_world(yaw_rot * Vec3::Z);
 
-    for event in events.iter() {
+    for event in events {
         match event {
             ControlEvent::Rotate(delta) => {
                 // Rotates with pitch and yaw.
@@ -165,12 +546,137 @@ pub fn control_system(
             }
             ControlEvent::TranslateEye(delta) => {
                 // Translates up/down (Y) left/right (X) and forward/back (Z).
-                transform.eye += delta.x * rot_x + delta.y * rot_y + delta.z * rot_z;
+                transform.eye = move_eye(
+                    collision_hook,
+                    controller.collision,
+                    transform.eye,
+                    delta.x * rot_x + delta.y * rot_y + delta.z * rot_z,
+                );
+            }
+            ControlEvent::SetEye(eye) => {
+                transform.eye = *eye;
+            }
+            ControlEvent::SetYawPitch { yaw, pitch } => {
+                look_angles.set_yaw(*yaw);
+                look_angles.set_pitch(*pitch);
+            }
+            ControlEvent::SetTarget(target) => {
+                if let Some(direction) = (*target - transform.eye).try_normalize() {
+                    look_angles.set_direction(direction);
+                }
+            }
+            ControlEvent::Crouch(crouch) => {
+                crouched = *crouch;
             }
         }
     }
 
+    // The eye height has its own interpolation speed, independent of the smoother.
+    let goal_offset = if crouched {
+        -controller.crouch_height
+    } else {
+        0.0
+    };
+    let max_step = controller.crouch_speed * dt;
+    let step = (goal_offset - controller.eye_height_offset).clamp(-max_step, max_step);
+    transform.eye += step * coordinates.up();
+
+    controller.crouched = crouched;
+    controller.eye_height_offset += step;
+
     look_angles.assert_not_looking_up();
 
-    transform.target = transform.eye + transform.radius() * look_angles.unit_vector();
+    set_first_person_angles(&mut transform, look_angles);
+    controller.look_angles = Some(look_angles);
+
+    transform
+}
+
+// ████████╗███████╗███████╗████████╗
+// ╚══██╔══╝██╔════╝██╔════╝╚══██╔══╝
+//    ██║   █████╗  ███████╗   ██║
+//    ██║   ██╔══╝  ╚════██║   ██║
+//    ██║   ███████╗███████║   ██║
+//    ╚═╝   ╚══════╝╚══════╝   ╚═╝
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_crouch_lowers_eye_at_crouch_speed() {
+        let mut controller = FpsCameraController::default();
+        let mut transform = LookTransform::new(Vec3::new(0.0, 2.0, 0.0), Vec3::new(0.0, 2.0, -1.0));
+        let dt = 0.05;
+        let mut events = vec![ControlEvent::Crouch(true)];
+        for _ in 0..100 {
+            let eye = transform.eye;
+            transform = control_camera(
+                &mut controller,
+                transform,
+                &events,
+                dt,
+                None,
+                &CoordinateSystem::Y_UP,
+            );
+            assert!(eye.y - transform.eye.y <= controller.crouch_speed * dt + 1e-5);
+            events.clear();
+        }
+        assert!(controller.crouched);
+        assert!((transform.eye.y - (2.0 - controller.crouch_height)).abs() < 1e-5);
+        assert!(transform
+            .look_direction()
+            .unwrap()
+            .abs_diff_eq(Vec3::NEG_Z, 1e-5));
+    }
+
+    #[test]
+    fn test_yaw_pitch_without_look_direction() {
+        let mut controller = FpsCameraController::default();
+        let degenerate = LookTransform::new(Vec3::ONE, Vec3::ONE);
+        assert_eq!(
+            controller.yaw_pitch(&degenerate, &CoordinateSystem::Y_UP),
+            None
+        );
+
+        let mut transform = LookTransform::new(Vec3::ZERO, -Vec3::Z);
+        controller.set_yaw_pitch(&mut transform, &CoordinateSystem::Y_UP, 0.5, 0.25);
+        let (yaw, pitch) = controller
+            .yaw_pitch(&degenerate, &CoordinateSystem::Y_UP)
+            .unwrap();
+        assert!((yaw - 0.5).abs() < 1e-6 && (pitch - 0.25).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_control_without_look_direction() {
+        let mut controller = FpsCameraController::default();
+        let transform = LookTransform::new(Vec3::ONE, Vec3::ONE);
+        let events = [ControlEvent::Rotate(Vec2::new(0.1, 0.0))];
+        assert_eq!(
+            control_camera(
+                &mut controller,
+                transform,
+                &events,
+                0.1,
+                None,
+                &CoordinateSystem::Y_UP
+            ),
+            transform
+        );
+    }
+
+    #[test]
+    fn test_translate_z_up() {
+        let z_up = CoordinateSystem::Z_UP;
+        let mut controller = FpsCameraController::default();
+        let transform = LookTransform::new(Vec3::new(0.0, 0.0, 1.0), Vec3::new(0.0, 5.0, 1.0));
+
+        // Forward stays on the ground plane, up is +Z.
+        let events = [ControlEvent::TranslateEye(Vec3::Z)];
+        let forward = control_camera(&mut controller, transform, &events, 0.1, None, &z_up);
+        assert!(forward.eye.abs_diff_eq(Vec3::new(0.0, 1.0, 1.0), 1e-4));
+        let events = [ControlEvent::TranslateEye(Vec3::Y)];
+        let up = control_camera(&mut controller, transform, &events, 0.1, None, &z_up);
+        assert!(up.eye.abs_diff_eq(Vec3::new(0.0, 0.0, 2.0), 1e-4));
+    }
 }
@@ -0,0 +1,253 @@
+//! A uniform view of the tunable parameters of the built-in controllers, so generic editor UIs can render tuning panels
+//! without hardcoding each controller's struct.
+
+use crate::controllers::{
+    follow::FollowCameraController, fps::FpsCameraController,
+    observation::ObservationCameraController, orbit::OrbitCameraController,
+    pan_cam_2d::PanCam2dController, rts::RtsCameraController, unreal::UnrealCameraController,
+    CameraController,
+};
+
+use bevy::math::prelude::*;
+use std::f32::consts::PI;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ParameterKind {
+    Bool,
+    Float,
+    Vec2,
+    Vec3,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ParameterValue {
+    Bool(bool),
+    Float(f32),
+    Vec2(Vec2),
+    Vec3(Vec3),
+}
+
+impl ParameterValue {
+    pub fn kind(&self) -> ParameterKind {
+        match self {
+            ParameterValue::Bool(_) => ParameterKind::Bool,
+            ParameterValue::Float(_) => ParameterKind::Float,
+            ParameterValue::Vec2(_) => ParameterKind::Vec2,
+            ParameterValue::Vec3(_) => ParameterKind::Vec3,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Unit {
+    None,
+    /// A fraction in `[0, 1]`.
+    Fraction,
+    Pixels,
+    Radians,
+    Seconds,
+    /// Per second, like a rate of decay or return.
+    PerSecond,
+    WorldUnits,
+    WorldUnitsPerSecond,
+    /// Per pixel of mouse motion.
+    PerPixel,
+    /// Per line of mouse wheel scrolling.
+    PerLine,
+}
+
+/// A tunable parameter of a controller. Editors should offer values in `min..=max` (per component for vectors); `Bool`
+/// parameters ignore the range.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ParameterInfo {
+    pub name: &'static str,
+    pub description: &'static str,
+    pub kind: ParameterKind,
+    pub min: f32,
+    pub max: f32,
+    pub unit: Unit,
+}
+
+/// Reflection of a controller's tunable parameters by name.
+pub trait CameraControllerInfo: CameraController {
+    fn parameters(&self) -> &'static [ParameterInfo];
+
+    fn get_parameter(&self, name: &str) -> Option<ParameterValue>;
+
+    /// Sets the parameter `name`, clamped to its range. Returns `false` if there is no such parameter or `value` has the
+    /// wrong kind.
+    fn set_parameter(&mut self, name: &str, value: ParameterValue) -> bool;
+}
+
+fn clamp_value(info: &ParameterInfo, value: ParameterValue) -> ParameterValue {
+    match value {
+        ParameterValue::Bool(_) => value,
+        ParameterValue::Float(v) => ParameterValue::Float(v.clamp(info.min, info.max)),
+        ParameterValue::Vec2(v) => {
+            ParameterValue::Vec2(v.clamp(Vec2::splat(info.min), Vec2::splat(info.max)))
+        }
+        ParameterValue::Vec3(v) => {
+            ParameterValue::Vec3(v.clamp(Vec3::splat(info.min), Vec3::splat(info.max)))
+        }
+    }
+}
+
+macro_rules! impl_controller_info {
+    ($Controller:ty {
+        $($field:ident: $kind:ident [$min:expr, $max:expr] $unit:ident, $description:literal;)*
+    }) => {
+        impl CameraControllerInfo for $Controller {
+            fn parameters(&self) -> &'static [ParameterInfo] {
+                const PARAMETERS: &[ParameterInfo] = &[$(ParameterInfo {
+                    name: stringify!($field),
+                    description: $description,
+                    kind: ParameterKind::$kind,
+                    min: $min,
+                    max: $max,
+                    unit: Unit::$unit,
+                }),*];
+
+                PARAMETERS
+            }
+
+            fn get_parameter(&self, name: &str) -> Option<ParameterValue> {
+                match name {
+                    $(stringify!($field) => Some(ParameterValue::$kind(self.$field)),)*
+                    _ => None,
+                }
+            }
+
+            fn set_parameter(&mut self, name: &str, value: ParameterValue) -> bool {
+                let info = match self.parameters().iter().find(|p| p.name == name) {
+                    Some(info) if info.kind == value.kind() => info,
+                    _ => return false,
+                };
+                match (name, clamp_value(info, value)) {
+                    $((stringify!($field), ParameterValue::$kind(v)) => self.$field = v,)*
+                    _ => return false,
+                }
+
+                true
+            }
+        }
+    };
+}
+
+impl_controller_info!(FollowCameraController {
+    target_offset: Vec3 [-10.0, 10.0] WorldUnits, "Offset of the target from the followed entity";
+    distance: Float [0.0, 100.0] WorldUnits, "Distance from the eye to the target";
+    min_distance: Float [0.0, 100.0] WorldUnits, "Minimum zoom distance";
+    max_distance: Float [0.0, 100.0] WorldUnits, "Maximum zoom distance";
+    min_pitch: Float [-1.5, 1.5] Radians, "Lowest elevation of the eye above the target";
+    max_pitch: Float [-1.5, 1.5] Radians, "Highest elevation of the eye above the target";
+    mouse_rotate_sensitivity: Vec2 [0.0, 0.05] PerPixel, "Radians of (yaw, pitch) per pixel of mouse motion";
+    mouse_wheel_zoom_sensitivity: Float [0.0, 0.9] PerLine, "Fraction of the distance zoomed per scrolled line";
+    smoothing_weight: Float [0.0, 0.99] Fraction, "The greater, the slower to follow input";
+    mouse_deadband: Float [0.0, 10.0] Pixels, "Mouse motion shorter than this per frame is ignored";
+});
+
+impl_controller_info!(FpsCameraController {
+    mouse_rotate_sensitivity: Vec2 [0.0, 0.05] PerPixel, "Radians of (yaw, pitch) per pixel of mouse motion";
+    translate_sensitivity: Float [0.0, 10.0] WorldUnits, "Distance moved per frame while a movement key is held";
+    smoothing_weight: Float [0.0, 0.99] Fraction, "The greater, the slower to follow input";
+    mouse_deadband: Float [0.0, 10.0] Pixels, "Mouse motion shorter than this per frame is ignored";
+    low_latency_rotation: Bool [0.0, 1.0] None, "Apply mouse-look immediately, without smoothing";
+    crouch_height: Float [0.0, 2.0] WorldUnits, "How far the eye lowers when crouching";
+    crouch_speed: Float [0.0, 10.0] WorldUnitsPerSecond, "How fast the eye moves between standing and crouching";
+});
+
+impl_controller_info!(ObservationCameraController {
+    mouse_rotate_sensitivity: Vec2 [0.0, 0.05] PerPixel, "Radians of (yaw, pitch) per pixel of mouse motion";
+    max_look_angle: Float [0.0, PI] Radians, "Maximum angle between the look direction and the mount's forward";
+    look_angle_soft_edge: Float [0.0, 1.0] Radians, "Width of the damped band at the edge of the look cone";
+    return_delay: Float [0.0, 10.0] Seconds, "Idle time before returning to look forward";
+    return_speed: Float [0.0, 10.0] PerSecond, "How fast the view returns to look forward";
+    smoothing_weight: Float [0.0, 0.99] Fraction, "The greater, the slower to follow input";
+    mouse_deadband: Float [0.0, 10.0] Pixels, "Mouse motion shorter than this per frame is ignored";
+});
+
+impl_controller_info!(OrbitCameraController {
+    mouse_rotate_sensitivity: Vec2 [0.0, 1.0] PerPixel, "Rotation speed per pixel of mouse motion";
+    mouse_translate_sensitivity: Vec2 [0.0, 1.0] PerPixel, "Panning speed per pixel of mouse motion";
+    mouse_wheel_zoom_sensitivity: Float [0.0, 0.9] PerLine, "Fraction of the distance zoomed per scrolled line";
+    pixels_per_line: Float [1.0, 200.0] Pixels, "Pixels of touchpad scrolling counted as one line";
+    smoothing_weight: Float [0.0, 0.99] Fraction, "The greater, the slower to follow input";
+    mouse_deadband: Float [0.0, 10.0] Pixels, "Mouse motion shorter than this per frame is ignored";
+    viewport_relative_rotation: Bool [0.0, 1.0] None, "Rotation sensitivity is per viewport width instead of per pixel";
+    cursor_anchored_rotation: Bool [0.0, 1.0] None, "Orbit around the point under the cursor";
+    pivot_under_cursor: Bool [0.0, 1.0] None, "Re-target orbit drags at the scene point under the cursor";
+    zoom_to_cursor: Bool [0.0, 1.0] None, "Zoom toward the point under the cursor";
+    min_pitch: Float [-PI / 2.0, PI / 2.0] Radians, "Lowest elevation of the eye above the target";
+    max_pitch: Float [-PI / 2.0, PI / 2.0] Radians, "Highest elevation of the eye above the target";
+    min_radius: Float [0.0, 1000000.0] WorldUnits, "Minimum zoom distance of perspective cameras";
+    max_radius: Float [0.0, 1000000.0] WorldUnits, "Maximum zoom distance of perspective cameras";
+    min_scale: Float [0.0, 1000.0] None, "Minimum projection scale of orthographic cameras";
+    max_scale: Float [0.0, f32::MAX] None, "Maximum projection scale of orthographic cameras";
+});
+
+impl_controller_info!(PanCam2dController {
+    mouse_wheel_zoom_sensitivity: Float [0.0, 0.9] PerLine, "Fraction of the scale zoomed per scrolled line";
+    pixels_per_line: Float [1.0, 200.0] Pixels, "Pixels of touchpad scrolling counted as one line";
+    zoom_to_cursor: Bool [0.0, 1.0] None, "Zoom around the point under the cursor";
+    min_scale: Float [0.0, 1000.0] None, "Minimum projection scale";
+    max_scale: Float [0.0, f32::MAX] None, "Maximum projection scale";
+    smoothing_weight: Float [0.0, 0.99] Fraction, "The greater, the slower to follow input";
+    mouse_deadband: Float [0.0, 10.0] Pixels, "Mouse motion shorter than this per frame is ignored";
+});
+
+impl_controller_info!(RtsCameraController {
+    pan_speed: Float [0.0, 10.0] PerSecond, "Panning speed in multiples of the eye's height per second";
+    mouse_wheel_zoom_sensitivity: Float [0.0, 0.9] PerLine, "Fraction of the distance zoomed per scrolled line";
+    pixels_per_line: Float [1.0, 200.0] Pixels, "Pixels of touchpad scrolling counted as one line";
+    min_height: Float [0.0, 1000.0] WorldUnits, "Lowest height of the eye above the target";
+    max_height: Float [0.0, 1000.0] WorldUnits, "Highest height of the eye above the target";
+    rotate_speed: Float [0.0, 10.0] PerSecond, "Radians per second of rotation around the vertical axis";
+    smoothing_weight: Float [0.0, 0.99] Fraction, "The greater, the slower to follow input";
+});
+
+impl_controller_info!(UnrealCameraController {
+    rotate_sensitivity: Vec2 [0.0, 0.05] PerPixel, "Radians of (yaw, pitch) per pixel of mouse motion";
+    mouse_translate_sensitivity: Vec2 [0.0, 10.0] PerPixel, "Panning distance per pixel of mouse motion";
+    wheel_translate_sensitivity: Float [0.0, 10.0] PerLine, "Distance moved per scrolled line";
+    keyboard_mvmt_sensitivity: Float [0.01, 100.0] WorldUnits, "Distance moved per frame while a key is held";
+    keyboard_mvmt_wheel_sensitivity: Float [0.0, 10.0] PerLine, "Change of the keyboard speed per scrolled line";
+    smoothing_weight: Float [0.0, 0.99] Fraction, "The greater, the slower to follow input";
+    mouse_deadband: Float [0.0, 10.0] Pixels, "Mouse motion shorter than this per frame is ignored";
+    zoom_to_cursor: Bool [0.0, 1.0] None, "Scroll toward the point under the cursor";
+    min_radius: Float [0.0, 1000000.0] WorldUnits, "Minimum distance between the eye and the target";
+    max_radius: Float [0.0, 1000000.0] WorldUnits, "Maximum distance between the eye and the target";
+    min_scale: Float [0.0, 1000.0] None, "Minimum projection scale of orthographic cameras";
+    max_scale: Float [0.0, f32::MAX] None, "Maximum projection scale of orthographic cameras";
+});
+
+// ████████╗███████╗███████╗████████╗
+// ╚══██╔══╝██╔════╝██╔════╝╚══██╔══╝
+//    ██║   █████╗  ███████╗   ██║
+//    ██║   ██╔══╝  ╚════██║   ██║
+//    ██║   ███████╗███████║   ██║
+//    ╚═╝   ╚══════╝╚══════╝   ╚═╝
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_every_parameter_can_be_read() {
+        let controller = OrbitCameraController::default();
+        for info in controller.parameters() {
+            let value = controller.get_parameter(info.name).unwrap();
+            assert_eq!(value.kind(), info.kind);
+        }
+    }
+
+    #[test]
+    fn test_set_parameter_clamps_and_checks_kind() {
+        let mut controller = FpsCameraController::default();
+        assert!(controller.set_parameter("smoothing_weight", ParameterValue::Float(2.0)));
+        assert_eq!(controller.smoothing_weight, 0.99);
+
+        assert!(!controller.set_parameter("smoothing_weight", ParameterValue::Bool(true)));
+        assert!(!controller.set_parameter("enabled", ParameterValue::Bool(false)));
+        assert!(controller.enabled);
+    }
+}
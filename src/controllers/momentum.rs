@@ -0,0 +1,208 @@
+//! Frame-rate independent spinning of orbit cameras: momentum after a drag is released, and idle auto-rotation.
+//!
+//! Both integrate in closed form over each frame's delta time, so the camera follows the same trajectory at any frame
+//! rate. Time steps are clamped to `max_step` so a hitch doesn't fling the camera.
+
+use bevy::{
+    math::prelude::*,
+    reflect::{FromReflect, Reflect},
+};
+#[cfg(feature = "serialize")]
+use serde::{Deserialize, Serialize};
+
+/// Keeps rotating with the velocity of the last drag after it's released, decaying exponentially.
+#[derive(Clone, Copy, Debug, FromReflect, Reflect)]
+#[cfg_attr(feature = "serialize", derive(Deserialize, Serialize))]
+pub struct Momentum {
+    /// Exponential decay rate of the velocity, per second.
+    pub decay_rate: f32,
+    /// The momentum stops below this speed, in radians per second.
+    pub min_speed: f32,
+    /// Longest time step integrated per frame, in seconds.
+    pub max_step: f32,
+    #[cfg_attr(feature = "serialize", serde(skip))]
+    #[reflect(ignore)]
+    velocity: Vec2,
+}
+
+impl Default for Momentum {
+    fn default() -> Self {
+        Self {
+            decay_rate: 4.0,
+            min_speed: 1e-3,
+            max_step: 0.1,
+            velocity: Vec2::ZERO,
+        }
+    }
+}
+
+impl Momentum {
+    /// The current (yaw, pitch) angular velocity in radians per second.
+    pub fn velocity(&self) -> Vec2 {
+        self.velocity
+    }
+
+    pub fn is_moving(&self) -> bool {
+        self.velocity != Vec2::ZERO
+    }
+
+    /// Sets the velocity while the user is dragging, to be continued once they let go.
+    pub fn set_velocity(&mut self, velocity: Vec2) {
+        self.velocity = velocity;
+    }
+
+    pub fn stop(&mut self) {
+        self.velocity = Vec2::ZERO;
+    }
+
+    /// Decays the velocity over `dt` seconds and returns the (yaw, pitch) rotation covered meanwhile.
+    pub fn advance(&mut self, dt: f32) -> Vec2 {
+        let dt = dt.clamp(0.0, self.max_step);
+        if self.decay_rate <= 0.0 {
+            return self.velocity * dt;
+        }
+
+        let decay = (-self.decay_rate * dt).exp();
+        let delta = self.velocity * (1.0 - decay) / self.decay_rate;
+        self.velocity *= decay;
+        if self.velocity.length() < self.min_speed {
+            self.velocity = Vec2::ZERO;
+        }
+
+        delta
+    }
+}
+
+/// Slowly rotates the camera around its target while the user isn't interacting with it, like a turntable.
+#[derive(Clone, Copy, Debug, FromReflect, Reflect)]
+#[cfg_attr(feature = "serialize", derive(Deserialize, Serialize))]
+pub struct AutoRotate {
+    /// Yaw speed in radians per second.
+    pub speed: f32,
+    /// Seconds without interaction before rotation (re)starts.
+    pub resume_delay: f32,
+    /// Longest time step integrated per frame, in seconds.
+    pub max_step: f32,
+    #[cfg_attr(feature = "serialize", serde(skip))]
+    #[reflect(ignore)]
+    idle_time: f32,
+}
+
+impl Default for AutoRotate {
+    fn default() -> Self {
+        Self {
+            speed: 0.3,
+            resume_delay: 3.0,
+            max_step: 0.1,
+            idle_time: 0.0,
+        }
+    }
+}
+
+impl AutoRotate {
+    pub fn is_rotating(&self) -> bool {
+        self.idle_time >= self.resume_delay
+    }
+
+    /// The current yaw velocity in radians per second.
+    pub fn velocity(&self) -> f32 {
+        if self.is_rotating() {
+            self.speed
+        } else {
+            0.0
+        }
+    }
+
+    /// Continues the idle time of `previous`, e.g. when new settings replace it.
+    pub(crate) fn continue_from(&mut self, previous: &Self) {
+        self.idle_time = previous.idle_time;
+    }
+
+    /// Restarts the `resume_delay`.
+    pub fn interrupt(&mut self) {
+        self.idle_time = 0.0;
+    }
+
+    /// Advances the idle time by `dt` seconds and returns the yaw rotation covered meanwhile.
+    pub fn advance(&mut self, dt: f32) -> f32 {
+        let dt = dt.clamp(0.0, self.max_step);
+        let was_idle_time = self.idle_time;
+        self.idle_time += dt;
+        let rotating_time = (self.idle_time - self.resume_delay.max(was_idle_time)).max(0.0);
+
+        self.speed * rotating_time
+    }
+}
+
+// ████████╗███████╗███████╗████████╗
+// ╚══██╔══╝██╔════╝██╔════╝╚══██╔══╝
+//    ██║   █████╗  ███████╗   ██║
+//    ██║   ██╔══╝  ╚════██║   ██║
+//    ██║   ███████╗███████║   ██║
+//    ╚═╝   ╚══════╝╚══════╝   ╚═╝
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Releases a drag spinning at 2 rad/s, then samples the accumulated yaw every 1/30 s for 4 seconds.
+    fn simulate(fps: u32) -> Vec<f32> {
+        let mut momentum = Momentum::default();
+        momentum.set_velocity(Vec2::new(2.0, 0.0));
+        let mut auto_rotate = AutoRotate {
+            resume_delay: 1.0,
+            ..Default::default()
+        };
+
+        let dt = 1.0 / fps as f32;
+        let frames_per_sample = fps / 30;
+        let mut yaw = 0.0;
+        let mut samples = Vec::new();
+        for frame in 1..=4 * fps {
+            yaw += momentum.advance(dt).x + auto_rotate.advance(dt);
+            if frame % frames_per_sample == 0 {
+                samples.push(yaw);
+            }
+        }
+
+        samples
+    }
+
+    #[test]
+    fn test_trajectory_is_frame_rate_independent() {
+        let reference = simulate(30);
+        for fps in [60, 240] {
+            let samples = simulate(fps);
+            assert_eq!(samples.len(), reference.len());
+            for (a, b) in reference.iter().zip(&samples) {
+                assert!((a - b).abs() < 1e-3, "{} FPS: {} != {}", fps, a, b);
+            }
+        }
+    }
+
+    #[test]
+    fn test_momentum_converges_to_velocity_over_decay_rate() {
+        let mut momentum = Momentum::default();
+        momentum.set_velocity(Vec2::new(2.0, -1.0));
+        let mut total = Vec2::ZERO;
+        for _ in 0..600 {
+            total += momentum.advance(1.0 / 60.0);
+        }
+
+        assert!(!momentum.is_moving());
+        assert!(total.abs_diff_eq(Vec2::new(0.5, -0.25), 1e-3));
+    }
+
+    #[test]
+    fn test_time_step_is_clamped() {
+        let mut auto_rotate = AutoRotate {
+            resume_delay: 0.0,
+            ..Default::default()
+        };
+
+        assert_eq!(
+            auto_rotate.advance(10.0),
+            auto_rotate.speed * auto_rotate.max_step
+        );
+    }
+}
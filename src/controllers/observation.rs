@@ -0,0 +1,287 @@
+use crate::{
+    camera_path_handoff_system,
+    controllers::{
+        apply_deadband,
+        blockers::CameraInputBlockers,
+        devices::{accepts_keyboard_mouse, InputDevices},
+        input_available,
+        precision::PrecisionMode,
+        routed_control_events,
+        state::{and_in_controller_state, in_controller_state, state_activation, StateActivation},
+        CameraController, TargetedControlEvent,
+    },
+    ConeReference, CoordinateSystem, LookAngles, LookCone, LookConePlugin, LookTransform,
+    LookTransformBundle, Smoother,
+};
+
+use bevy::{
+    app::prelude::*,
+    ecs::{bundle::Bundle, prelude::*, schedule::StateData},
+    input::mouse::MouseMotion,
+    math::prelude::*,
+    prelude::Camera3dBundle,
+    reflect::Reflect,
+    time::Time,
+    transform::components::Transform,
+};
+#[cfg(feature = "serialize")]
+use serde::{Deserialize, Serialize};
+
+#[derive(Default)]
+pub struct ObservationCameraPlugin {
+    pub override_input_system: bool,
+    state_activation: Option<StateActivation>,
+}
+
+impl ObservationCameraPlugin {
+    pub fn new(override_input_system: bool) -> Self {
+        Self {
+            override_input_system,
+            state_activation: None,
+        }
+    }
+
+    /// Only runs the controls and input maps while the app is in `state`. The smoothers are reset when it is entered.
+    pub fn run_in_state<S: StateData>(mut self, state: S) -> Self {
+        self.state_activation = Some(state_activation::<ObservationCameraController, S>(state));
+        self
+    }
+}
+
+impl Plugin for ObservationCameraPlugin {
+    fn build(&self, app: &mut App) {
+        let app = app
+            .register_type::<ObservationCameraController>()
+            .add_system_to_stage(CoreStage::PreUpdate, on_controller_enabled_changed)
+            .add_system_to_stage(
+                CoreStage::PreUpdate,
+                camera_path_handoff_system::<ObservationCameraController>
+                    .before(on_controller_enabled_changed),
+            )
+            .add_system(
+                control_system
+                    .with_run_criteria(in_controller_state::<ObservationCameraController>),
+            )
+            .init_resource::<CameraInputBlockers>()
+            .init_resource::<CoordinateSystem>()
+            .add_event::<ControlEvent>()
+            .add_event::<TargetedControlEvent<ControlEvent>>();
+
+        if !app.is_plugin_added::<LookConePlugin>() {
+            app.add_plugin(LookConePlugin);
+        }
+
+        if let Some(state_activation) = &self.state_activation {
+            state_activation(app);
+        }
+
+        if !self.override_input_system {
+            app.add_system(default_input_map.with_run_criteria(
+                input_available.pipe(and_in_controller_state::<ObservationCameraController>),
+            ));
+        }
+    }
+}
+
+/// Spawn this as a child of the vehicle's mount point entity. The `LookTransform` is then in the mount's local frame, so the
+/// camera moves rigidly with the vehicle and only the free-look is smoothed.
+#[derive(Bundle)]
+pub struct ObservationCameraBundle {
+    controller: ObservationCameraController,
+    #[bundle]
+    look_transform: LookTransformBundle,
+    look_cone: LookCone,
+    #[bundle]
+    camera: Camera3dBundle,
+}
+
+impl ObservationCameraBundle {
+    pub fn new(controller: ObservationCameraController, mut camera: Camera3dBundle) -> Self {
+        // Start looking forward (-Z) from the mount point.
+        camera.transform = Transform::IDENTITY;
+
+        Self {
+            controller,
+            look_transform: LookTransformBundle {
+                transform: LookTransform::new(Vec3::ZERO, FORWARD),
+                smoother: Smoother::new(controller.smoothing_weight),
+            },
+            look_cone: LookCone::new(
+                ConeReference::Direction(FORWARD),
+                controller.max_look_angle,
+                controller.look_angle_soft_edge,
+            ),
+            camera,
+        }
+    }
+}
+
+/// The forward direction of the mount point.
+const FORWARD: Vec3 = Vec3::NEG_Z;
+
+/// A passenger or cockpit camera that looks around from a mount point on a vehicle, and returns to looking forward when
+/// left alone.
+#[derive(Clone, Component, Copy, Debug, Reflect)]
+#[cfg_attr(feature = "serialize", derive(Deserialize, Serialize))]
+#[reflect(Component)]
+#[cfg_attr(feature = "serialize", serde(default))]
+pub struct ObservationCameraController {
+    pub enabled: bool,
+    pub mouse_rotate_sensitivity: Vec2,
+    /// How far (in radians) the view may stray from the mount's forward direction.
+    pub max_look_angle: f32,
+    /// Free-look slows down within this many radians of `max_look_angle`.
+    pub look_angle_soft_edge: f32,
+    /// Seconds without input before the view returns to forward.
+    pub return_delay: f32,
+    /// How fast the view returns to forward, as the fraction of the remaining angle per second.
+    pub return_speed: f32,
+    pub smoothing_weight: f32,
+    /// Mouse motion shorter than this many pixels per frame is ignored, so jitter of a resting high-DPI mouse doesn't keep
+    /// the camera from settling.
+    pub mouse_deadband: f32,
+    #[cfg_attr(feature = "serialize", serde(skip))]
+    #[reflect(ignore)]
+    pub idle_time: f32,
+}
+
+impl Default for ObservationCameraController {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            mouse_rotate_sensitivity: Vec2::splat(0.002),
+            max_look_angle: 70f32.to_radians(),
+            look_angle_soft_edge: 10f32.to_radians(),
+            return_delay: 2.0,
+            return_speed: 2.0,
+            smoothing_weight: 0.8,
+            mouse_deadband: 0.0,
+            idle_time: 0.0,
+        }
+    }
+}
+
+impl CameraController for ObservationCameraController {
+    fn enabled(&self) -> bool {
+        self.enabled
+    }
+
+    fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub enum ControlEvent {
+    Rotate(Vec2),
+}
+
+define_on_controller_enabled_changed!(ObservationCameraController);
+
+pub fn default_input_map(
+    mut events: EventWriter<TargetedControlEvent<ControlEvent>>,
+    mut mouse_motion_events: EventReader<MouseMotion>,
+    controllers: Query<(Entity, &ObservationCameraController, Option<&InputDevices>)>,
+) {
+    // The mouse can only control one camera at a time.
+    let (camera, controller) = if let Some((camera, controller, _)) = controllers
+        .iter()
+        .find(|c| c.1.enabled && accepts_keyboard_mouse(c.2))
+    {
+        (camera, controller)
+    } else {
+        return;
+    };
+
+    let mut cursor_delta = Vec2::ZERO;
+    for event in mouse_motion_events.iter() {
+        cursor_delta += event.delta;
+    }
+    let cursor_delta = apply_deadband(cursor_delta, controller.mouse_deadband);
+
+    if cursor_delta != Vec2::ZERO {
+        events.send(TargetedControlEvent {
+            camera,
+            event: ControlEvent::Rotate(controller.mouse_rotate_sensitivity * cursor_delta),
+        });
+    }
+}
+
+pub fn control_system(
+    time: Res<Time>,
+    coordinates: Res<CoordinateSystem>,
+    mut events: EventReader<ControlEvent>,
+    mut targeted_events: EventReader<TargetedControlEvent<ControlEvent>>,
+    precision: Option<Res<PrecisionMode>>,
+    mut cameras: Query<(Entity, &mut ObservationCameraController, &mut LookTransform)>,
+) {
+    let first_enabled = cameras.iter().find(|c| c.1.enabled).map(|c| c.0);
+    let events = routed_control_events(
+        &mut events,
+        &mut targeted_events,
+        first_enabled,
+        precision.as_deref(),
+    );
+
+    for (camera, mut controller, mut transform) in cameras.iter_mut() {
+        if !controller.enabled {
+            continue;
+        }
+        let camera_events = events.iter().filter(|e| e.0 == camera).map(|e| &e.1);
+        // Only the idle time of the controller changes, which is runtime state, so don't trigger change detection.
+        let new_transform = control_camera(
+            controller.bypass_change_detection(),
+            *transform,
+            camera_events,
+            time.delta_seconds(),
+            &coordinates,
+        );
+        // Only write when moved, so the transform isn't marked as changed every frame.
+        if new_transform != *transform {
+            *transform = new_transform;
+        }
+    }
+}
+
+/// Applies `events` (and the return to the forward direction over `dt` seconds) to `transform` and returns the new
+/// transform, without any ECS plumbing. The idle time of the controller is updated in place.
+pub fn control_camera<'a>(
+    controller: &mut ObservationCameraController,
+    mut transform: LookTransform,
+    events: impl IntoIterator<Item = &'a ControlEvent>,
+    dt: f32,
+    coordinates: &CoordinateSystem,
+) -> LookTransform {
+    let mut look_angles =
+        LookAngles::from_vector_in(transform.look_direction().unwrap_or(FORWARD), *coordinates);
+    let mut rotated = false;
+    for event in events {
+        match event {
+            ControlEvent::Rotate(delta) => {
+                look_angles.add_yaw(-delta.x);
+                look_angles.add_pitch(-delta.y);
+                rotated = true;
+            }
+        }
+    }
+
+    let mut idle_time = if rotated {
+        0.0
+    } else {
+        controller.idle_time + dt
+    };
+
+    let mut direction = look_angles.unit_vector();
+    if idle_time > controller.return_delay {
+        let fraction = (controller.return_speed * dt).min(1.0);
+        direction = direction.lerp(FORWARD, fraction).normalize();
+        if direction.abs_diff_eq(FORWARD, 1e-4) {
+            direction = FORWARD;
+            idle_time = controller.return_delay;
+        }
+    }
+    transform.target = transform.eye + transform.radius() * direction;
+    controller.idle_time = idle_time;
+
+    transform
+}
@@ -1,41 +1,123 @@
-use crate::{LookAngles, LookTransform, LookTransformBundle, Smoother};
+use crate::{
+    camera_path_handoff_system,
+    controllers::{
+        apply_deadband,
+        blockers::CameraInputBlockers,
+        defaults::{
+            apply_controller_defaults_system, CameraControllerDefaults, DefaultableController,
+        },
+        devices::{accepts_gamepad, accepts_keyboard_mouse, InputDevices},
+        gamepad_available, input_available,
+        momentum::{AutoRotate, Momentum},
+        precision::PrecisionMode,
+        routed_control_events,
+        state::{and_in_controller_state, in_controller_state, state_activation, StateActivation},
+        touch_available,
+        tween::{tween_controller_system, TweenableController},
+        AxisLock, AxisLockState, CameraController, ChordTracker, GamepadInput, GamepadSticks,
+        InteractionMode, MouseChord, MouseChordBindings, PenInput, TargetedControlEvent,
+        TouchControls, TouchGesture,
+    },
+    cursor_ray, fit_sphere_distance, orthographic_scale_for_pixel_density, ray_plane_intersection,
+    send_gesture_transition, CameraFeedbackEvent, CameraIntentEvent, CoordinateSystem, Gesture,
+    LookAngles, LookTransform, LookTransformBundle, PivotProvider, Smoother,
+};
 
 use bevy::{
     app::prelude::*,
-    ecs::{bundle::Bundle, prelude::*},
+    ecs::{bundle::Bundle, prelude::*, schedule::StateData},
+    hierarchy::Children,
     input::{
         mouse::{MouseMotion, MouseScrollUnit, MouseWheel},
         prelude::*,
     },
     math::prelude::*,
+    prelude::Projection,
+    reflect::{FromReflect, Reflect},
+    render::{camera::Camera, primitives::Aabb},
     time::Time,
-    transform::components::Transform, prelude::Projection,
+    transform::components::{GlobalTransform, Transform},
+    window::Windows,
 };
+#[cfg(feature = "serialize")]
 use serde::{Deserialize, Serialize};
+use std::f32::consts::PI;
 
 #[derive(Default)]
 pub struct OrbitCameraPlugin {
     pub override_input_system: bool,
+    state_activation: Option<StateActivation>,
 }
 
 impl OrbitCameraPlugin {
     pub fn new(override_input_system: bool) -> Self {
         Self {
             override_input_system,
+            state_activation: None,
         }
     }
+
+    /// Only runs the controls and input maps while the app is in `state`. The smoothers are reset when it is entered.
+    pub fn run_in_state<S: StateData>(mut self, state: S) -> Self {
+        self.state_activation = Some(state_activation::<OrbitCameraController, S>(state));
+        self
+    }
 }
 
 impl Plugin for OrbitCameraPlugin {
     fn build(&self, app: &mut App) {
         let app = app
+            .register_type::<OrbitCameraController>()
+            .register_type::<AutoRotate>()
+            .register_type::<AxisLock>()
+            .register_type::<GamepadInput>()
+            .register_type::<InteractionMode>()
+            .register_type::<Momentum>()
+            .register_type::<MouseChord>()
+            .register_type::<MouseChordBindings>()
+            .register_type::<OrbitMode>()
+            .register_type::<PenInput>()
+            .register_type::<TouchControls>()
+            .register_type::<WheelTilt>()
             .add_startup_system_to_stage(StartupStage::PostStartup, setup_orthographic_transform)
             .add_system_to_stage(CoreStage::PreUpdate, on_controller_enabled_changed)
-            .add_system(control_system)
-            .add_event::<ControlEvent>();
+            .add_system_to_stage(
+                CoreStage::PreUpdate,
+                camera_path_handoff_system::<OrbitCameraController>
+                    .before(on_controller_enabled_changed),
+            )
+            .add_system(
+                control_system.with_run_criteria(in_controller_state::<OrbitCameraController>),
+            )
+            .add_system(fit_to_scene_system)
+            .add_system(tween_controller_system::<OrbitCameraController>)
+            .add_system_to_stage(
+                CoreStage::PreUpdate,
+                apply_controller_defaults_system::<OrbitCameraController>,
+            )
+            .init_resource::<CameraControllerDefaults>()
+            .init_resource::<CoordinateSystem>()
+            .init_resource::<CameraInputBlockers>()
+            .add_event::<ControlEvent>()
+            .add_event::<TargetedControlEvent<ControlEvent>>()
+            .add_event::<FitToSceneEvent>()
+            .add_event::<CameraFeedbackEvent>()
+            .add_event::<CameraIntentEvent>();
+
+        if let Some(state_activation) = &self.state_activation {
+            state_activation(app);
+        }
 
         if !self.override_input_system {
-            app.add_system(default_input_map);
+            app.add_system(default_input_map.with_run_criteria(
+                input_available.pipe(and_in_controller_state::<OrbitCameraController>),
+            ))
+            .add_system(gamepad_input_map.with_run_criteria(
+                gamepad_available.pipe(and_in_controller_state::<OrbitCameraController>),
+            ))
+            .add_system(touch_input_map.with_run_criteria(
+                touch_available.pipe(and_in_controller_state::<OrbitCameraController>),
+            ));
         }
     }
 }
@@ -65,7 +147,10 @@ impl OrbitCameraBundle {
 }
 
 /// A 3rd person camera that orbits around the target.
-#[derive(Clone, Component, Copy, Debug, Deserialize, Serialize)]
+#[derive(Clone, Component, Copy, Debug, Reflect)]
+#[cfg_attr(feature = "serialize", derive(Deserialize, Serialize))]
+#[reflect(Component)]
+#[cfg_attr(feature = "serialize", serde(default))]
 pub struct OrbitCameraController {
     pub enabled: bool,
     pub mouse_rotate_sensitivity: Vec2,
@@ -73,6 +158,66 @@ pub struct OrbitCameraController {
     pub mouse_wheel_zoom_sensitivity: f32,
     pub pixels_per_line: f32,
     pub smoothing_weight: f32,
+    /// Mouse motion shorter than this many pixels per frame is ignored, so jitter of a resting high-DPI mouse doesn't keep
+    /// the camera from settling.
+    pub mouse_deadband: f32,
+    pub interaction_mode: InteractionMode,
+    /// Pen/tablet jitter filtering. The barrel button is not remapped, it acts as whichever mouse button the pen reports.
+    pub pen_input: Option<PenInput>,
+    /// When set, holding its modifier constrains orbit and pan drags to their dominant axis, e.g. to orbit near the poles
+    /// or pan horizontally without drifting.
+    pub axis_lock: Option<AxisLock>,
+    /// When set, `mouse_rotate_sensitivity` is per viewport width instead of per pixel, so the same drag relative to the
+    /// viewport rotates the same amount regardless of the viewport's size.
+    pub viewport_relative_rotation: bool,
+    /// When set, orbiting pivots around the point under the cursor at the start of the drag (on the plane through the
+    /// target facing the camera) instead of the target, keeping that point fixed on screen.
+    pub cursor_anchored_rotation: bool,
+    /// When set, an orbit drag first re-targets the camera at the scene point under the cursor found by the
+    /// `PivotProvider` resource, keeping the eye in place, and the `Smoother` turns the view toward it. Drags that miss
+    /// the scene orbit the current target.
+    pub pivot_under_cursor: bool,
+    /// When set, scrolling zooms toward the point under the cursor (on the plane through the target facing the camera)
+    /// instead of the target, keeping that point fixed on screen. Orthographic cameras pan as they scale.
+    pub zoom_to_cursor: bool,
+    pub orbit_mode: OrbitMode,
+    /// Limits (in radians) of the eye's elevation above the target, e.g. `0.0` as the minimum keeps the eye above the
+    /// ground plane through the target.
+    pub min_pitch: f32,
+    pub max_pitch: f32,
+    /// When set, the target stays at this height (along the world up axis). Panning moves it parallel to the ground instead of the view plane,
+    /// so map-style cameras keep focusing the ground even with a tilted view.
+    pub target_height_lock: Option<f32>,
+    /// When set, panning grabs the ground plane at this height (along the world up axis): the ground point under the
+    /// cursor at the start of the drag stays under the cursor, like dragging a map. `mouse_translate_sensitivity` doesn't apply.
+    pub grab_pan_height: Option<f32>,
+    /// The ground point held under the cursor by the current grab pan.
+    #[cfg_attr(feature = "serialize", serde(skip))]
+    #[reflect(ignore)]
+    pub grab_point: Option<Vec3>,
+    /// When set, scrolling while holding its modifier tilts the view instead of zooming, like in map apps.
+    pub wheel_tilt: Option<WheelTilt>,
+    /// When set, orbiting, panning and zooming are bound to these mouse button chords instead of the default bindings
+    /// (left control to orbit, right button to pan). `interaction_mode` doesn't apply to chords.
+    pub mouse_chords: Option<MouseChordBindings>,
+    /// Limits of the distance between the eye and the target of perspective cameras.
+    pub min_radius: f32,
+    pub max_radius: f32,
+    /// Limits of the projection scale of orthographic cameras.
+    pub min_scale: f32,
+    pub max_scale: f32,
+    /// When set, orthographic cameras can't zoom in further than this many world units per (physical) pixel, e.g. the size
+    /// of a texel to stop at 1:1 pixel density in 2D apps.
+    pub min_world_units_per_pixel: Option<f32>,
+    /// Keeps orbiting after a drag is released.
+    pub momentum: Option<Momentum>,
+    /// Orbits around the target while the camera is idle.
+    pub auto_rotate: Option<AutoRotate>,
+    /// Right stick: orbit, left stick: pan, triggers: zoom. Panning speed is in multiples of the eye-target distance per
+    /// second, and zoom speed is the rate of the exponential zoom per second.
+    pub gamepad: GamepadInput,
+    /// One finger: orbit, two fingers: pan, pinch: zoom.
+    pub touch: Option<TouchControls>,
 }
 
 impl Default for OrbitCameraController {
@@ -82,20 +227,341 @@ impl Default for OrbitCameraController {
             mouse_translate_sensitivity: Vec2::splat(0.1),
             mouse_wheel_zoom_sensitivity: 0.2,
             smoothing_weight: 0.8,
+            mouse_deadband: 0.0,
             enabled: true,
             pixels_per_line: 53.0,
+            interaction_mode: InteractionMode::Hold,
+            pen_input: None,
+            axis_lock: None,
+            viewport_relative_rotation: false,
+            cursor_anchored_rotation: false,
+            pivot_under_cursor: false,
+            zoom_to_cursor: false,
+            orbit_mode: OrbitMode::Turntable,
+            min_pitch: -PI / 2.0,
+            max_pitch: PI / 2.0,
+            target_height_lock: None,
+            grab_pan_height: None,
+            grab_point: None,
+            wheel_tilt: None,
+            mouse_chords: None,
+            min_radius: 0.001,
+            max_radius: 1000000.0,
+            min_scale: 0.0,
+            max_scale: f32::MAX,
+            min_world_units_per_pixel: None,
+            momentum: None,
+            auto_rotate: None,
+            gamepad: GamepadInput {
+                look_sensitivity: Vec2::splat(2.0),
+                translate_sensitivity: 1.0,
+                zoom_sensitivity: 1.5,
+                ..Default::default()
+            },
+            touch: Some(TouchControls::default()),
+        }
+    }
+}
+
+/// Binding of the scroll-to-tilt gesture.
+#[derive(Clone, Copy, Debug, FromReflect, PartialEq, Reflect)]
+#[cfg_attr(feature = "serialize", derive(Deserialize, Serialize))]
+pub struct WheelTilt {
+    pub modifier: KeyCode,
+    /// Radians of pitch per scrolled line. Scrolling up tilts toward a top-down view.
+    pub sensitivity: f32,
+}
+
+impl Default for WheelTilt {
+    fn default() -> Self {
+        Self {
+            modifier: KeyCode::LShift,
+            sensitivity: 0.05,
+        }
+    }
+}
+
+/// How orbit drags map to rotations of the eye around the target.
+#[derive(Clone, Copy, Debug, Default, Eq, FromReflect, PartialEq, Reflect)]
+#[cfg_attr(feature = "serialize", derive(Deserialize, Serialize))]
+pub enum OrbitMode {
+    /// Yaw always rotates about the world up axis and pitch about the camera's right axis, like a gimbal with azimuth and
+    /// elevation. The camera never rolls and dragging back along the same path always returns to the same view.
+    #[default]
+    Turntable,
+    /// Yaw and pitch rotate about the camera's current up and right axes, so a horizontal drag while looking down follows a
+    /// great circle instead of a circle of latitude.
+    Trackball,
+}
+
+impl OrbitMode {
+    /// Rotates the target-to-eye direction `angles` by `delta` (yaw, pitch) in radians.
+    pub fn rotate(self, angles: LookAngles, delta: Vec2) -> LookAngles {
+        match self {
+            OrbitMode::Turntable => {
+                let mut angles = angles;
+                angles.add_yaw(delta.x);
+                angles.add_pitch(delta.y);
+                angles
+            }
+            OrbitMode::Trackball => {
+                let direction = angles.unit_vector();
+                let right = angles.coordinate_system().up().cross(direction).normalize();
+                let up = direction.cross(right);
+                let rotation = Quat::from_axis_angle(up, delta.x)
+                    * Quat::from_axis_angle(direction.cross(up), delta.y);
+                // The pitch is still clamped away from the poles, since the `LookTransform` has a fixed up axis.
+                LookAngles::from_vector_in(rotation * direction, angles.coordinate_system())
+            }
+        }
+    }
+}
+
+impl OrbitCameraController {
+    /// The current (yaw, pitch) angular velocity from momentum and auto-rotation in radians per second, e.g. to show a
+    /// spinning indicator.
+    pub fn angular_velocity(&self) -> Vec2 {
+        let momentum = self.momentum.map_or(Vec2::ZERO, |m| m.velocity());
+        let auto_rotate = self.auto_rotate.map_or(0.0, |a| a.velocity());
+
+        momentum + Vec2::new(auto_rotate, 0.0)
+    }
+
+    /// Clamps the pitch of the target-to-eye direction `angles` to `min_pitch..=max_pitch`.
+    pub fn clamp_pitch(&self, mut angles: LookAngles) -> LookAngles {
+        angles.set_pitch(
+            angles
+                .get_pitch()
+                .clamp(self.min_pitch, self.max_pitch.max(self.min_pitch)),
+        );
+
+        angles
+    }
+}
+
+impl OrbitCameraController {
+    /// The (min, max) orthographic scale, combining `min_scale`/`max_scale` with `min_world_units_per_pixel`.
+    pub fn scale_limits(&self, projection: &Projection, camera: Option<&Camera>) -> (f32, f32) {
+        let mut min_scale = self.min_scale;
+        if let (Some(world_units_per_pixel), Projection::Orthographic(orthographic), Some(size)) = (
+            self.min_world_units_per_pixel,
+            projection,
+            camera.and_then(Camera::physical_viewport_size),
+        ) {
+            let unit_view_height = orthographic.top - orthographic.bottom;
+            if unit_view_height > 0.0 {
+                min_scale = min_scale.max(orthographic_scale_for_pixel_density(
+                    world_units_per_pixel,
+                    unit_view_height,
+                    size.y as f32,
+                ));
+            }
         }
+
+        (min_scale, self.max_scale.max(min_scale))
+    }
+}
+
+impl CameraController for OrbitCameraController {
+    fn enabled(&self) -> bool {
+        self.enabled
+    }
+
+    fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+    }
+}
+
+impl DefaultableController for OrbitCameraController {
+    fn from_defaults(defaults: &CameraControllerDefaults) -> Self {
+        defaults.orbit
+    }
+
+    fn apply_settings(&mut self, settings: &Self) {
+        let previous = *self;
+        *self = *settings;
+        self.enabled = previous.enabled;
+        self.grab_point = previous.grab_point;
+        if let (Some(momentum), Some(previous)) = (&mut self.momentum, previous.momentum) {
+            momentum.set_velocity(previous.velocity());
+        }
+        if let (Some(auto_rotate), Some(previous)) = (&mut self.auto_rotate, previous.auto_rotate) {
+            auto_rotate.continue_from(&previous);
+        }
+    }
+
+    fn smoothing_weight(&self) -> f32 {
+        self.smoothing_weight
+    }
+}
+
+impl TweenableController for OrbitCameraController {
+    fn tween_parameters(&mut self, start: &Self, end: &Self, t: f32) {
+        self.mouse_rotate_sensitivity = start
+            .mouse_rotate_sensitivity
+            .lerp(end.mouse_rotate_sensitivity, t);
+        self.mouse_translate_sensitivity = start
+            .mouse_translate_sensitivity
+            .lerp(end.mouse_translate_sensitivity, t);
+        self.mouse_wheel_zoom_sensitivity = start.mouse_wheel_zoom_sensitivity
+            + (end.mouse_wheel_zoom_sensitivity - start.mouse_wheel_zoom_sensitivity) * t;
     }
 }
 
+#[derive(Clone, Copy, Debug)]
 pub enum ControlEvent {
     Orbit(Vec2),
+    /// Like `Orbit`, but rotates both the eye and the target around `pivot`.
+    OrbitAbout {
+        pivot: Vec3,
+        delta: Vec2,
+    },
     TranslateTarget(Vec2),
+    /// Moves the camera so the ground point grabbed at the `start` of the drag is under `cursor` (in logical pixels of the
+    /// viewport). Requires `grab_pan_height`.
+    GrabPan {
+        cursor: Vec2,
+        start: bool,
+    },
     Zoom(f32),
+    /// Like `Zoom`, but scales the eye and target about `anchor`, so it stays fixed on screen.
+    ZoomAbout {
+        anchor: Vec3,
+        scalar: f32,
+    },
+    /// Changes the pitch by this many radians, e.g. from the scroll-to-tilt gesture.
+    Tilt(f32),
+    /// Banks the camera by this many radians about the look direction, see `LookTransform::roll`.
+    Roll(f32),
+    /// Sets the distance between the eye and the target.
+    SetRadius(f32),
+    /// Sets the angles of the direction from the target to the eye.
+    SetYawPitch {
+        yaw: f32,
+        pitch: f32,
+    },
+    /// Moves the target (and the eye with it) to this point.
+    SetTarget(Vec3),
+    /// Moves the target to this point, keeping the eye in place, so following orbits pivot around it.
+    SetPivot(Vec3),
+    /// Sets the projection scale of an orthographic camera.
+    SetScale(f32),
+}
+
+/// Discrete zoom levels, like the zoom steps of a map app. When present, scrolling moves to the next or previous level
+/// instead of zooming continuously, and the `Smoother` animates the transition.
+///
+/// Levels are eye-to-target distances for perspective cameras and projection scales for orthographic ones.
+#[derive(Clone, Component, Debug, Default)]
+pub struct ZoomSteps {
+    pub levels: Vec<f32>,
+}
+
+impl ZoomSteps {
+    pub fn new(mut levels: Vec<f32>) -> Self {
+        levels.sort_by(f32::total_cmp);
+
+        Self { levels }
+    }
+
+    /// Returns the level `steps` away from the level closest to `current`, clamped to the available levels.
+    pub fn step(&self, current: f32, steps: i32) -> Option<f32> {
+        let (nearest, _) = self
+            .levels
+            .iter()
+            .enumerate()
+            .min_by(|(_, a), (_, b)| (*a - current).abs().total_cmp(&(*b - current).abs()))?;
+        let index = (nearest as i32 + steps).clamp(0, self.levels.len() as i32 - 1);
+
+        Some(self.levels[index as usize])
+    }
+}
+
+/// Frames all meshes below (and including) `scene_root` with the active orbit camera, e.g. once a glTF scene has spawned.
+/// Mesh bounds are computed by bevy a frame after the meshes spawn, so send this after that.
+pub struct FitToSceneEvent {
+    pub scene_root: Entity,
 }
 
 define_on_controller_enabled_changed!(OrbitCameraController);
 
+fn fit_to_scene_system(
+    mut fit_events: EventReader<FitToSceneEvent>,
+    mut events: EventWriter<ControlEvent>,
+    mut intent_events: EventWriter<CameraIntentEvent>,
+    cameras: Query<(Entity, &OrbitCameraController, &Projection)>,
+    meshes: Query<(&Aabb, &GlobalTransform)>,
+    children: Query<&Children>,
+) {
+    for fit in fit_events.iter() {
+        let (camera, projection) =
+            if let Some((camera, _, projection)) = cameras.iter().find(|c| c.1.enabled) {
+                (camera, projection)
+            } else {
+                return;
+            };
+
+        let mut min = Vec3::splat(f32::INFINITY);
+        let mut max = Vec3::splat(f32::NEG_INFINITY);
+        let mut stack = vec![fit.scene_root];
+        while let Some(entity) = stack.pop() {
+            if let Ok((aabb, global_transform)) = meshes.get(entity) {
+                let affine = global_transform.affine();
+                let center = affine.transform_point3(aabb.center.into());
+                let half_extents = Vec3::from(
+                    affine.matrix3.x_axis.abs() * aabb.half_extents.x
+                        + affine.matrix3.y_axis.abs() * aabb.half_extents.y
+                        + affine.matrix3.z_axis.abs() * aabb.half_extents.z,
+                );
+                min = min.min(center - half_extents);
+                max = max.max(center + half_extents);
+            }
+            if let Ok(entity_children) = children.get(entity) {
+                stack.extend(entity_children.iter().copied());
+            }
+        }
+        if min.cmpgt(max).any() {
+            // No meshes with bounds yet.
+            continue;
+        }
+
+        let center = 0.5 * (min + max);
+        let sphere_radius = 0.5 * (max - min).length();
+        events.send(ControlEvent::SetTarget(center));
+        let (radius, scale) = scene_framing(projection, sphere_radius);
+        events.send(ControlEvent::SetRadius(radius));
+        if let Some(scale) = scale {
+            events.send(ControlEvent::SetScale(scale));
+        }
+        intent_events.send(CameraIntentEvent::UsedFraming { camera });
+    }
+}
+
+/// The radius, and for orthographic cameras the scale, at which `projection` shows a sphere of `sphere_radius` whole.
+fn scene_framing(projection: &Projection, sphere_radius: f32) -> (f32, Option<f32>) {
+    match projection {
+        Projection::Perspective(perspective) => {
+            // The narrower of the two FOVs limits what fits, e.g. the horizontal one on portrait viewports.
+            let horizontal_fov =
+                2.0 * ((0.5 * perspective.fov).tan() * perspective.aspect_ratio).atan();
+            let fov = perspective.fov.min(horizontal_fov);
+
+            (fit_sphere_distance(sphere_radius, fov), None)
+        }
+        Projection::Orthographic(orthographic) => {
+            let unit_view_size = Vec2::new(
+                orthographic.right - orthographic.left,
+                orthographic.top - orthographic.bottom,
+            );
+            // The scale at which the sphere's bounding square fits both ways.
+            let scale = (2.0 * sphere_radius / unit_view_size).max_element();
+
+            // Keep the eye outside of the scene so nothing is clipped by the near plane.
+            (2.0 * sphere_radius, Some(scale))
+        }
+    }
+}
+
 fn setup_orthographic_transform(
     mut cameras: Query<(&OrbitCameraController, &mut LookTransform, &Projection)>,
 ) {
@@ -113,25 +579,64 @@ fn setup_orthographic_transform(
     transform.scale = scale;
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn default_input_map(
-    mut events: EventWriter<ControlEvent>,
+    mut events: EventWriter<TargetedControlEvent<ControlEvent>>,
+    mut feedback_events: EventWriter<CameraFeedbackEvent>,
     mut mouse_wheel_reader: EventReader<MouseWheel>,
     mut mouse_motion_events: EventReader<MouseMotion>,
     mouse_buttons: Res<Input<MouseButton>>,
     keyboard: Res<Input<KeyCode>>,
-    controllers: Query<&OrbitCameraController>,
+    windows: Option<Res<Windows>>,
+    controllers: Query<(
+        Entity,
+        &OrbitCameraController,
+        Option<&Camera>,
+        Option<&InputDevices>,
+    )>,
+    zoom_steps: Query<(&ZoomSteps, &LookTransform, Option<&Projection>)>,
+    pivot_cameras: Query<(&Camera, &GlobalTransform, &LookTransform)>,
+    mut toggled: Local<(bool, bool)>,
+    mut chord_tracker: Local<ChordTracker>,
+    (mut was_orbiting, mut was_panning, mut was_zooming): (Local<bool>, Local<bool>, Local<bool>),
+    pivot_provider: Option<Res<PivotProvider>>,
+    (mut orbit_pivot, mut axis_lock_state, mut zoom_step_lines): (
+        Local<Option<Vec3>>,
+        Local<AxisLockState>,
+        Local<f32>,
+    ),
 ) {
-    // Can only control one camera at a time.
-    let controller = if let Some(controller) = controllers.iter().find(|c| c.enabled) {
-        controller
+    // The keyboard and mouse can only control one camera at a time.
+    let (entity, controller, camera) = if let Some((entity, controller, camera, _)) = controllers
+        .iter()
+        .find(|c| c.1.enabled && accepts_keyboard_mouse(c.3))
+    {
+        (entity, controller, camera)
     } else {
         return;
     };
+    let mut send = |event| {
+        events.send(TargetedControlEvent {
+            camera: entity,
+            event,
+        })
+    };
     let OrbitCameraController {
         mouse_rotate_sensitivity,
         mouse_translate_sensitivity,
         mouse_wheel_zoom_sensitivity,
         pixels_per_line,
+        interaction_mode,
+        pen_input,
+        viewport_relative_rotation,
+        cursor_anchored_rotation,
+        pivot_under_cursor,
+        wheel_tilt,
+        mouse_chords,
+        mouse_deadband,
+        grab_pan_height,
+        axis_lock,
+        zoom_to_cursor,
         ..
     } = *controller;
 
@@ -139,17 +644,166 @@ pub fn default_input_map(
     for event in mouse_motion_events.iter() {
         cursor_delta += event.delta;
     }
+    cursor_delta = apply_deadband(cursor_delta, mouse_deadband);
+    if let Some(pen_input) = pen_input {
+        cursor_delta = pen_input.filter_motion(cursor_delta);
+    }
 
-    if keyboard.pressed(KeyCode::LControl) {
-        events.send(ControlEvent::Orbit(mouse_rotate_sensitivity * cursor_delta));
+    let (orbit_toggled, translate_toggled) = &mut *toggled;
+    let (orbiting, panning, zooming) = match mouse_chords {
+        Some(chords) => {
+            chords.active_gestures(chord_tracker.update(MouseChord::pressed(&mouse_buttons)))
+        }
+        None => (
+            interaction_mode.is_active(&keyboard, KeyCode::LControl, orbit_toggled),
+            interaction_mode.is_active(&mouse_buttons, MouseButton::Right, translate_toggled),
+            false,
+        ),
+    };
+    match axis_lock {
+        Some(axis_lock) if orbiting || panning => {
+            let locking = keyboard.pressed(axis_lock.modifier);
+            cursor_delta = axis_lock_state.filter(&axis_lock, locking, cursor_delta);
+        }
+        _ => axis_lock_state.reset(),
     }
 
-    if mouse_buttons.pressed(MouseButton::Right) {
-        events.send(ControlEvent::TranslateTarget(
-            mouse_translate_sensitivity * cursor_delta,
+    let cursor = windows
+        .as_ref()
+        .and_then(|windows| windows.get_primary())
+        .and_then(|w| w.cursor_position());
+    // The point under the cursor on the plane through the target facing the camera.
+    let cursor_point = || match (cursor, pivot_cameras.get(entity)) {
+        (Some(cursor), Ok((camera, camera_transform, transform))) => {
+            cursor_ray(camera, camera_transform, cursor).and_then(|(origin, direction)| {
+                ray_plane_intersection(
+                    origin,
+                    direction,
+                    transform.target,
+                    transform.look_direction()?,
+                )
+            })
+        }
+        _ => None,
+    };
+    let pan_started = panning && !*was_panning;
+    if !orbiting {
+        *orbit_pivot = None;
+    } else if !*was_orbiting {
+        let provider = pivot_provider.as_deref().filter(|_| pivot_under_cursor);
+        let picked_pivot = match (provider, cursor, pivot_cameras.get(entity)) {
+            (Some(provider), Some(cursor), Ok((camera, camera_transform, _))) => {
+                cursor_ray(camera, camera_transform, cursor)
+                    .and_then(|(origin, direction)| provider.pick(origin, direction))
+            }
+            _ => None,
+        };
+        if let Some(pivot) = picked_pivot {
+            send(ControlEvent::SetPivot(pivot));
+        } else if cursor_anchored_rotation {
+            *orbit_pivot = cursor_point();
+        }
+    }
+    send_gesture_transition(
+        &mut feedback_events,
+        entity,
+        Gesture::Orbit,
+        orbiting,
+        &mut was_orbiting,
+    );
+    send_gesture_transition(
+        &mut feedback_events,
+        entity,
+        Gesture::Pan,
+        panning,
+        &mut was_panning,
+    );
+    send_gesture_transition(
+        &mut feedback_events,
+        entity,
+        Gesture::Zoom,
+        zooming,
+        &mut was_zooming,
+    );
+
+    if orbiting {
+        let mut rotate_delta = cursor_delta;
+        if viewport_relative_rotation {
+            if let Some(viewport_size) = camera.and_then(Camera::logical_viewport_size) {
+                rotate_delta /= viewport_size.x.max(1.0);
+            }
+        }
+        let delta = mouse_rotate_sensitivity * rotate_delta;
+        send(match *orbit_pivot {
+            Some(pivot) => ControlEvent::OrbitAbout { pivot, delta },
+            None => ControlEvent::Orbit(delta),
+        });
+    }
+
+    if panning {
+        if grab_pan_height.is_none() {
+            send(ControlEvent::TranslateTarget(
+                mouse_translate_sensitivity * cursor_delta,
+            ));
+        } else if let Some(cursor) = cursor {
+            send(ControlEvent::GrabPan {
+                cursor,
+                start: pan_started,
+            });
+        }
+    }
+
+    if let Some(chords) = mouse_chords.filter(|_| zooming) {
+        send(ControlEvent::Zoom(
+            (cursor_delta.y * chords.zoom_sensitivity).exp(),
         ));
     }
 
+    if let Some(wheel_tilt) = wheel_tilt.filter(|t| keyboard.pressed(t.modifier)) {
+        let mut lines = 0.0;
+        for event in mouse_wheel_reader.iter() {
+            lines += match event.unit {
+                MouseScrollUnit::Line => event.y,
+                MouseScrollUnit::Pixel => event.y / pixels_per_line,
+            };
+        }
+        if lines != 0.0 {
+            send(ControlEvent::Tilt(lines * wheel_tilt.sensitivity));
+        }
+        return;
+    }
+
+    if let Ok((zoom_steps, transform, projection)) = zoom_steps.get(entity) {
+        for event in mouse_wheel_reader.iter() {
+            *zoom_step_lines += match event.unit {
+                MouseScrollUnit::Line => event.y,
+                MouseScrollUnit::Pixel => event.y / pixels_per_line,
+            };
+        }
+        // Trackpads scroll a few pixels per frame, so only step once a whole line has built up.
+        let scroll_amount = zoom_step_lines.trunc();
+        if scroll_amount == 0.0 {
+            return;
+        }
+        *zoom_step_lines = zoom_step_lines.fract();
+
+        // Scrolling up zooms in, toward smaller levels.
+        let steps = -scroll_amount.signum() as i32;
+        match projection {
+            Some(Projection::Orthographic(_)) => {
+                if let Some(scale) = zoom_steps.step(transform.scale, steps) {
+                    send(ControlEvent::SetScale(scale));
+                }
+            }
+            _ => {
+                if let Some(radius) = zoom_steps.step(transform.radius(), steps) {
+                    send(ControlEvent::SetRadius(radius));
+                }
+            }
+        }
+        return;
+    }
+
     let mut scalar = 1.0;
     for event in mouse_wheel_reader.iter() {
         // scale the event magnitude per pixel or per line
@@ -159,36 +813,258 @@ pub fn default_input_map(
         };
         scalar *= 1.0 - scroll_amount * mouse_wheel_zoom_sensitivity;
     }
-    events.send(ControlEvent::Zoom(scalar));
+    match cursor_point().filter(|_| zoom_to_cursor && scalar != 1.0) {
+        Some(anchor) => send(ControlEvent::ZoomAbout { anchor, scalar }),
+        None => send(ControlEvent::Zoom(scalar)),
+    }
 }
 
+#[allow(clippy::type_complexity)]
+pub fn gamepad_input_map(
+    time: Res<Time>,
+    mut events: EventWriter<TargetedControlEvent<ControlEvent>>,
+    gamepads: Res<Gamepads>,
+    axes: Res<Axis<GamepadAxis>>,
+    button_axes: Res<Axis<GamepadButton>>,
+    controllers: Query<(
+        Entity,
+        &OrbitCameraController,
+        &LookTransform,
+        Option<&Projection>,
+        Option<&InputDevices>,
+    )>,
+) {
+    let dt = time.delta_seconds();
+    for gamepad in gamepads.iter() {
+        // Each gamepad controls the first enabled camera that accepts it.
+        let (camera, controller, transform, projection) =
+            if let Some((camera, controller, transform, projection, _)) = controllers
+                .iter()
+                .find(|c| c.1.enabled && accepts_gamepad(c.4, gamepad))
+            {
+                (camera, controller, transform, projection)
+            } else {
+                continue;
+            };
+        let mut send = |event| events.send(TargetedControlEvent { camera, event });
+        let GamepadInput {
+            deadzone,
+            look_sensitivity,
+            translate_sensitivity,
+            zoom_sensitivity,
+        } = controller.gamepad;
+
+        let sticks = GamepadSticks::read(gamepad, deadzone, &axes, &button_axes);
+        if sticks.is_idle() {
+            continue;
+        }
+
+        // Orbit and pan events are scaled by the frame time when they are applied.
+        let look = sticks.right_stick;
+        if look != Vec2::ZERO {
+            // Pushing the stick up orbits like dragging the mouse up.
+            send(ControlEvent::Orbit(
+                look_sensitivity * Vec2::new(look.x, -look.y),
+            ));
+        }
+
+        let pan = sticks.left_stick;
+        if pan != Vec2::ZERO {
+            // Orthographic panning is already scaled by the projection.
+            let distance = match projection {
+                Some(Projection::Orthographic(_)) => 1.0,
+                _ => transform.radius(),
+            };
+            // Pushing the stick right or up moves the view right or up.
+            send(ControlEvent::TranslateTarget(
+                translate_sensitivity * distance * Vec2::new(-pan.x, pan.y),
+            ));
+        }
+
+        let zoom = sticks.left_trigger - sticks.right_trigger;
+        if zoom != 0.0 {
+            send(ControlEvent::Zoom((dt * zoom_sensitivity * zoom).exp()));
+        }
+    }
+}
+
+pub fn touch_input_map(
+    mut events: EventWriter<TargetedControlEvent<ControlEvent>>,
+    touches: Res<Touches>,
+    controllers: Query<(Entity, &OrbitCameraController, Option<&InputDevices>)>,
+) {
+    // Touch input goes to the camera of the keyboard and mouse.
+    let (camera, controller, touch) = if let Some((camera, controller, _)) = controllers
+        .iter()
+        .find(|c| c.1.enabled && accepts_keyboard_mouse(c.2))
+    {
+        match controller.touch {
+            Some(touch) => (camera, controller, touch),
+            None => return,
+        }
+    } else {
+        return;
+    };
+    let mut send = |event| events.send(TargetedControlEvent { camera, event });
+
+    match TouchGesture::from_touches(&touches) {
+        TouchGesture::None => (),
+        TouchGesture::Drag(delta) => {
+            send(ControlEvent::Orbit(
+                touch.rotate_scale * controller.mouse_rotate_sensitivity * delta,
+            ));
+        }
+        TouchGesture::TwoFinger {
+            pan,
+            previous_distance,
+            distance,
+        } => {
+            send(ControlEvent::TranslateTarget(
+                touch.pan_scale * controller.mouse_translate_sensitivity * pan,
+            ));
+            send(ControlEvent::Zoom(TouchGesture::pinch_zoom(
+                previous_distance,
+                distance,
+                touch.pinch_zoom_scale,
+            )));
+        }
+    }
+}
+
+#[allow(clippy::type_complexity)]
 pub fn control_system(
     time: Res<Time>,
+    coordinates: Res<CoordinateSystem>,
     mut events: EventReader<ControlEvent>,
-    mut cameras: Query<(&OrbitCameraController, &mut LookTransform, &Transform, &Projection)>,
+    mut targeted_events: EventReader<TargetedControlEvent<ControlEvent>>,
+    precision: Option<Res<PrecisionMode>>,
+    mut feedback_events: EventWriter<CameraFeedbackEvent>,
+    mut cameras: Query<(
+        Entity,
+        &mut OrbitCameraController,
+        &mut LookTransform,
+        &Transform,
+        &Projection,
+        Option<&Camera>,
+    )>,
 ) {
-    // Can only control one camera at a time.
-    let (mut transform, scene_transform, projection) =
-        if let Some((_, transform, scene_transform, proj)) = cameras.iter_mut().find(|c| c.0.enabled) {
-            (transform, scene_transform, proj)
+    let first_enabled = cameras.iter().find(|c| c.1.enabled).map(|c| c.0);
+    let events = routed_control_events(
+        &mut events,
+        &mut targeted_events,
+        first_enabled,
+        precision.as_deref(),
+    );
+
+    let precise = PrecisionMode::active_factor(precision.as_deref()).is_some();
+    for (entity, mut controller, mut transform, scene_transform, projection, camera) in
+        cameras.iter_mut()
+    {
+        if !controller.enabled {
+            continue;
+        }
+        let camera_events = events.iter().filter(|e| e.0 == entity).map(|e| &e.1);
+        // Only runtime state of the controller changes, so don't trigger change detection (which resets the smoother).
+        let controller = controller.bypass_change_detection();
+        // Precise adjustments shouldn't drift after the drag is released, so momentum is set aside meanwhile.
+        let set_aside = if precise {
+            controller.momentum.take()
         } else {
-            return;
+            None
         };
+        let output = control_camera(
+            controller,
+            *transform,
+            camera_events,
+            time.delta_seconds(),
+            scene_transform,
+            projection,
+            camera,
+            &coordinates,
+        );
+        if let Some(mut momentum) = set_aside {
+            momentum.stop();
+            controller.momentum = Some(momentum);
+        }
+        if output.zoom_limit_reached {
+            feedback_events.send(CameraFeedbackEvent::ZoomLimitReached { camera: entity });
+        }
+        // Only write when moved, so the transform isn't marked as changed every frame.
+        if output.transform != *transform {
+            *transform = output.transform;
+        }
+    }
+}
 
-    let mut look_angles = LookAngles::from_vector(-transform.look_direction().unwrap());
+/// The result of the orbit controller's `control_camera`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct OrbitControlOutput {
+    pub transform: LookTransform,
+    /// A zoom was clamped to the controller's radius or scale limits.
+    pub zoom_limit_reached: bool,
+}
+
+/// Applies `events` (and momentum and auto-rotation over `dt` seconds) to `transform` and returns the new transform,
+/// without any ECS plumbing, e.g. to unit test custom input maps or to drive the controller from a custom system. The
+/// runtime state of the controller, like its momentum, is updated in place.
+///
+/// `scene_transform` is the camera's (smoothed) `Transform`, which sets the directions of panning. `camera` is needed
+/// for `ControlEvent::GrabPan` and `min_world_units_per_pixel`.
+#[allow(clippy::too_many_arguments)]
+pub fn control_camera<'a>(
+    controller: &mut OrbitCameraController,
+    mut transform: LookTransform,
+    events: impl IntoIterator<Item = &'a ControlEvent>,
+    dt: f32,
+    scene_transform: &Transform,
+    projection: &Projection,
+    camera: Option<&Camera>,
+    coordinates: &CoordinateSystem,
+) -> OrbitControlOutput {
+    let mut look_angles =
+        LookAngles::from_vector_in(-transform.look_direction().unwrap(), *coordinates);
     let mut radius_scalar = 1.0;
+    let mut set_radius = None;
+    let mut zoom_anchor = None;
     let is_orthographic = matches!(projection, Projection::Orthographic(_));
 
-    let dt = time.delta_seconds();
-    for event in events.iter() {
+    let orbit_mode = controller.orbit_mode;
+    let mut orbit_velocity = None;
+    let mut interacted = false;
+    for event in events {
+        // The default input map sends a neutral zoom every frame.
+        if !matches!(event, ControlEvent::Zoom(scalar) if *scalar == 1.0) {
+            interacted = true;
+        }
         match event {
             ControlEvent::Orbit(delta) => {
-                look_angles.add_yaw(dt * -delta.x);
-                look_angles.add_pitch(dt * delta.y);
+                look_angles = orbit_mode.rotate(look_angles, dt * Vec2::new(-delta.x, delta.y));
+                *orbit_velocity.get_or_insert(Vec2::ZERO) += Vec2::new(-delta.x, delta.y);
+            }
+            ControlEvent::OrbitAbout { pivot, delta } => {
+                // Clamped before rotating about the pivot, so the eye and target stay consistent.
+                let new_look_angles =
+                    orbit_mode.rotate(look_angles, dt * Vec2::new(-delta.x, delta.y));
+                let new_look_angles = controller.clamp_pitch(new_look_angles);
+
+                let rotation = Quat::from_rotation_arc(
+                    look_angles.unit_vector(),
+                    new_look_angles.unit_vector(),
+                );
+                transform = transform.rotated_around(*pivot, rotation);
+                look_angles = new_look_angles;
             }
             ControlEvent::TranslateTarget(delta) => {
                 let right_dir = scene_transform.rotation * -Vec3::X;
-                let up_dir = scene_transform.rotation * Vec3::Y;
+                let mut up_dir = scene_transform.rotation * Vec3::Y;
+                if controller.target_height_lock.is_some() {
+                    // The camera's up axis projected onto the ground points forward when the view is tilted.
+                    let forward = scene_transform.rotation * Vec3::NEG_Z;
+                    up_dir = coordinates
+                        .horizontal(forward)
+                        .try_normalize()
+                        .unwrap_or(up_dir);
+                }
                 let mut translation = dt * delta.x * right_dir + dt * delta.y * up_dir;
                 if is_orthographic {
                     let scale = transform.scale * 0.5;
@@ -196,21 +1072,464 @@ pub fn control_system(
                 }
                 transform.target += translation;
             }
+            ControlEvent::GrabPan { cursor, start } => {
+                let (height, camera) = match (controller.grab_pan_height, camera) {
+                    (Some(height), Some(camera)) => (height, camera),
+                    _ => continue,
+                };
+                // Unproject from the unsmoothed transform, so the grabbed point ends up exactly under the cursor once
+                // the smoother settles.
+                let camera_transform = GlobalTransform::from(transform.to_transform(coordinates));
+                let hit = cursor_ray(camera, &camera_transform, *cursor).and_then(
+                    |(origin, direction)| {
+                        ray_plane_intersection(
+                            origin,
+                            direction,
+                            height * coordinates.up(),
+                            coordinates.up(),
+                        )
+                    },
+                );
+                if *start {
+                    controller.grab_point = hit;
+                } else if let (Some(grab_point), Some(hit)) = (controller.grab_point, hit) {
+                    // Both points are on the plane, so translating the camera parallel to it moves the hit by the
+                    // same offset.
+                    transform += grab_point - hit;
+                }
+            }
             ControlEvent::Zoom(scalar) => {
                 radius_scalar *= scalar;
             }
+            ControlEvent::ZoomAbout { anchor, scalar } => {
+                radius_scalar *= scalar;
+                zoom_anchor = Some(*anchor);
+            }
+            ControlEvent::Tilt(delta) => {
+                look_angles = orbit_mode.rotate(look_angles, Vec2::new(0.0, *delta));
+            }
+            ControlEvent::Roll(delta) => {
+                transform.roll += delta;
+            }
+            ControlEvent::SetRadius(radius) => {
+                set_radius = Some(*radius);
+                radius_scalar = 1.0;
+            }
+            ControlEvent::SetYawPitch { yaw, pitch } => {
+                look_angles.set_yaw(*yaw);
+                look_angles.set_pitch(*pitch);
+            }
+            ControlEvent::SetTarget(target) => {
+                transform.target = *target;
+            }
+            ControlEvent::SetPivot(pivot) => {
+                if let Some(direction) = (transform.eye - *pivot).try_normalize() {
+                    transform.target = *pivot;
+                    look_angles = LookAngles::from_vector_in(direction, *coordinates);
+                    set_radius = Some(transform.eye.distance(*pivot));
+                    radius_scalar = 1.0;
+                }
+            }
+            ControlEvent::SetScale(scale) => {
+                transform.scale = *scale;
+                radius_scalar = 1.0;
+            }
+        }
+    }
+
+    if let Some(momentum) = &mut controller.momentum {
+        match orbit_velocity {
+            Some(velocity) => momentum.set_velocity(velocity),
+            None if interacted => momentum.stop(),
+            None => {
+                look_angles = orbit_mode.rotate(look_angles, momentum.advance(dt));
+            }
+        }
+    }
+    if let Some(auto_rotate) = &mut controller.auto_rotate {
+        let spinning = controller.momentum.map_or(false, |m| m.is_moving());
+        if interacted || spinning {
+            auto_rotate.interrupt();
+        } else {
+            look_angles.add_yaw(auto_rotate.advance(dt));
         }
     }
 
+    if let Some(height) = controller.target_height_lock {
+        transform.target = coordinates.with_height(transform.target, height);
+    }
+
+    look_angles = controller.clamp_pitch(look_angles);
     look_angles.assert_not_looking_up();
 
+    let radius = set_radius.unwrap_or_else(|| transform.radius());
+    let zoom_limit_reached;
     if is_orthographic {
-        transform.scale *= radius_scalar;
-        transform.eye = transform.target + transform.radius() * look_angles.unit_vector();
+        let (min_scale, max_scale) = controller.scale_limits(projection, camera);
+        let old_scale = transform.scale;
+        let unclamped_scale = old_scale * radius_scalar;
+        transform.scale = unclamped_scale.clamp(min_scale, max_scale);
+        zoom_limit_reached = radius_scalar != 1.0 && transform.scale != unclamped_scale;
+        if let Some(anchor) = zoom_anchor.filter(|_| old_scale > 0.0) {
+            transform.target = scale_about(transform.target, anchor, transform.scale / old_scale);
+        }
+        transform.eye = transform.target + radius * look_angles.unit_vector();
     } else {
-        let new_radius = (radius_scalar * transform.radius())
-            .min(1000000.0)
-            .max(0.001);
+        let unclamped_radius = radius_scalar * radius;
+        let new_radius = unclamped_radius.clamp(
+            controller.min_radius,
+            controller.max_radius.max(controller.min_radius),
+        );
+        zoom_limit_reached = radius_scalar != 1.0 && new_radius != unclamped_radius;
+        if let Some(anchor) = zoom_anchor.filter(|_| radius > 0.0) {
+            // Scaling the target and the radius by the same (clamped) factor scales the whole view about the anchor.
+            transform.target = scale_about(transform.target, anchor, new_radius / radius);
+        }
         transform.eye = transform.target + new_radius * look_angles.unit_vector();
     }
+    OrbitControlOutput {
+        transform,
+        zoom_limit_reached,
+    }
+}
+
+/// Moves `point` toward (`factor < 1`) or away from `anchor`.
+fn scale_about(point: Vec3, anchor: Vec3, factor: f32) -> Vec3 {
+    anchor + factor * (point - anchor)
+}
+
+// ████████╗███████╗███████╗████████╗
+// ╚══██╔══╝██╔════╝██╔════╝╚══██╔══╝
+//    ██║   █████╗  ███████╗   ██║
+//    ██║   ██╔══╝  ╚════██║   ██║
+//    ██║   ███████╗███████║   ██║
+//    ╚═╝   ╚══════╝╚══════╝   ╚═╝
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn drag_loop(mode: OrbitMode, start: LookAngles, loops: usize) -> LookAngles {
+        let mut angles = start;
+        for _ in 0..loops {
+            for delta in [
+                Vec2::new(0.3, 0.0),
+                Vec2::new(0.0, 0.4),
+                Vec2::new(-0.3, 0.0),
+                Vec2::new(0.0, -0.4),
+            ] {
+                angles = mode.rotate(angles, delta);
+            }
+        }
+
+        angles
+    }
+
+    #[test]
+    fn test_turntable_drag_loop_returns_to_start() {
+        let start = LookAngles::from_vector(Vec3::new(1.0, 0.5, 2.0));
+        let end = drag_loop(OrbitMode::Turntable, start, 100);
+
+        assert!(end.unit_vector().abs_diff_eq(start.unit_vector(), 1e-4));
+    }
+
+    #[test]
+    fn test_zoom_about_anchor_keeps_anchor_direction() {
+        let (eye, target, anchor) = (
+            Vec3::new(0.0, 0.0, 10.0),
+            Vec3::ZERO,
+            Vec3::new(2.0, 1.0, 0.0),
+        );
+        let factor = 0.5;
+        let new_target = scale_about(target, anchor, factor);
+        let new_eye = new_target + factor * (eye - target);
+
+        // The anchor is seen in the same direction from the eye, so it stays fixed on screen.
+        let before = (anchor - eye).normalize();
+        let after = (anchor - new_eye).normalize();
+        assert!(before.abs_diff_eq(after, 1e-6));
+    }
+
+    #[test]
+    fn test_pitch_is_clamped_to_limits() {
+        let controller = OrbitCameraController {
+            min_pitch: 0.0,
+            max_pitch: 1.0,
+            ..Default::default()
+        };
+        let mut angles = LookAngles::from_vector(Vec3::new(0.0, 0.5, 2.0));
+        for _ in 0..20 {
+            angles =
+                controller.clamp_pitch(OrbitMode::Turntable.rotate(angles, Vec2::new(0.1, 0.3)));
+            assert!((0.0..=1.0).contains(&angles.get_pitch()));
+        }
+        assert_eq!(angles.get_pitch(), 1.0);
+
+        angles = controller.clamp_pitch(OrbitMode::Turntable.rotate(angles, Vec2::new(0.0, -5.0)));
+        assert_eq!(angles.get_pitch(), 0.0);
+    }
+
+    fn control(
+        controller: &mut OrbitCameraController,
+        transform: LookTransform,
+        event: ControlEvent,
+    ) -> OrbitControlOutput {
+        let scene_transform = Transform::from(transform);
+        let projection = Projection::Perspective(Default::default());
+        control_camera(
+            controller,
+            transform,
+            &[event],
+            1.0 / 60.0,
+            &scene_transform,
+            &projection,
+            None,
+            &CoordinateSystem::Y_UP,
+        )
+    }
+
+    #[test]
+    fn test_control_keeps_pitch_within_limits() {
+        let mut controller = OrbitCameraController {
+            min_pitch: -0.5,
+            max_pitch: 1.0,
+            ..Default::default()
+        };
+        let mut transform = LookTransform::new(Vec3::new(0.0, 2.0, 5.0), Vec3::ZERO);
+        for i in 0..1000 {
+            let delta = Vec2::new((i as f32 * 0.7).sin(), (i as f32 * 1.3).cos()) * 200.0;
+            transform = control(&mut controller, transform, ControlEvent::Orbit(delta)).transform;
+
+            let pitch = LookAngles::from_vector(-transform.look_direction().unwrap()).get_pitch();
+            assert!((-0.5 - 1e-4..=1.0 + 1e-4).contains(&pitch));
+        }
+    }
+
+    #[test]
+    fn test_control_zoom_is_monotonic() {
+        let mut controller = OrbitCameraController {
+            min_radius: 1.0,
+            ..Default::default()
+        };
+        let mut transform = LookTransform::new(Vec3::new(0.0, 2.0, 5.0), Vec3::ZERO);
+        let mut limit_reached = false;
+        for _ in 0..100 {
+            let output = control(&mut controller, transform, ControlEvent::Zoom(0.9));
+            assert!(output.transform.radius() <= transform.radius() + 1e-5);
+            assert_eq!(output.transform.target, transform.target);
+            limit_reached |= output.zoom_limit_reached;
+            transform = output.transform;
+        }
+        assert!((transform.radius() - 1.0).abs() < 1e-4);
+        assert!(limit_reached);
+    }
+
+    #[test]
+    fn test_trackball_drag_loop_drifts() {
+        let start = LookAngles::from_vector(Vec3::new(1.0, 0.5, 2.0));
+        let end = drag_loop(OrbitMode::Trackball, start, 1);
+
+        assert!(!end.unit_vector().abs_diff_eq(start.unit_vector(), 1e-3));
+    }
+
+    #[test]
+    fn test_turntable_never_rolls() {
+        let mut angles = LookAngles::from_vector(Vec3::Z);
+        for i in 0..1000 {
+            let delta = Vec2::new((i as f32 * 0.7).sin(), (i as f32 * 1.3).cos()) * 0.2;
+            angles = OrbitMode::Turntable.rotate(angles, delta);
+
+            let eye = 5.0 * angles.unit_vector();
+            let transform = Transform::from(LookTransform::new(eye, Vec3::ZERO));
+            assert!((transform.rotation * Vec3::X).y.abs() < 1e-4);
+        }
+    }
+
+    #[test]
+    fn test_modes_agree_at_the_horizon() {
+        let start = LookAngles::from_vector(Vec3::Z);
+        let delta = Vec2::new(0.5, 0.0);
+        let turntable = OrbitMode::Turntable.rotate(start, delta);
+        let trackball = OrbitMode::Trackball.rotate(start, delta);
+
+        assert!(turntable
+            .unit_vector()
+            .abs_diff_eq(trackball.unit_vector(), 1e-5));
+    }
+
+    #[test]
+    fn test_wheel_zoom_stops_at_max_radius() {
+        use bevy::{input::InputPlugin, prelude::PerspectiveProjection};
+
+        let mut app = App::new();
+        app.add_plugin(InputPlugin)
+            .init_resource::<Time>()
+            .add_plugin(OrbitCameraPlugin::default());
+        let controller = OrbitCameraController {
+            max_radius: 10.0,
+            ..Default::default()
+        };
+        let camera = app
+            .world
+            .spawn((
+                OrbitCameraBundle::new(controller, Vec3::new(0.0, 0.0, 5.0), Vec3::ZERO),
+                Projection::Perspective(PerspectiveProjection::default()),
+            ))
+            .id();
+
+        for _ in 0..20 {
+            // Scrolling down zooms out.
+            app.world.send_event(MouseWheel {
+                unit: MouseScrollUnit::Line,
+                x: 0.0,
+                y: -5.0,
+            });
+            app.update();
+        }
+
+        let radius = app.world.get::<LookTransform>(camera).unwrap().radius();
+        assert!((radius - 10.0).abs() < 1e-4, "radius {}", radius);
+    }
+
+    #[test]
+    fn test_scene_framing_fits_the_narrower_fov() {
+        use bevy::prelude::{OrthographicProjection, PerspectiveProjection};
+
+        let perspective = |aspect_ratio| {
+            Projection::Perspective(PerspectiveProjection {
+                fov: 1.0,
+                aspect_ratio,
+                ..Default::default()
+            })
+        };
+        let (landscape, _) = scene_framing(&perspective(2.0), 1.0);
+        let (portrait, _) = scene_framing(&perspective(0.5), 1.0);
+        assert!((landscape - fit_sphere_distance(1.0, 1.0)).abs() < 1e-5);
+        assert!(portrait > landscape);
+
+        let orthographic = Projection::Orthographic(OrthographicProjection {
+            left: -2.0,
+            right: 2.0,
+            bottom: -1.0,
+            top: 1.0,
+            ..Default::default()
+        });
+        let (_, scale) = scene_framing(&orthographic, 3.0);
+        assert!((scale.unwrap() - 3.0).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_pixel_scrolling_steps_once_per_line() {
+        use bevy::{input::InputPlugin, prelude::PerspectiveProjection};
+
+        let mut app = App::new();
+        app.add_plugin(InputPlugin)
+            .init_resource::<Time>()
+            .add_plugin(OrbitCameraPlugin::default());
+        let controller = OrbitCameraController {
+            pixels_per_line: 50.0,
+            ..Default::default()
+        };
+        let camera = app
+            .world
+            .spawn((
+                OrbitCameraBundle::new(controller, Vec3::new(0.0, 0.0, 5.0), Vec3::ZERO),
+                Projection::Perspective(PerspectiveProjection::default()),
+                ZoomSteps::new(vec![2.0, 5.0, 10.0, 20.0]),
+            ))
+            .id();
+        let scroll = |app: &mut App, frames| {
+            for _ in 0..frames {
+                app.world.send_event(MouseWheel {
+                    unit: MouseScrollUnit::Pixel,
+                    x: 0.0,
+                    y: -10.0,
+                });
+                app.update();
+            }
+            app.world.get::<LookTransform>(camera).unwrap().radius()
+        };
+
+        assert!((scroll(&mut app, 4) - 5.0).abs() < 1e-4);
+        assert!((scroll(&mut app, 3) - 10.0).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_zoom_steps_with_nan() {
+        let steps = ZoomSteps::new(vec![10.0, f32::NAN, 2.0, 5.0]);
+        assert_eq!(&steps.levels[..3], &[2.0, 5.0, 10.0]);
+        assert_eq!(steps.step(4.0, 1), Some(10.0));
+        assert!(steps.step(f32::NAN, 1).is_some());
+    }
+
+    #[test]
+    fn test_settings_keep_runtime_state() {
+        let mut momentum = Momentum::default();
+        let mut auto_rotate = AutoRotate::default();
+        auto_rotate.resume_delay = 0.15;
+        let mut controller = OrbitCameraController {
+            momentum: Some(momentum),
+            auto_rotate: Some(auto_rotate),
+            grab_point: Some(Vec3::X),
+            ..Default::default()
+        };
+        controller
+            .momentum
+            .as_mut()
+            .unwrap()
+            .set_velocity(Vec2::ONE);
+        controller.auto_rotate.as_mut().unwrap().advance(0.1);
+
+        momentum.decay_rate = 2.0;
+        let settings = OrbitCameraController {
+            mouse_wheel_zoom_sensitivity: 0.5,
+            momentum: Some(momentum),
+            auto_rotate: Some(auto_rotate),
+            ..Default::default()
+        };
+        controller.apply_settings(&settings);
+
+        assert_eq!(controller.mouse_wheel_zoom_sensitivity, 0.5);
+        assert_eq!(controller.grab_point, Some(Vec3::X));
+        let momentum = controller.momentum.unwrap();
+        assert_eq!(momentum.decay_rate, 2.0);
+        assert_eq!(momentum.velocity(), Vec2::ONE);
+        // The idle time continues, so the resume delay is over after another 0.1 seconds.
+        assert!(controller.auto_rotate.unwrap().advance(0.1) > 0.0);
+    }
+
+    #[test]
+    fn test_orbit_z_up() {
+        use bevy::prelude::PerspectiveProjection;
+
+        let z_up = CoordinateSystem::Z_UP;
+        let mut controller = OrbitCameraController::default();
+        let transform = LookTransform::new(Vec3::new(0.0, -4.0, 3.0), Vec3::ZERO);
+        let projection = Projection::Perspective(PerspectiveProjection::default());
+        let mut orbit = |transform: LookTransform, delta| {
+            let scene_transform = transform.to_transform(&z_up);
+            let events = [ControlEvent::Orbit(delta)];
+            control_camera(
+                &mut controller,
+                transform,
+                &events,
+                1.0,
+                &scene_transform,
+                &projection,
+                None,
+                &z_up,
+            )
+            .transform
+        };
+
+        // Yaw turns about the Z axis, keeping the height.
+        let turned = orbit(transform, Vec2::new(1.0, 0.0));
+        assert!((turned.eye.z - 3.0).abs() < 1e-4);
+        assert!((turned.radius() - 5.0).abs() < 1e-4);
+        assert!(!turned.eye.abs_diff_eq(transform.eye, 1e-2));
+
+        // Pitch stops at the Z pole.
+        let top = orbit(transform, Vec2::new(0.0, 10.0));
+        assert!(top.eye.z > 4.99 && top.eye.z <= 5.0);
+    }
 }
@@ -0,0 +1,331 @@
+use crate::{
+    camera_path_handoff_system,
+    controllers::{
+        apply_deadband,
+        blockers::CameraInputBlockers,
+        devices::{accepts_keyboard_mouse, InputDevices},
+        input_available,
+        precision::PrecisionMode,
+        routed_control_events,
+        state::{and_in_controller_state, in_controller_state, state_activation, StateActivation},
+        CameraController, TargetedControlEvent,
+    },
+    LookTransform, LookTransformBundle, Smoother,
+};
+
+use bevy::{
+    app::prelude::*,
+    ecs::{bundle::Bundle, prelude::*, schedule::StateData},
+    input::{
+        mouse::{MouseMotion, MouseScrollUnit, MouseWheel},
+        prelude::*,
+    },
+    math::prelude::*,
+    prelude::Camera2dBundle,
+    reflect::Reflect,
+    render::camera::{Camera, OrthographicProjection},
+    window::Windows,
+};
+#[cfg(feature = "serialize")]
+use serde::{Deserialize, Serialize};
+
+#[derive(Default)]
+pub struct PanCam2dPlugin {
+    pub override_input_system: bool,
+    state_activation: Option<StateActivation>,
+}
+
+impl PanCam2dPlugin {
+    pub fn new(override_input_system: bool) -> Self {
+        Self {
+            override_input_system,
+            state_activation: None,
+        }
+    }
+
+    /// Only runs the controls and input maps while the app is in `state`. The smoothers are reset when it is entered.
+    pub fn run_in_state<S: StateData>(mut self, state: S) -> Self {
+        self.state_activation = Some(state_activation::<PanCam2dController, S>(state));
+        self
+    }
+}
+
+impl Plugin for PanCam2dPlugin {
+    fn build(&self, app: &mut App) {
+        let app = app
+            .register_type::<PanCam2dController>()
+            .add_system_to_stage(CoreStage::PreUpdate, on_controller_enabled_changed)
+            .add_system_to_stage(
+                CoreStage::PreUpdate,
+                camera_path_handoff_system::<PanCam2dController>
+                    .before(on_controller_enabled_changed),
+            )
+            .add_system(control_system.with_run_criteria(in_controller_state::<PanCam2dController>))
+            .init_resource::<CameraInputBlockers>()
+            .add_event::<ControlEvent>()
+            .add_event::<TargetedControlEvent<ControlEvent>>();
+
+        if let Some(state_activation) = &self.state_activation {
+            state_activation(app);
+        }
+
+        if !self.override_input_system {
+            app.add_system(default_input_map.with_run_criteria(
+                input_available.pipe(and_in_controller_state::<PanCam2dController>),
+            ));
+        }
+    }
+}
+
+#[derive(Bundle)]
+pub struct PanCam2dBundle {
+    controller: PanCam2dController,
+    #[bundle]
+    look_transform: LookTransformBundle,
+    #[bundle]
+    camera: Camera2dBundle,
+}
+
+impl PanCam2dBundle {
+    /// Centers the view on `position`. The zoom starts at the scale of the camera's projection.
+    pub fn new(controller: PanCam2dController, mut camera: Camera2dBundle, position: Vec2) -> Self {
+        let depth = camera.transform.translation.z;
+        camera.transform.translation = position.extend(depth);
+
+        Self {
+            controller,
+            look_transform: LookTransformBundle {
+                transform: LookTransform {
+                    eye: position.extend(depth),
+                    target: position.extend(0.0),
+                    scale: camera.projection.scale,
+                    roll: 0.0,
+                },
+                smoother: Smoother::new(controller.smoothing_weight),
+            },
+            camera,
+        }
+    }
+}
+
+/// Pans and zooms a 2D camera. The position is the target of the `LookTransform` and the zoom is its `scale`, which the
+/// `Smoother` applies to the `OrthographicProjection`.
+#[derive(Clone, Component, Copy, Debug, Reflect)]
+#[cfg_attr(feature = "serialize", derive(Deserialize, Serialize))]
+#[reflect(Component)]
+#[cfg_attr(feature = "serialize", serde(default))]
+pub struct PanCam2dController {
+    pub enabled: bool,
+    /// Dragging with this button pans, keeping the point under the cursor fixed.
+    pub pan_button: MouseButton,
+    /// Fraction of the scale zoomed per scrolled line.
+    pub mouse_wheel_zoom_sensitivity: f32,
+    pub pixels_per_line: f32,
+    /// Zoom around the point under the cursor instead of the center of the view.
+    pub zoom_to_cursor: bool,
+    pub min_scale: f32,
+    pub max_scale: f32,
+    pub smoothing_weight: f32,
+    /// Mouse motion shorter than this many pixels per frame is ignored, so jitter of a resting high-DPI mouse doesn't keep
+    /// the camera from settling.
+    pub mouse_deadband: f32,
+}
+
+impl Default for PanCam2dController {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            pan_button: MouseButton::Left,
+            mouse_wheel_zoom_sensitivity: 0.1,
+            pixels_per_line: 53.0,
+            zoom_to_cursor: true,
+            min_scale: 0.1,
+            max_scale: 10.0,
+            smoothing_weight: 0.7,
+            mouse_deadband: 0.0,
+        }
+    }
+}
+
+impl CameraController for PanCam2dController {
+    fn enabled(&self) -> bool {
+        self.enabled
+    }
+
+    fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub enum ControlEvent {
+    /// Moves the view by this many world units.
+    Pan(Vec2),
+    /// Multiplies the scale, keeping the world point `anchor` (or the center of the view) fixed on screen.
+    Zoom { scalar: f32, anchor: Option<Vec2> },
+}
+
+define_on_controller_enabled_changed!(PanCam2dController);
+
+/// World units per logical pixel of an orthographic projection.
+fn world_units_per_pixel(projection: &OrthographicProjection, camera: &Camera) -> Option<f32> {
+    let viewport_size = camera.logical_viewport_size()?;
+
+    Some((projection.right - projection.left) * projection.scale / viewport_size.x)
+}
+
+#[allow(clippy::type_complexity)]
+pub fn default_input_map(
+    mut events: EventWriter<TargetedControlEvent<ControlEvent>>,
+    mut mouse_wheel_reader: EventReader<MouseWheel>,
+    mut mouse_motion_events: EventReader<MouseMotion>,
+    mouse_buttons: Res<Input<MouseButton>>,
+    windows: Option<Res<Windows>>,
+    controllers: Query<(
+        Entity,
+        &PanCam2dController,
+        &LookTransform,
+        &OrthographicProjection,
+        &Camera,
+        Option<&InputDevices>,
+    )>,
+) {
+    // The mouse can only control one camera at a time.
+    let (camera, controller, transform, projection, camera_component) =
+        if let Some((camera, controller, transform, projection, camera_component, _)) = controllers
+            .iter()
+            .find(|c| c.1.enabled && accepts_keyboard_mouse(c.5))
+        {
+            (camera, controller, transform, projection, camera_component)
+        } else {
+            return;
+        };
+    let mut send = |event| events.send(TargetedControlEvent { camera, event });
+    let units_per_pixel = match world_units_per_pixel(projection, camera_component) {
+        Some(units_per_pixel) => units_per_pixel,
+        None => return,
+    };
+
+    let mut cursor_delta = Vec2::ZERO;
+    for event in mouse_motion_events.iter() {
+        cursor_delta += event.delta;
+    }
+    let cursor_delta = apply_deadband(cursor_delta, controller.mouse_deadband);
+    if mouse_buttons.pressed(controller.pan_button) && cursor_delta != Vec2::ZERO {
+        // Mouse motion is +Y down, the world is +Y up.
+        send(ControlEvent::Pan(
+            units_per_pixel * Vec2::new(-cursor_delta.x, cursor_delta.y),
+        ));
+    }
+
+    let mut scalar = 1.0;
+    for event in mouse_wheel_reader.iter() {
+        let scroll_amount = match event.unit {
+            MouseScrollUnit::Line => event.y,
+            MouseScrollUnit::Pixel => event.y / controller.pixels_per_line,
+        };
+        scalar *= 1.0 - scroll_amount * controller.mouse_wheel_zoom_sensitivity;
+    }
+    if scalar == 1.0 {
+        return;
+    }
+
+    let anchor = if controller.zoom_to_cursor {
+        let cursor = windows
+            .as_ref()
+            .and_then(|windows| windows.get_primary())
+            .and_then(|window| window.cursor_position());
+        cursor
+            .zip(camera_component.logical_viewport_size())
+            .map(|(cursor, viewport_size)| {
+                // The cursor position is measured from the bottom left corner.
+                transform.target.truncate() + units_per_pixel * (cursor - 0.5 * viewport_size)
+            })
+    } else {
+        None
+    };
+    send(ControlEvent::Zoom { scalar, anchor });
+}
+
+pub fn control_system(
+    mut events: EventReader<ControlEvent>,
+    mut targeted_events: EventReader<TargetedControlEvent<ControlEvent>>,
+    precision: Option<Res<PrecisionMode>>,
+    mut cameras: Query<(Entity, &PanCam2dController, &mut LookTransform)>,
+) {
+    let first_enabled = cameras.iter().find(|c| c.1.enabled).map(|c| c.0);
+    let events = routed_control_events(
+        &mut events,
+        &mut targeted_events,
+        first_enabled,
+        precision.as_deref(),
+    );
+
+    for (camera, controller, mut transform) in cameras.iter_mut() {
+        if !controller.enabled {
+            continue;
+        }
+
+        let camera_events = events.iter().filter(|e| e.0 == camera).map(|e| &e.1);
+        let new_transform = control_camera(controller, *transform, camera_events);
+        // Only write when moved, so the transform isn't marked as changed every frame.
+        if new_transform != *transform {
+            *transform = new_transform;
+        }
+    }
+}
+
+/// Applies `events` to `transform` and returns the new transform, without any ECS plumbing, e.g. to unit test custom
+/// input maps.
+pub fn control_camera<'a>(
+    controller: &PanCam2dController,
+    mut transform: LookTransform,
+    events: impl IntoIterator<Item = &'a ControlEvent>,
+) -> LookTransform {
+    for event in events {
+        let mut position = transform.target.truncate();
+        match *event {
+            ControlEvent::Pan(delta) => {
+                position += delta;
+            }
+            ControlEvent::Zoom { scalar, anchor } => {
+                let scale =
+                    (transform.scale * scalar).clamp(controller.min_scale, controller.max_scale);
+                if let Some(anchor) = anchor {
+                    position = zoom_about(position, anchor, scale / transform.scale);
+                }
+                transform.scale = scale;
+            }
+        }
+        transform.eye = position.extend(transform.eye.z);
+        transform.target = position.extend(transform.target.z);
+    }
+
+    transform
+}
+
+/// The view center after scaling the view by `ratio` around `anchor`.
+fn zoom_about(center: Vec2, anchor: Vec2, ratio: f32) -> Vec2 {
+    anchor + (center - anchor) * ratio
+}
+
+// ████████╗███████╗███████╗████████╗
+// ╚══██╔══╝██╔════╝██╔════╝╚══██╔══╝
+//    ██║   █████╗  ███████╗   ██║
+//    ██║   ██╔══╝  ╚════██║   ██║
+//    ██║   ███████╗███████║   ██║
+//    ╚═╝   ╚══════╝╚══════╝   ╚═╝
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_zoom_about_keeps_anchor_fixed() {
+        let (center, anchor) = (Vec2::new(10.0, 0.0), Vec2::new(14.0, 2.0));
+        let ratio = 0.5;
+        let new_center = zoom_about(center, anchor, ratio);
+        // The anchor is at the same screen position, measured in view-sized units.
+        assert!(((anchor - new_center) / ratio).abs_diff_eq(anchor - center, 1e-5));
+    }
+}
@@ -0,0 +1,221 @@
+use super::{follow, fps, observation, orbit, pan_cam_2d, rts, unreal};
+
+use bevy::{
+    app::prelude::*,
+    ecs::prelude::*,
+    input::{prelude::*, InputSystem},
+};
+
+/// Adds the `PrecisionMode` resource and toggles it with its `toggle_key`.
+pub struct PrecisionModePlugin;
+
+impl Plugin for PrecisionModePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<PrecisionMode>().add_system_to_stage(
+            CoreStage::PreUpdate,
+            precision_toggle_system.after(InputSystem),
+        );
+    }
+}
+
+/// Scales down the motion of all controllers for fine adjustments, e.g. when framing a screenshot or aligning a technical
+/// view. While active, relative control events (rotations, pans, zooms) are scaled by `factor` and orbit momentum is
+/// disabled. Absolute events like `SetTarget` are not affected.
+#[derive(Clone, Copy, Debug, Resource)]
+pub struct PrecisionMode {
+    /// Multiplies rotation and translation deltas. Zoom scalars are raised to this power.
+    pub factor: f32,
+    /// Toggles the mode when pressed. `None` leaves it to the app, through `set_active` or `toggle`.
+    pub toggle_key: Option<KeyCode>,
+    active: bool,
+}
+
+impl Default for PrecisionMode {
+    fn default() -> Self {
+        Self {
+            factor: 0.1,
+            toggle_key: None,
+            active: false,
+        }
+    }
+}
+
+impl PrecisionMode {
+    pub fn is_active(&self) -> bool {
+        self.active
+    }
+
+    pub fn set_active(&mut self, active: bool) {
+        self.active = active;
+    }
+
+    pub fn toggle(&mut self) {
+        self.active = !self.active;
+    }
+
+    /// The factor to scale events by, if active.
+    pub(crate) fn active_factor(precision: Option<&PrecisionMode>) -> Option<f32> {
+        precision.filter(|p| p.active).map(|p| p.factor)
+    }
+}
+
+/// Control events that can be scaled down by `PrecisionMode`.
+pub(crate) trait PrecisionScaled {
+    fn scaled(&self, factor: f32) -> Self;
+}
+
+impl PrecisionScaled for orbit::ControlEvent {
+    fn scaled(&self, factor: f32) -> Self {
+        use orbit::ControlEvent::*;
+        match *self {
+            Orbit(delta) => Orbit(factor * delta),
+            OrbitAbout { pivot, delta } => OrbitAbout {
+                pivot,
+                delta: factor * delta,
+            },
+            TranslateTarget(delta) => TranslateTarget(factor * delta),
+            Zoom(scalar) => Zoom(scalar.powf(factor)),
+            ZoomAbout { anchor, scalar } => ZoomAbout {
+                anchor,
+                scalar: scalar.powf(factor),
+            },
+            Tilt(delta) => Tilt(factor * delta),
+            Roll(angle) => Roll(factor * angle),
+            // Grab panning keeps the grabbed point under the cursor, and the rest are absolute.
+            event => event,
+        }
+    }
+}
+
+impl PrecisionScaled for fps::ControlEvent {
+    fn scaled(&self, factor: f32) -> Self {
+        use fps::ControlEvent::*;
+        match *self {
+            Rotate(delta) => Rotate(factor * delta),
+            TranslateEye(delta) => TranslateEye(factor * delta),
+            event => event,
+        }
+    }
+}
+
+impl PrecisionScaled for unreal::ControlEvent {
+    fn scaled(&self, factor: f32) -> Self {
+        use unreal::ControlEvent::*;
+        match *self {
+            Locomotion(delta) => Locomotion(factor * delta),
+            LocomotionToward {
+                direction,
+                distance,
+            } => LocomotionToward {
+                direction,
+                distance: factor * distance,
+            },
+            Rotate(delta) => Rotate(factor * delta),
+            Roll(angle) => Roll(factor * angle),
+            TranslateEye(delta) => TranslateEye(factor * delta),
+            event => event,
+        }
+    }
+}
+
+impl PrecisionScaled for rts::ControlEvent {
+    fn scaled(&self, factor: f32) -> Self {
+        use rts::ControlEvent::*;
+        match *self {
+            Pan(delta) => Pan(factor * delta),
+            Zoom(scalar) => Zoom(scalar.powf(factor)),
+            Rotate(angle) => Rotate(factor * angle),
+        }
+    }
+}
+
+impl PrecisionScaled for follow::ControlEvent {
+    fn scaled(&self, factor: f32) -> Self {
+        use follow::ControlEvent::*;
+        match *self {
+            Orbit(delta) => Orbit(factor * delta),
+            Zoom(scalar) => Zoom(scalar.powf(factor)),
+        }
+    }
+}
+
+impl PrecisionScaled for observation::ControlEvent {
+    fn scaled(&self, factor: f32) -> Self {
+        use observation::ControlEvent::*;
+        match *self {
+            Rotate(delta) => Rotate(factor * delta),
+        }
+    }
+}
+
+impl PrecisionScaled for pan_cam_2d::ControlEvent {
+    fn scaled(&self, factor: f32) -> Self {
+        use pan_cam_2d::ControlEvent::*;
+        match *self {
+            Pan(delta) => Pan(factor * delta),
+            Zoom { scalar, anchor } => Zoom {
+                scalar: scalar.powf(factor),
+                anchor,
+            },
+        }
+    }
+}
+
+fn precision_toggle_system(
+    mut precision: ResMut<PrecisionMode>,
+    keyboard: Option<Res<Input<KeyCode>>>,
+) {
+    let pressed = match (precision.toggle_key, keyboard) {
+        (Some(key), Some(keyboard)) => keyboard.just_pressed(key),
+        _ => false,
+    };
+    if pressed {
+        precision.toggle();
+    }
+}
+
+// ████████╗███████╗███████╗████████╗
+// ╚══██╔══╝██╔════╝██╔════╝╚══██╔══╝
+//    ██║   █████╗  ███████╗   ██║
+//    ██║   ██╔══╝  ╚════██║   ██║
+//    ██║   ███████╗███████║   ██║
+//    ╚═╝   ╚══════╝╚══════╝   ╚═╝
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::assert_relative_eq;
+    use bevy::math::prelude::*;
+
+    #[test]
+    fn test_relative_events_are_scaled() {
+        let factor = 0.1;
+        match orbit::ControlEvent::Orbit(Vec2::new(1.0, -2.0)).scaled(factor) {
+            orbit::ControlEvent::Orbit(delta) => {
+                assert!(delta.abs_diff_eq(Vec2::new(0.1, -0.2), 1e-6))
+            }
+            event => panic!("unexpected {:?}", event),
+        }
+        // Zooming in 10 steps of the scaled scalar adds up to the unscaled one.
+        match rts::ControlEvent::Zoom(2.0).scaled(factor) {
+            rts::ControlEvent::Zoom(scalar) => {
+                assert_relative_eq!(scalar.powi(10), 2.0, epsilon = 1e-5)
+            }
+            event => panic!("unexpected {:?}", event),
+        }
+        match fps::ControlEvent::SetEye(Vec3::X).scaled(factor) {
+            fps::ControlEvent::SetEye(eye) => assert_eq!(eye, Vec3::X),
+            event => panic!("unexpected {:?}", event),
+        }
+    }
+
+    #[test]
+    fn test_active_factor() {
+        let mut precision = PrecisionMode::default();
+        assert_eq!(PrecisionMode::active_factor(Some(&precision)), None);
+        precision.toggle();
+        assert_eq!(PrecisionMode::active_factor(Some(&precision)), Some(0.1));
+        assert_eq!(PrecisionMode::active_factor(None), None);
+    }
+}
@@ -0,0 +1,132 @@
+//! Extrapolation of mouse-look motion, to hide the frame of latency between reading the mouse and showing the rotation on
+//! low frame rates, without raising the sensitivity.
+//!
+//! Each frame the rotation is led by the motion predicted for the next frame, from the velocity of the last few frames.
+//! When the prediction turns out wrong (e.g. the mouse stops), the lead is taken back over the following frames, at most
+//! `max_correction` per frame so corrections don't show as snaps. The total rotation always converges to the total motion
+//! of the mouse.
+
+use bevy::{
+    math::prelude::*,
+    reflect::{FromReflect, Reflect},
+};
+#[cfg(feature = "serialize")]
+use serde::{Deserialize, Serialize};
+
+/// Settings of mouse-look prediction. Only rotation is predicted.
+#[derive(Clone, Copy, Debug, FromReflect, PartialEq, Reflect)]
+#[cfg_attr(feature = "serialize", derive(Deserialize, Serialize))]
+pub struct InputPrediction {
+    /// Fraction of the next frame's motion to lead by, from `0.0` (off) to `1.0`.
+    pub strength: f32,
+    /// The lead is clamped to this many pixels.
+    pub max_prediction: f32,
+    /// The most the lead changes per frame, in pixels.
+    pub max_correction: f32,
+}
+
+impl Default for InputPrediction {
+    fn default() -> Self {
+        Self {
+            strength: 0.5,
+            max_prediction: 20.0,
+            max_correction: 5.0,
+        }
+    }
+}
+
+/// Number of recent frames the mouse velocity is averaged over.
+const HISTORY: usize = 3;
+
+/// The state of the prediction, kept by the input maps.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct MotionPredictor {
+    history: [(Vec2, f32); HISTORY],
+    next: usize,
+    /// The lead already added to the motion.
+    lead: Vec2,
+}
+
+impl MotionPredictor {
+    /// Returns `delta`, the mouse motion over the last `dt` seconds, adjusted by the change of the lead.
+    pub fn predict(&mut self, settings: &InputPrediction, delta: Vec2, dt: f32) -> Vec2 {
+        self.history[self.next] = (delta, dt);
+        self.next = (self.next + 1) % HISTORY;
+
+        let (motion, time) = self
+            .history
+            .iter()
+            .fold((Vec2::ZERO, 0.0), |(m, t), (delta, dt)| {
+                (m + *delta, t + dt)
+            });
+        let velocity = if time > 0.0 {
+            motion / time
+        } else {
+            Vec2::ZERO
+        };
+
+        // Assume the next frame takes as long as this one.
+        let lead = (settings.strength * dt * velocity).clamp_length_max(settings.max_prediction);
+        let correction = (lead - self.lead).clamp_length_max(settings.max_correction);
+        self.lead += correction;
+
+        delta + correction
+    }
+
+    /// Whether a lead is still to be taken back, even if the mouse doesn't move.
+    pub fn is_leading(&self) -> bool {
+        self.lead != Vec2::ZERO
+    }
+
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+}
+
+// ████████╗███████╗███████╗████████╗
+// ╚══██╔══╝██╔════╝██╔════╝╚══██╔══╝
+//    ██║   █████╗  ███████╗   ██║
+//    ██║   ██╔══╝  ╚════██║   ██║
+//    ██║   ███████╗███████║   ██║
+//    ╚═╝   ╚══════╝╚══════╝   ╚═╝
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_prediction_leads_and_then_converges_to_input() {
+        let settings = InputPrediction::default();
+        let mut predictor = MotionPredictor::default();
+        let dt = 1.0 / 30.0;
+
+        let mut input = Vec2::ZERO;
+        let mut output = Vec2::ZERO;
+        for _ in 0..10 {
+            let delta = Vec2::new(10.0, 0.0);
+            input += delta;
+            output += predictor.predict(&settings, delta, dt);
+        }
+        assert!(output.x > input.x);
+        assert!(output.x - input.x <= settings.max_prediction);
+
+        // The mouse stops.
+        for _ in 0..20 {
+            output += predictor.predict(&settings, Vec2::ZERO, dt);
+        }
+        assert!(!predictor.is_leading());
+        assert!(output.abs_diff_eq(input, 1e-4));
+    }
+
+    #[test]
+    fn test_corrections_are_clamped() {
+        let settings = InputPrediction {
+            strength: 1.0,
+            max_prediction: 100.0,
+            max_correction: 2.0,
+        };
+        let mut predictor = MotionPredictor::default();
+        let output = predictor.predict(&settings, Vec2::new(0.0, 30.0), 1.0 / 60.0);
+        assert!(output.abs_diff_eq(Vec2::new(0.0, 32.0), 1e-4));
+    }
+}
@@ -0,0 +1,369 @@
+//! The classic CAD layout of four viewports: orthographic top, front and side views locked to their axes, and a
+//! perspective orbit view, all looking at a shared focus point.
+//!
+//! All four cameras are orbit cameras. Panning any of them moves the shared focus, and zooming one of the orthographic
+//! views zooms all of them when `QuadView::sync_ortho_zoom` is set. The mouse controls the viewport under the cursor.
+
+use crate::{
+    controllers::{
+        devices::InputDevices,
+        orbit::{control_system, OrbitCameraBundle, OrbitCameraController, OrbitCameraPlugin},
+    },
+    CoordinateSystem, LookAngles, LookTransform, LookTransformSystem,
+};
+
+use bevy::{
+    app::prelude::*,
+    core_pipeline::clear_color::ClearColorConfig,
+    ecs::prelude::*,
+    input::prelude::*,
+    math::prelude::*,
+    prelude::{Camera3d, Camera3dBundle},
+    render::camera::{Camera, OrthographicProjection, ScalingMode, Viewport},
+    window::Windows,
+};
+use std::f32::consts::PI;
+
+/// Spawns the four cameras of a `QuadView` at startup and keeps them linked. Adds the `OrbitCameraPlugin` if missing.
+#[derive(Default)]
+pub struct QuadViewPlugin {
+    pub quad_view: QuadView,
+}
+
+impl Plugin for QuadViewPlugin {
+    fn build(&self, app: &mut App) {
+        if !app.is_plugin_added::<OrbitCameraPlugin>() {
+            app.add_plugin(OrbitCameraPlugin::default());
+        }
+        app.insert_resource(self.quad_view)
+            .add_startup_system(spawn_quad_view)
+            .add_system_to_stage(CoreStage::PreUpdate, quad_view_hover_system)
+            .add_system(quad_view_layout_system)
+            .add_system(
+                quad_view_sync_system
+                    .after(control_system)
+                    .before(LookTransformSystem),
+            );
+    }
+}
+
+/// The shared state of the quad view cameras.
+#[derive(Clone, Copy, Debug, Resource)]
+pub struct QuadView {
+    /// The point all views look at. Follows the view that was panned last.
+    pub focus: Vec3,
+    /// The projection scale of the orthographic views.
+    pub ortho_scale: f32,
+    /// Distance of the orthographic eyes from the focus, which only matters for clipping.
+    pub ortho_distance: f32,
+    /// Where the perspective eye starts, relative to the focus.
+    pub perspective_offset: Vec3,
+    /// When set, zooming one of the orthographic views zooms all of them.
+    pub sync_ortho_zoom: bool,
+}
+
+impl Default for QuadView {
+    fn default() -> Self {
+        Self {
+            focus: Vec3::ZERO,
+            ortho_scale: 5.0,
+            ortho_distance: 100.0,
+            perspective_offset: Vec3::new(-2.0, 5.0, 5.0),
+            sync_ortho_zoom: true,
+        }
+    }
+}
+
+/// Which of the four views a camera shows.
+#[derive(Clone, Component, Copy, Debug, Eq, PartialEq)]
+pub enum QuadViewport {
+    /// Looks down the world up axis, in the top left quarter of the window.
+    Top,
+    /// Looks against the forward direction of the `CoordinateSystem`, in the top right quarter.
+    Front,
+    /// Looks along the ground perpendicular to the front view, in the bottom left quarter.
+    Side,
+    /// An orbit camera, in the bottom right quarter.
+    Perspective,
+}
+
+impl QuadViewport {
+    pub const ALL: [QuadViewport; 4] = [
+        QuadViewport::Top,
+        QuadViewport::Front,
+        QuadViewport::Side,
+        QuadViewport::Perspective,
+    ];
+
+    /// The direction from the focus to the eye of the orthographic views, in `coordinates`.
+    pub fn axis(self, coordinates: &CoordinateSystem) -> Option<Vec3> {
+        match self {
+            QuadViewport::Top => {
+                // Not straight down, which the look angles can't represent. The top of the view points away from the
+                // front view's eye.
+                let mut angles = LookAngles::new(*coordinates);
+                angles.set_pitch(PI / 2.0);
+                Some(angles.unit_vector())
+            }
+            QuadViewport::Front => Some(LookAngles::new(*coordinates).unit_vector()),
+            QuadViewport::Side => {
+                let mut angles = LookAngles::new(*coordinates);
+                angles.set_yaw(PI / 2.0);
+                Some(angles.unit_vector())
+            }
+            QuadViewport::Perspective => None,
+        }
+    }
+
+    /// The physical position and size of the viewport in a window of `window_size` physical pixels.
+    pub fn rect(self, window_size: UVec2) -> (UVec2, UVec2) {
+        let (column, row) = match self {
+            QuadViewport::Top => (0, 0),
+            QuadViewport::Front => (1, 0),
+            QuadViewport::Side => (0, 1),
+            QuadViewport::Perspective => (1, 1),
+        };
+        let half = window_size / 2;
+        let position = UVec2::new(column * half.x, row * half.y);
+        // The right and bottom views take the odd pixel.
+        let size = UVec2::select(BVec2::new(column == 0, row == 0), half, window_size - half);
+
+        (position, size.max(UVec2::ONE))
+    }
+
+    /// Returns `transform` linked to the shared state: looking at the focus and, for the orthographic views, locked to
+    /// their axis at the shared scale.
+    fn linked_transform(
+        self,
+        transform: LookTransform,
+        quad_view: &QuadView,
+        coordinates: &CoordinateSystem,
+    ) -> LookTransform {
+        let mut linked = transform + (quad_view.focus - transform.target);
+        if let Some(axis) = self.axis(coordinates) {
+            linked.eye = quad_view.focus + quad_view.ortho_distance * axis;
+            linked.scale = quad_view.ortho_scale;
+        }
+
+        linked
+    }
+}
+
+fn spawn_quad_view(
+    mut commands: Commands,
+    quad_view: Res<QuadView>,
+    coordinates: Res<CoordinateSystem>,
+) {
+    for (priority, viewport) in QuadViewport::ALL.into_iter().enumerate() {
+        let mut camera = Camera3dBundle {
+            camera: Camera {
+                priority: priority as isize,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        if priority > 0 {
+            // The first camera already cleared the window.
+            camera.camera_3d = Camera3d {
+                clear_color: ClearColorConfig::None,
+                ..Default::default()
+            };
+        }
+
+        let focus = quad_view.focus;
+        let look_transform = match viewport.axis(&coordinates) {
+            Some(axis) => {
+                camera.projection = OrthographicProjection {
+                    scale: quad_view.ortho_scale,
+                    scaling_mode: ScalingMode::FixedVertical(2.0),
+                    ..Default::default()
+                }
+                .into();
+                LookTransform {
+                    eye: focus + quad_view.ortho_distance * axis,
+                    target: focus,
+                    scale: quad_view.ortho_scale,
+                    roll: 0.0,
+                }
+            }
+            None => LookTransform::new(focus + quad_view.perspective_offset, focus),
+        };
+
+        commands
+            .spawn(camera)
+            .insert(OrbitCameraBundle::new(
+                OrbitCameraController::default(),
+                look_transform.eye,
+                look_transform.target,
+            ))
+            .insert(look_transform)
+            .insert(InputDevices {
+                keyboard_mouse: viewport == QuadViewport::Perspective,
+                gamepad: None,
+            })
+            .insert(viewport);
+    }
+}
+
+/// Gives the mouse to the viewport under the cursor. Not while a button is held, so drags stay in their viewport.
+fn quad_view_hover_system(
+    windows: Option<Res<Windows>>,
+    mouse_buttons: Option<Res<Input<MouseButton>>>,
+    mut cameras: Query<(&Camera, &mut InputDevices), With<QuadViewport>>,
+) {
+    if mouse_buttons.map_or(false, |b| b.get_pressed().next().is_some()) {
+        return;
+    }
+    let window = match windows.as_ref().and_then(|w| w.get_primary()) {
+        Some(window) => window,
+        None => return,
+    };
+    let cursor = match window.cursor_position() {
+        Some(cursor) => cursor,
+        None => return,
+    };
+    // The cursor is in logical pixels from the bottom left, viewports are in physical pixels from the top left.
+    let scale_factor = window.scale_factor() as f32;
+    let cursor = scale_factor * Vec2::new(cursor.x, window.height() - cursor.y);
+
+    for (camera, mut devices) in cameras.iter_mut() {
+        let hovered = camera.viewport.as_ref().map_or(false, |viewport| {
+            let min = viewport.physical_position.as_vec2();
+            let max = min + viewport.physical_size.as_vec2();
+            cursor.cmpge(min).all() && cursor.cmplt(max).all()
+        });
+        if devices.keyboard_mouse != hovered {
+            devices.keyboard_mouse = hovered;
+        }
+    }
+}
+
+fn quad_view_layout_system(
+    windows: Option<Res<Windows>>,
+    mut cameras: Query<(&QuadViewport, &mut Camera)>,
+) {
+    let window = match windows.as_ref().and_then(|w| w.get_primary()) {
+        Some(window) => window,
+        None => return,
+    };
+    let window_size = UVec2::new(window.physical_width(), window.physical_height());
+
+    for (viewport, mut camera) in cameras.iter_mut() {
+        let (physical_position, physical_size) = viewport.rect(window_size);
+        let current = camera
+            .viewport
+            .as_ref()
+            .map(|v| (v.physical_position, v.physical_size));
+        // Only write on resize, so the cameras aren't marked as changed every frame.
+        if current != Some((physical_position, physical_size)) {
+            camera.viewport = Some(Viewport {
+                physical_position,
+                physical_size,
+                ..Default::default()
+            });
+        }
+    }
+}
+
+fn quad_view_sync_system(
+    mut quad_view: ResMut<QuadView>,
+    coordinates: Res<CoordinateSystem>,
+    mut cameras: Query<(&QuadViewport, &mut LookTransform)>,
+) {
+    // A view that moved away from the shared state this frame leads the others.
+    if let Some(target) = cameras
+        .iter()
+        .map(|(_, transform)| transform.target)
+        .find(|target| *target != quad_view.focus)
+    {
+        quad_view.focus = target;
+    }
+    if quad_view.sync_ortho_zoom {
+        if let Some(scale) = cameras
+            .iter()
+            .filter(|(viewport, _)| viewport.axis(&coordinates).is_some())
+            .map(|(_, transform)| transform.scale)
+            .find(|scale| *scale != quad_view.ortho_scale)
+        {
+            quad_view.ortho_scale = scale;
+        }
+    }
+
+    for (viewport, mut transform) in cameras.iter_mut() {
+        let mut shared = *quad_view;
+        if !shared.sync_ortho_zoom && viewport.axis(&coordinates).is_some() {
+            shared.ortho_scale = transform.scale;
+        }
+        let linked = viewport.linked_transform(*transform, &shared, &coordinates);
+        // Only write when moved, so the transforms aren't marked as changed every frame.
+        if linked != *transform {
+            *transform = linked;
+        }
+    }
+}
+
+// ████████╗███████╗███████╗████████╗
+// ╚══██╔══╝██╔════╝██╔════╝╚══██╔══╝
+//    ██║   █████╗  ███████╗   ██║
+//    ██║   ██╔══╝  ╚════██║   ██║
+//    ██║   ███████╗███████║   ██║
+//    ╚═╝   ╚══════╝╚══════╝   ╚═╝
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_viewports_tile_the_window() {
+        let window_size = UVec2::new(1001, 601);
+        let area: u32 = QuadViewport::ALL
+            .iter()
+            .map(|v| {
+                let (_, size) = v.rect(window_size);
+                size.x * size.y
+            })
+            .sum();
+        assert_eq!(area, window_size.x * window_size.y);
+        assert_eq!(
+            QuadViewport::Perspective.rect(window_size),
+            (UVec2::new(500, 300), UVec2::new(501, 301))
+        );
+    }
+
+    #[test]
+    fn test_orthographic_views_stay_on_their_axis() {
+        let quad_view = QuadView {
+            focus: Vec3::new(1.0, 2.0, 3.0),
+            ..Default::default()
+        };
+        // Orbited away from the axis, as by an orbit drag.
+        let orbited = LookTransform::new(Vec3::new(5.0, 5.0, 5.0), Vec3::ZERO);
+        let linked =
+            QuadViewport::Front.linked_transform(orbited, &quad_view, &CoordinateSystem::Y_UP);
+        assert_eq!(linked.target, quad_view.focus);
+        assert_eq!(
+            linked.eye,
+            quad_view.focus + quad_view.ortho_distance * Vec3::Z
+        );
+        assert_eq!(linked.scale, quad_view.ortho_scale);
+
+        let top = QuadViewport::Top.axis(&CoordinateSystem::Y_UP).unwrap();
+        assert!(top.y > 0.99 && top.z > 0.0);
+    }
+
+    #[test]
+    fn test_perspective_view_keeps_its_angle() {
+        let quad_view = QuadView {
+            focus: Vec3::X,
+            ..Default::default()
+        };
+        let transform = LookTransform::new(Vec3::new(0.0, 3.0, 4.0), Vec3::ZERO);
+        let linked = QuadViewport::Perspective.linked_transform(
+            transform,
+            &quad_view,
+            &CoordinateSystem::Y_UP,
+        );
+        assert_eq!(linked.target, Vec3::X);
+        assert_eq!(linked.eye, Vec3::new(1.0, 3.0, 4.0));
+    }
+}
@@ -0,0 +1,397 @@
+use crate::{
+    camera_path_handoff_system,
+    controllers::{
+        blockers::CameraInputBlockers,
+        devices::{accepts_keyboard_mouse, InputDevices},
+        input_available,
+        precision::PrecisionMode,
+        routed_control_events,
+        state::{and_in_controller_state, in_controller_state, state_activation, StateActivation},
+        CameraController, TargetedControlEvent,
+    },
+    CoordinateSystem, LookAngles, LookTransform, LookTransformBundle, Smoother,
+};
+
+use bevy::{
+    app::prelude::*,
+    ecs::{bundle::Bundle, prelude::*, schedule::StateData},
+    input::{
+        mouse::{MouseScrollUnit, MouseWheel},
+        prelude::*,
+    },
+    math::prelude::*,
+    prelude::Camera3dBundle,
+    reflect::Reflect,
+    time::Time,
+    transform::components::Transform,
+    window::Windows,
+};
+#[cfg(feature = "serialize")]
+use serde::{Deserialize, Serialize};
+
+#[derive(Default)]
+pub struct RtsCameraPlugin {
+    pub override_input_system: bool,
+    state_activation: Option<StateActivation>,
+}
+
+impl RtsCameraPlugin {
+    pub fn new(override_input_system: bool) -> Self {
+        Self {
+            override_input_system,
+            state_activation: None,
+        }
+    }
+
+    /// Only runs the controls and input maps while the app is in `state`. The smoothers are reset when it is entered.
+    pub fn run_in_state<S: StateData>(mut self, state: S) -> Self {
+        self.state_activation = Some(state_activation::<RtsCameraController, S>(state));
+        self
+    }
+}
+
+impl Plugin for RtsCameraPlugin {
+    fn build(&self, app: &mut App) {
+        let app = app
+            .register_type::<RtsCameraController>()
+            .add_system_to_stage(CoreStage::PreUpdate, on_controller_enabled_changed)
+            .add_system_to_stage(
+                CoreStage::PreUpdate,
+                camera_path_handoff_system::<RtsCameraController>
+                    .before(on_controller_enabled_changed),
+            )
+            .add_system(
+                control_system.with_run_criteria(in_controller_state::<RtsCameraController>),
+            )
+            .init_resource::<CameraInputBlockers>()
+            .init_resource::<CoordinateSystem>()
+            .add_event::<ControlEvent>()
+            .add_event::<TargetedControlEvent<ControlEvent>>();
+
+        if let Some(state_activation) = &self.state_activation {
+            state_activation(app);
+        }
+
+        if !self.override_input_system {
+            app.add_system(default_input_map.with_run_criteria(
+                input_available.pipe(and_in_controller_state::<RtsCameraController>),
+            ));
+        }
+    }
+}
+
+#[derive(Bundle)]
+pub struct RtsCameraBundle {
+    controller: RtsCameraController,
+    #[bundle]
+    look_transform: LookTransformBundle,
+    #[bundle]
+    camera: Camera3dBundle,
+}
+
+impl RtsCameraBundle {
+    /// `target` is the point on the ground the camera looks at. The camera starts upright in bevy's Y-up convention. The
+    /// `LookTransformPlugin` turns it upright in the app's `CoordinateSystem` on the first update.
+    pub fn new(
+        controller: RtsCameraController,
+        mut camera: Camera3dBundle,
+        eye: Vec3,
+        target: Vec3,
+    ) -> Self {
+        // Make sure the transform is consistent with the controller to start.
+        camera.transform = Transform::from_translation(eye).looking_at(target, Vec3::Y);
+
+        Self {
+            controller,
+            look_transform: LookTransformBundle {
+                transform: LookTransform::new(eye, target),
+                smoother: Smoother::new(controller.smoothing_weight),
+            },
+            camera,
+        }
+    }
+}
+
+/// A top-down strategy game camera. The target moves on the ground plane (its height is kept) and the eye looks down at it
+/// from above.
+#[derive(Clone, Component, Copy, Debug, Reflect)]
+#[cfg_attr(feature = "serialize", derive(Deserialize, Serialize))]
+#[reflect(Component)]
+#[cfg_attr(feature = "serialize", serde(default))]
+pub struct RtsCameraController {
+    pub enabled: bool,
+    /// Panning speed in multiples of the eye's height above the target per second, so panning covers the same fraction of
+    /// the view at any zoom.
+    pub pan_speed: f32,
+    /// When set, the view pans while the cursor is within this many pixels of the window's edge.
+    pub edge_pan_margin: Option<f32>,
+    /// Fraction of the distance zoomed per scrolled line.
+    pub mouse_wheel_zoom_sensitivity: f32,
+    pub pixels_per_line: f32,
+    /// Limits of the eye's height above the target.
+    pub min_height: f32,
+    pub max_height: f32,
+    /// Radians per second of rotation around the vertical axis.
+    pub rotate_speed: f32,
+    /// When set, the pitch (in radians below the horizon) follows the zoom, from the first value at `min_height` to the
+    /// second at `max_height`, e.g. to look at the horizon up close and straight down from far away.
+    pub zoom_pitch: Option<(f32, f32)>,
+    pub smoothing_weight: f32,
+}
+
+impl Default for RtsCameraController {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            pan_speed: 1.0,
+            edge_pan_margin: Some(8.0),
+            mouse_wheel_zoom_sensitivity: 0.1,
+            pixels_per_line: 53.0,
+            min_height: 5.0,
+            max_height: 100.0,
+            rotate_speed: 1.5,
+            zoom_pitch: None,
+            smoothing_weight: 0.8,
+        }
+    }
+}
+
+impl CameraController for RtsCameraController {
+    fn enabled(&self) -> bool {
+        self.enabled
+    }
+
+    fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+    }
+}
+
+impl RtsCameraController {
+    /// The pitch (in radians below the horizon) for a `height` of the eye above the target, if it follows the zoom.
+    pub fn pitch_for_height(&self, height: f32) -> Option<f32> {
+        let (near_pitch, far_pitch) = self.zoom_pitch?;
+        let range = self.max_height - self.min_height;
+        let t = if range > 0.0 {
+            ((height - self.min_height) / range).clamp(0.0, 1.0)
+        } else {
+            0.0
+        };
+
+        Some(near_pitch + (far_pitch - near_pitch) * t)
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub enum ControlEvent {
+    /// Moves the target on the ground by (right, forward) world units relative to the view.
+    Pan(Vec2),
+    /// Multiplies the eye-to-target distance.
+    Zoom(f32),
+    /// Rotates the eye around the vertical axis through the target by this many radians.
+    Rotate(f32),
+}
+
+define_on_controller_enabled_changed!(RtsCameraController);
+
+pub fn default_input_map(
+    mut events: EventWriter<TargetedControlEvent<ControlEvent>>,
+    mut mouse_wheel_reader: EventReader<MouseWheel>,
+    time: Res<Time>,
+    coordinates: Res<CoordinateSystem>,
+    keyboard: Res<Input<KeyCode>>,
+    windows: Option<Res<Windows>>,
+    controllers: Query<(
+        Entity,
+        &RtsCameraController,
+        &LookTransform,
+        Option<&InputDevices>,
+    )>,
+) {
+    // The keyboard and mouse can only control one camera at a time.
+    let (camera, controller, transform) = if let Some((camera, controller, transform, _)) =
+        controllers
+            .iter()
+            .find(|c| c.1.enabled && accepts_keyboard_mouse(c.3))
+    {
+        (camera, controller, transform)
+    } else {
+        return;
+    };
+    let mut send = |event| events.send(TargetedControlEvent { camera, event });
+    let dt = time.delta_seconds();
+
+    let mut pan = Vec2::ZERO;
+    for (keys, dir) in [
+        ([KeyCode::W, KeyCode::Up], Vec2::Y),
+        ([KeyCode::A, KeyCode::Left], -Vec2::X),
+        ([KeyCode::S, KeyCode::Down], -Vec2::Y),
+        ([KeyCode::D, KeyCode::Right], Vec2::X),
+    ] {
+        if keyboard.any_pressed(keys) {
+            pan += dir;
+        }
+    }
+    if let Some(margin) = controller.edge_pan_margin {
+        let window = windows.as_ref().and_then(|windows| windows.get_primary());
+        if let Some((window, cursor)) = window.and_then(|w| Some((w, w.cursor_position()?))) {
+            // The cursor position is measured from the bottom left corner.
+            if cursor.x < margin {
+                pan.x -= 1.0;
+            } else if cursor.x > window.width() - margin {
+                pan.x += 1.0;
+            }
+            if cursor.y < margin {
+                pan.y -= 1.0;
+            } else if cursor.y > window.height() - margin {
+                pan.y += 1.0;
+            }
+        }
+    }
+    if let Some(direction) = pan.try_normalize() {
+        let height = coordinates.height(transform.eye - transform.target).abs();
+        send(ControlEvent::Pan(
+            direction * controller.pan_speed * height * dt,
+        ));
+    }
+
+    let mut rotation = 0.0;
+    if keyboard.pressed(KeyCode::Q) {
+        rotation -= 1.0;
+    }
+    if keyboard.pressed(KeyCode::E) {
+        rotation += 1.0;
+    }
+    if rotation != 0.0 {
+        send(ControlEvent::Rotate(
+            rotation * controller.rotate_speed * dt,
+        ));
+    }
+
+    let mut scalar = 1.0;
+    for event in mouse_wheel_reader.iter() {
+        let scroll_amount = match event.unit {
+            MouseScrollUnit::Line => event.y,
+            MouseScrollUnit::Pixel => event.y / controller.pixels_per_line,
+        };
+        scalar *= 1.0 - scroll_amount * controller.mouse_wheel_zoom_sensitivity;
+    }
+    if scalar != 1.0 {
+        send(ControlEvent::Zoom(scalar));
+    }
+}
+
+pub fn control_system(
+    coordinates: Res<CoordinateSystem>,
+    mut events: EventReader<ControlEvent>,
+    mut targeted_events: EventReader<TargetedControlEvent<ControlEvent>>,
+    precision: Option<Res<PrecisionMode>>,
+    mut cameras: Query<(Entity, &RtsCameraController, &mut LookTransform)>,
+) {
+    let first_enabled = cameras.iter().find(|c| c.1.enabled).map(|c| c.0);
+    let events = routed_control_events(
+        &mut events,
+        &mut targeted_events,
+        first_enabled,
+        precision.as_deref(),
+    );
+
+    for (camera, controller, mut transform) in cameras.iter_mut() {
+        if controller.enabled {
+            let camera_events = events.iter().filter(|e| e.0 == camera).map(|e| &e.1);
+            let new_transform = control_camera(controller, *transform, camera_events, &coordinates);
+            // Only write when moved, so the transform isn't marked as changed every frame.
+            if new_transform != *transform {
+                *transform = new_transform;
+            }
+        }
+    }
+}
+
+/// Applies `events` to `transform` and returns the new transform, without any ECS plumbing, e.g. to unit test custom
+/// input maps.
+pub fn control_camera<'a>(
+    controller: &RtsCameraController,
+    transform: LookTransform,
+    events: impl IntoIterator<Item = &'a ControlEvent>,
+    coordinates: &CoordinateSystem,
+) -> LookTransform {
+    let offset = transform.eye - transform.target;
+    let mut look_angles = LookAngles::from_vector_in(
+        offset.try_normalize().unwrap_or(coordinates.up()),
+        *coordinates,
+    );
+    let mut radius = offset.length();
+    let mut target = transform.target;
+    let mut changed = false;
+    for event in events {
+        changed = true;
+        match event {
+            ControlEvent::Pan(delta) => {
+                // The view's right and forward directions, flattened onto the ground.
+                let back = coordinates
+                    .horizontal(offset)
+                    .try_normalize()
+                    .unwrap_or(coordinates.forward());
+                let right = coordinates.up().cross(back);
+                target += delta.x * right - delta.y * back;
+            }
+            ControlEvent::Zoom(scalar) => {
+                radius *= scalar;
+            }
+            ControlEvent::Rotate(angle) => {
+                look_angles.add_yaw(*angle);
+            }
+        }
+    }
+
+    let mut sin_pitch = look_angles.get_pitch().sin();
+    if let Some(pitch) = controller.pitch_for_height(radius * sin_pitch) {
+        look_angles.set_pitch(pitch);
+        sin_pitch = look_angles.get_pitch().sin();
+        changed = true;
+    }
+    if sin_pitch > 0.0 {
+        let height = (radius * sin_pitch).clamp(controller.min_height, controller.max_height);
+        radius = height / sin_pitch;
+    }
+    if !changed {
+        return transform;
+    }
+
+    LookTransform {
+        eye: target + radius * look_angles.unit_vector(),
+        target,
+        scale: transform.scale,
+        roll: transform.roll,
+    }
+}
+
+// ████████╗███████╗███████╗████████╗
+// ╚══██╔══╝██╔════╝██╔════╝╚══██╔══╝
+//    ██║   █████╗  ███████╗   ██║
+//    ██║   ██╔══╝  ╚════██║   ██║
+//    ██║   ███████╗███████║   ██║
+//    ╚═╝   ╚══════╝╚══════╝   ╚═╝
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn test_pitch_follows_zoom() {
+        let controller = RtsCameraController {
+            min_height: 10.0,
+            max_height: 30.0,
+            zoom_pitch: Some((0.5, 1.5)),
+            ..Default::default()
+        };
+        assert_relative_eq!(controller.pitch_for_height(10.0).unwrap(), 0.5);
+        assert_relative_eq!(controller.pitch_for_height(20.0).unwrap(), 1.0);
+        assert_relative_eq!(controller.pitch_for_height(50.0).unwrap(), 1.5);
+        assert!(RtsCameraController::default()
+            .pitch_for_height(20.0)
+            .is_none());
+    }
+}
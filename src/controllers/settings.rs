@@ -0,0 +1,123 @@
+use super::defaults::CameraControllerDefaults;
+
+use bevy::{
+    app::prelude::*,
+    asset::{
+        AddAsset, AssetEvent, AssetLoader, AssetServer, Assets, Handle, LoadContext, LoadedAsset,
+    },
+    ecs::prelude::*,
+    utils::BoxedFuture,
+};
+
+/// Loads `CameraControllerDefaults` from a RON file (with the `.camera.ron` extension) and applies it to
+/// `Res<CameraControllerDefaults>` whenever the file is loaded or modified. Enable asset hot reloading to apply edits live.
+///
+/// Only controllers with a `ControllerOverrides` component follow the defaults.
+pub struct CameraSettingsPlugin {
+    pub path: String,
+}
+
+impl CameraSettingsPlugin {
+    pub fn new(path: impl Into<String>) -> Self {
+        Self { path: path.into() }
+    }
+}
+
+impl Plugin for CameraSettingsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_asset::<CameraControllerDefaults>()
+            .init_asset_loader::<CameraSettingsLoader>()
+            .init_resource::<CameraControllerDefaults>();
+
+        let handle = app
+            .world
+            .resource::<AssetServer>()
+            .load::<CameraControllerDefaults, _>(self.path.as_str());
+
+        app.insert_resource(CameraSettingsFile(handle))
+            .add_system_to_stage(CoreStage::PreUpdate, apply_camera_settings_system);
+    }
+}
+
+/// The handle of the settings file loaded by `CameraSettingsPlugin`.
+#[derive(Resource)]
+pub struct CameraSettingsFile(pub Handle<CameraControllerDefaults>);
+
+#[derive(Default)]
+struct CameraSettingsLoader;
+
+impl AssetLoader for CameraSettingsLoader {
+    fn load<'a>(
+        &'a self,
+        bytes: &'a [u8],
+        load_context: &'a mut LoadContext,
+    ) -> BoxedFuture<'a, Result<(), anyhow::Error>> {
+        Box::pin(async move {
+            let settings: CameraControllerDefaults = ron::de::from_bytes(bytes)?;
+            load_context.set_default_asset(LoadedAsset::new(settings));
+            Ok(())
+        })
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["camera.ron"]
+    }
+}
+
+fn apply_camera_settings_system(
+    mut asset_events: EventReader<AssetEvent<CameraControllerDefaults>>,
+    file: Res<CameraSettingsFile>,
+    assets: Res<Assets<CameraControllerDefaults>>,
+    mut defaults: ResMut<CameraControllerDefaults>,
+) {
+    for event in asset_events.iter() {
+        match event {
+            AssetEvent::Created { handle } | AssetEvent::Modified { handle }
+                if *handle == file.0 =>
+            {
+                if let Some(settings) = assets.get(handle) {
+                    *defaults = *settings;
+                }
+            }
+            _ => (),
+        }
+    }
+}
+
+// ████████╗███████╗███████╗████████╗
+// ╚══██╔══╝██╔════╝██╔════╝╚══██╔══╝
+//    ██║   █████╗  ███████╗   ██║
+//    ██║   ██╔══╝  ╚════██║   ██║
+//    ██║   ███████╗███████║   ██║
+//    ╚═╝   ╚══════╝╚══════╝   ╚═╝
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_partial_settings_load() {
+        // Written before most of the controller settings existed.
+        let settings: CameraControllerDefaults = ron::de::from_str(
+            "(
+                orbit: (
+                    enabled: true,
+                    mouse_rotate_sensitivity: (0.1, 0.1),
+                    mouse_translate_sensitivity: (0.1, 0.1),
+                    mouse_wheel_zoom_sensitivity: 0.2,
+                    pixels_per_line: 53.0,
+                    smoothing_weight: 0.5,
+                ),
+            )",
+        )
+        .unwrap();
+
+        assert_eq!(settings.orbit.smoothing_weight, 0.5);
+        let defaults = CameraControllerDefaults::default();
+        assert_eq!(settings.orbit.min_radius, defaults.orbit.min_radius);
+        assert_eq!(
+            settings.fps.translate_sensitivity,
+            defaults.fps.translate_sensitivity
+        );
+    }
+}
@@ -0,0 +1,161 @@
+use super::CameraController;
+use crate::Smoother;
+
+use bevy::{
+    app::prelude::*,
+    ecs::{
+        prelude::*,
+        schedule::{ShouldRun, StateData},
+    },
+};
+use std::marker::PhantomData;
+
+/// Registers the systems that tie a controller plugin to an app state.
+pub(crate) type StateActivation = Box<dyn Fn(&mut App) + Send + Sync>;
+
+pub(crate) fn state_activation<C: CameraController, S: StateData>(state: S) -> StateActivation {
+    Box::new(move |app| {
+        app.insert_resource(ControllerStateGate::<C>::default())
+            .add_system_to_stage(
+                CoreStage::First,
+                controller_state_system::<C, S>(state.clone()),
+            );
+    })
+}
+
+/// Whether the app is in the state that controllers of type `C` run in. Only present for plugins set up with
+/// `run_in_state`.
+#[derive(Resource)]
+pub(crate) struct ControllerStateGate<C: CameraController> {
+    active: bool,
+    marker: PhantomData<fn() -> C>,
+}
+
+impl<C: CameraController> Default for ControllerStateGate<C> {
+    fn default() -> Self {
+        Self {
+            active: false,
+            marker: PhantomData,
+        }
+    }
+}
+
+/// Opens the gate of controllers of type `C` while the app is in `active_state`. Entering the state resets their
+/// smoothers, so the cameras resync with their `LookTransform` instead of jumping. The controllers' `enabled` flags are
+/// left to the app.
+#[allow(clippy::type_complexity)]
+fn controller_state_system<C: CameraController, S: StateData>(
+    active_state: S,
+) -> impl FnMut(Res<State<S>>, ResMut<ControllerStateGate<C>>, Query<&mut Smoother, With<C>>) {
+    move |state, mut gate, mut smoothers| {
+        let active = *state.current() == active_state;
+        if gate.active == active {
+            return;
+        }
+        gate.active = active;
+
+        if active {
+            for mut smoother in smoothers.iter_mut() {
+                smoother.reset();
+            }
+        }
+    }
+}
+
+/// Run criteria of the control systems of controllers of type `C`. Without `run_in_state`, they always run.
+pub(crate) fn in_controller_state<C: CameraController>(
+    gate: Option<Res<ControllerStateGate<C>>>,
+) -> ShouldRun {
+    match gate {
+        Some(gate) if !gate.active => ShouldRun::No,
+        _ => ShouldRun::Yes,
+    }
+}
+
+/// Piped after the run criteria of an input map, so it also only runs in the state of controllers of type `C`.
+pub(crate) fn and_in_controller_state<C: CameraController>(
+    In(should_run): In<ShouldRun>,
+    gate: Option<Res<ControllerStateGate<C>>>,
+) -> ShouldRun {
+    match in_controller_state(gate) {
+        ShouldRun::No => ShouldRun::No,
+        _ => should_run,
+    }
+}
+
+// ████████╗███████╗███████╗████████╗
+// ╚══██╔══╝██╔════╝██╔════╝╚══██╔══╝
+//    ██║   █████╗  ███████╗   ██║
+//    ██║   ██╔══╝  ╚════██║   ██║
+//    ██║   ███████╗███████║   ██║
+//    ╚═╝   ╚══════╝╚══════╝   ╚═╝
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::controllers::orbit::{
+        ControlEvent, OrbitCameraBundle, OrbitCameraController, OrbitCameraPlugin,
+    };
+    use crate::LookTransform;
+
+    use bevy::{
+        math::prelude::*,
+        prelude::{PerspectiveProjection, Projection},
+        time::Time,
+    };
+
+    #[derive(Clone, Debug, Eq, Hash, PartialEq)]
+    enum AppState {
+        Menu,
+        Editor,
+    }
+
+    #[test]
+    fn test_controllers_only_run_in_state() {
+        let mut app = App::new();
+        app.add_state(AppState::Menu)
+            .init_resource::<Time>()
+            .add_plugin(OrbitCameraPlugin::new(true).run_in_state(AppState::Editor));
+        let disabled = OrbitCameraController {
+            enabled: false,
+            ..Default::default()
+        };
+        let camera = app
+            .world
+            .spawn((
+                OrbitCameraBundle::new(Default::default(), Vec3::new(0.0, 0.0, 5.0), Vec3::ZERO),
+                Projection::Perspective(PerspectiveProjection::default()),
+            ))
+            .id();
+        let other = app
+            .world
+            .spawn((
+                OrbitCameraBundle::new(disabled, Vec3::new(0.0, 0.0, 5.0), Vec3::ZERO),
+                Projection::Perspective(PerspectiveProjection::default()),
+            ))
+            .id();
+        let radius = |app: &App| app.world.get::<LookTransform>(camera).unwrap().radius();
+
+        // Spawned outside of the state, the camera ignores control events.
+        app.world.send_event(ControlEvent::SetRadius(10.0));
+        app.update();
+        assert_eq!(radius(&app), 5.0);
+
+        app.world
+            .resource_mut::<State<AppState>>()
+            .set(AppState::Editor)
+            .unwrap();
+        app.update();
+        app.world.send_event(ControlEvent::SetRadius(10.0));
+        app.update();
+        assert_eq!(radius(&app), 10.0);
+
+        // Entering the state doesn't enable cameras the app disabled.
+        assert!(
+            !app.world
+                .get::<OrbitCameraController>(other)
+                .unwrap()
+                .enabled
+        );
+    }
+}
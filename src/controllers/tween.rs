@@ -0,0 +1,63 @@
+use crate::Easing;
+
+use bevy::{ecs::prelude::*, time::Time};
+
+/// A controller whose parameters (sensitivities, limits, ...) can be animated with a `ControllerTween`.
+pub trait TweenableController: Component + Copy {
+    /// Writes the parameters interpolated between `start` and `end` at `t` (in `[0, 1]`) into `self`. Fields that are not
+    /// parameters, like `enabled`, are left untouched.
+    fn tween_parameters(&mut self, start: &Self, end: &Self, t: f32);
+}
+
+/// Gradually changes the parameters of the controller `C` on the same entity from `start` to `end` over `duration` seconds,
+/// instead of changing them instantly. The component removes itself once the tween is finished.
+#[derive(Component)]
+pub struct ControllerTween<C: TweenableController> {
+    pub start: C,
+    pub end: C,
+    pub duration: f32,
+    pub easing: Easing,
+    elapsed: f32,
+}
+
+impl<C: TweenableController> ControllerTween<C> {
+    pub fn new(start: C, end: C, duration: f32) -> Self {
+        Self {
+            start,
+            end,
+            duration,
+            easing: Easing::Linear,
+            elapsed: 0.0,
+        }
+    }
+
+    pub fn with_easing(mut self, easing: Easing) -> Self {
+        self.easing = easing;
+        self
+    }
+
+    /// The (linear) progress of the tween in `[0, 1]`.
+    pub fn progress(&self) -> f32 {
+        if self.duration > 0.0 {
+            (self.elapsed / self.duration).min(1.0)
+        } else {
+            1.0
+        }
+    }
+}
+
+pub fn tween_controller_system<C: TweenableController>(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut tweens: Query<(Entity, &mut ControllerTween<C>, &mut C)>,
+) {
+    for (entity, mut tween, mut controller) in tweens.iter_mut() {
+        tween.elapsed += time.delta_seconds();
+        let t = tween.progress();
+        controller.tween_parameters(&tween.start, &tween.end, tween.easing.apply(t));
+
+        if t >= 1.0 {
+            commands.entity(entity).remove::<ControllerTween<C>>();
+        }
+    }
+}
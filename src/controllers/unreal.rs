@@ -1,39 +1,110 @@
-use crate::{LookAngles, LookTransform, LookTransformBundle, Smoother};
+use crate::{
+    camera_path_handoff_system,
+    controllers::{
+        apply_deadband,
+        blockers::CameraInputBlockers,
+        defaults::{
+            apply_controller_defaults_system, CameraControllerDefaults, DefaultableController,
+        },
+        devices::{accepts_gamepad, accepts_keyboard_mouse, InputDevices},
+        gamepad_available, input_available,
+        precision::PrecisionMode,
+        prediction::{InputPrediction, MotionPredictor},
+        routed_control_events, set_first_person_angles,
+        state::{and_in_controller_state, in_controller_state, state_activation, StateActivation},
+        synced_look_angles, touch_available,
+        tween::{tween_controller_system, TweenableController},
+        CameraController, GamepadInput, GamepadSticks, InteractionMode, PenInput,
+        TargetedControlEvent, TouchControls, TouchGesture,
+    },
+    cursor_ray, move_eye, send_gesture_transition, CameraCollisionHook, CameraFeedbackEvent,
+    CollisionSettings, CoordinateSystem, Gesture, LookAngles, LookTransform, LookTransformBundle,
+    Smoother,
+};
 
 use bevy::{
     app::prelude::*,
-    ecs::{bundle::Bundle, prelude::*},
+    ecs::{bundle::Bundle, prelude::*, schedule::StateData},
     input::{
         mouse::{MouseMotion, MouseWheel},
         prelude::*,
     },
     math::prelude::*,
     prelude::Camera3dBundle,
-    transform::components::Transform,
+    reflect::Reflect,
+    render::camera::Camera,
+    time::Time,
+    transform::components::{GlobalTransform, Transform},
+    window::Windows,
 };
+#[cfg(feature = "serialize")]
 use serde::{Deserialize, Serialize};
 
 #[derive(Default)]
 pub struct UnrealCameraPlugin {
     pub override_input_system: bool,
+    state_activation: Option<StateActivation>,
 }
 
 impl UnrealCameraPlugin {
     pub fn new(override_input_system: bool) -> Self {
         Self {
             override_input_system,
+            state_activation: None,
         }
     }
+
+    /// Only runs the controls and input maps while the app is in `state`. The smoothers are reset when it is entered.
+    pub fn run_in_state<S: StateData>(mut self, state: S) -> Self {
+        self.state_activation = Some(state_activation::<UnrealCameraController, S>(state));
+        self
+    }
 }
 
 impl Plugin for UnrealCameraPlugin {
     fn build(&self, app: &mut App) {
         let app = app
+            .register_type::<UnrealCameraController>()
+            .register_type::<CollisionSettings>()
+            .register_type::<GamepadInput>()
+            .register_type::<InputPrediction>()
+            .register_type::<InteractionMode>()
+            .register_type::<PenInput>()
+            .register_type::<TouchControls>()
             .add_system_to_stage(CoreStage::PreUpdate, on_controller_enabled_changed)
-            .add_system(control_system)
-            .add_event::<ControlEvent>();
+            .add_system_to_stage(
+                CoreStage::PreUpdate,
+                camera_path_handoff_system::<UnrealCameraController>
+                    .before(on_controller_enabled_changed),
+            )
+            .add_system(
+                control_system.with_run_criteria(in_controller_state::<UnrealCameraController>),
+            )
+            .add_system(tween_controller_system::<UnrealCameraController>)
+            .add_system_to_stage(
+                CoreStage::PreUpdate,
+                apply_controller_defaults_system::<UnrealCameraController>,
+            )
+            .init_resource::<CameraControllerDefaults>()
+            .init_resource::<CoordinateSystem>()
+            .init_resource::<CameraInputBlockers>()
+            .add_event::<ControlEvent>()
+            .add_event::<TargetedControlEvent<ControlEvent>>()
+            .add_event::<CameraFeedbackEvent>();
+        if let Some(state_activation) = &self.state_activation {
+            state_activation(app);
+        }
+
         if !self.override_input_system {
-            app.add_system(default_input_map);
+            app.add_system(default_input_map.with_run_criteria(
+                input_available.pipe(and_in_controller_state::<UnrealCameraController>),
+            ))
+            .add_system(gamepad_input_map.with_run_criteria(
+                gamepad_available.pipe(and_in_controller_state::<UnrealCameraController>),
+            ))
+            .add_system(touch_input_map.with_run_criteria(
+                touch_available.pipe(and_in_controller_state::<UnrealCameraController>),
+            ));
         }
     }
 }
@@ -48,6 +119,8 @@ pub struct UnrealCameraBundle {
 }
 
 impl UnrealCameraBundle {
+    /// The camera starts upright in bevy's Y-up convention. The `LookTransformPlugin` turns it upright in the app's
+    /// `CoordinateSystem` on the first update.
     pub fn new(
         controller: UnrealCameraController,
         mut camera: Camera3dBundle,
@@ -69,7 +142,10 @@ impl UnrealCameraBundle {
 }
 
 /// A camera controlled with the mouse in the same way as Unreal Engine's viewport controller.
-#[derive(Clone, Component, Copy, Debug, Deserialize, Serialize)]
+#[derive(Clone, Component, Copy, Debug, Reflect)]
+#[cfg_attr(feature = "serialize", derive(Deserialize, Serialize))]
+#[reflect(Component)]
+#[cfg_attr(feature = "serialize", serde(default))]
 pub struct UnrealCameraController {
     /// Whether to process input or ignore it
     pub enabled: bool,
@@ -92,6 +168,46 @@ pub struct UnrealCameraController {
 
     /// The greater, the slower to follow input
     pub smoothing_weight: f32,
+
+    /// Mouse motion shorter than this many pixels per frame is ignored, so jitter of a resting high-DPI mouse doesn't keep
+    /// the camera from settling
+    pub mouse_deadband: f32,
+
+    /// Whether mouse drags are active while a button is held or toggled by clicking
+    pub interaction_mode: InteractionMode,
+
+    /// Pen/tablet filtering; the pen's barrel button acts as the middle mouse button
+    pub pen_input: Option<PenInput>,
+
+    /// Canonical look angles, only resynchronized from the `LookTransform` when it is changed externally
+    #[cfg_attr(feature = "serialize", serde(skip))]
+    #[reflect(ignore)]
+    pub look_angles: Option<LookAngles>,
+
+    /// Slide along obstacles instead of moving through them. Requires a `CameraCollisionHook` resource
+    pub collision: Option<CollisionSettings>,
+
+    /// Right stick: rotate, left stick: locomotion and strafing, triggers: pan down/up. Speeds are in units per second
+    pub gamepad: GamepadInput,
+
+    /// One finger: rotate, two fingers: pan, pinch: locomotion forward/backward
+    pub touch: Option<TouchControls>,
+
+    /// Leads rotation by the predicted mouse motion of the next frame, hiding a frame of latency on low frame rates
+    pub input_prediction: Option<InputPrediction>,
+
+    /// Whether scrolling moves toward the point under the cursor instead of straight ahead, keeping that point fixed on
+    /// screen
+    pub zoom_to_cursor: bool,
+
+    /// Limits of the distance between the eye and the target. Movement carries the target along, so only `SetEye` and
+    /// `SetTarget` change the distance
+    pub min_radius: f32,
+    pub max_radius: f32,
+
+    /// Limits of the `LookTransform` scale, which sets the projection scale of orthographic cameras
+    pub min_scale: f32,
+    pub max_scale: f32,
 }
 
 impl Default for UnrealCameraController {
@@ -104,49 +220,237 @@ impl Default for UnrealCameraController {
             keyboard_mvmt_sensitivity: 0.1,
             keyboard_mvmt_wheel_sensitivity: 0.1,
             smoothing_weight: 0.7,
+            mouse_deadband: 0.0,
+            interaction_mode: InteractionMode::Hold,
+            pen_input: None,
+            look_angles: None,
+            collision: None,
+            gamepad: GamepadInput {
+                look_sensitivity: Vec2::splat(2.0),
+                translate_sensitivity: 6.0,
+                zoom_sensitivity: 3.0,
+                ..Default::default()
+            },
+            touch: Some(TouchControls::default()),
+            input_prediction: None,
+            zoom_to_cursor: false,
+            min_radius: 0.001,
+            max_radius: 1000000.0,
+            min_scale: 0.0,
+            max_scale: f32::MAX,
         }
     }
 }
 
+impl UnrealCameraController {
+    /// The (yaw, pitch) of the camera in radians. While the eye and target coincide, these are the last angles the
+    /// controller stored, if any.
+    pub fn yaw_pitch(
+        &self,
+        transform: &LookTransform,
+        coordinates: &CoordinateSystem,
+    ) -> Option<(f32, f32)> {
+        let angles = match transform.look_direction() {
+            Some(look_vector) => synced_look_angles(self.look_angles, look_vector, coordinates),
+            None => self.look_angles?,
+        };
+
+        Some((angles.get_yaw(), angles.get_pitch()))
+    }
+
+    /// Points the camera exactly at (`yaw`, `pitch`) in radians. The pitch is clamped to avoid looking straight up or down.
+    pub fn set_yaw_pitch(
+        &mut self,
+        transform: &mut LookTransform,
+        coordinates: &CoordinateSystem,
+        yaw: f32,
+        pitch: f32,
+    ) {
+        let mut angles = LookAngles::new(*coordinates);
+        angles.set_yaw(yaw);
+        angles.set_pitch(pitch);
+        set_first_person_angles(transform, angles);
+        self.look_angles = Some(angles);
+    }
+}
+
+impl CameraController for UnrealCameraController {
+    fn enabled(&self) -> bool {
+        self.enabled
+    }
+
+    fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+    }
+}
+
+impl DefaultableController for UnrealCameraController {
+    fn from_defaults(defaults: &CameraControllerDefaults) -> Self {
+        defaults.unreal
+    }
+
+    fn apply_settings(&mut self, settings: &Self) {
+        let (enabled, look_angles) = (self.enabled, self.look_angles);
+        *self = *settings;
+        self.enabled = enabled;
+        self.look_angles = look_angles;
+    }
+
+    fn smoothing_weight(&self) -> f32 {
+        self.smoothing_weight
+    }
+}
+
+impl TweenableController for UnrealCameraController {
+    fn tween_parameters(&mut self, start: &Self, end: &Self, t: f32) {
+        let lerp = |a: f32, b: f32| a + (b - a) * t;
+        self.rotate_sensitivity = start.rotate_sensitivity.lerp(end.rotate_sensitivity, t);
+        self.mouse_translate_sensitivity = start
+            .mouse_translate_sensitivity
+            .lerp(end.mouse_translate_sensitivity, t);
+        self.wheel_translate_sensitivity = lerp(
+            start.wheel_translate_sensitivity,
+            end.wheel_translate_sensitivity,
+        );
+        self.keyboard_mvmt_sensitivity = lerp(
+            start.keyboard_mvmt_sensitivity,
+            end.keyboard_mvmt_sensitivity,
+        );
+        self.keyboard_mvmt_wheel_sensitivity = lerp(
+            start.keyboard_mvmt_wheel_sensitivity,
+            end.keyboard_mvmt_wheel_sensitivity,
+        );
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
 pub enum ControlEvent {
     Locomotion(Vec2),
+    /// Moves the eye `distance` along the unit vector `direction` without turning, e.g. toward the point under the
+    /// cursor.
+    LocomotionToward {
+        direction: Vec3,
+        distance: f32,
+    },
     Rotate(Vec2),
+    /// Banks the camera by this many radians about the look direction, see `LookTransform::roll`.
+    Roll(f32),
     TranslateEye(Vec2),
+    /// Moves the eye to this point.
+    SetEye(Vec3),
+    /// Sets the angles of the look direction.
+    SetYawPitch {
+        yaw: f32,
+        pitch: f32,
+    },
+    /// Turns the camera to look at this point, which becomes the target (within the radius limits).
+    SetTarget(Vec3),
 }
 
 define_on_controller_enabled_changed!(UnrealCameraController);
 
+#[allow(clippy::too_many_arguments)]
 pub fn default_input_map(
-    mut events: EventWriter<ControlEvent>,
+    time: Res<Time>,
+    mut events: EventWriter<TargetedControlEvent<ControlEvent>>,
+    mut feedback_events: EventWriter<CameraFeedbackEvent>,
     mut mouse_wheel_reader: EventReader<MouseWheel>,
     mut mouse_motion_events: EventReader<MouseMotion>,
     keyboard: Res<Input<KeyCode>>,
     mouse_buttons: Res<Input<MouseButton>>,
-    mut controllers: Query<&mut UnrealCameraController>,
+    mut controllers: Query<(Entity, &mut UnrealCameraController, Option<&InputDevices>)>,
+    windows: Option<Res<Windows>>,
+    cursor_cameras: Query<(&Camera, &GlobalTransform)>,
+    (mut left_toggled, mut right_toggled, mut middle_toggled, mut barrel_toggled): (
+        Local<bool>,
+        Local<bool>,
+        Local<bool>,
+        Local<bool>,
+    ),
+    mut was_rotating: Local<bool>,
+    mut was_panning: Local<bool>,
+    mut was_locomoting: Local<bool>,
+    mut predictor: Local<MotionPredictor>,
 ) {
-    // Can only control one camera at a time.
-    let mut controller = if let Some(controller) = controllers.iter_mut().find(|c| c.enabled) {
-        controller
+    // The keyboard and mouse can only control one camera at a time.
+    let (entity, mut controller) = if let Some((entity, controller, _)) = controllers
+        .iter_mut()
+        .find(|c| c.1.enabled && accepts_keyboard_mouse(c.2))
+    {
+        (entity, controller)
     } else {
         return;
     };
+    let mut send = |event| {
+        events.send(TargetedControlEvent {
+            camera: entity,
+            event,
+        })
+    };
     let UnrealCameraController {
         rotate_sensitivity: mouse_rotate_sensitivity,
         mouse_translate_sensitivity,
         wheel_translate_sensitivity,
         mut keyboard_mvmt_sensitivity,
         keyboard_mvmt_wheel_sensitivity,
+        interaction_mode,
+        pen_input,
+        mouse_deadband,
+        input_prediction,
+        zoom_to_cursor,
         ..
     } = *controller;
 
-    let left_pressed = mouse_buttons.pressed(MouseButton::Left);
-    let right_pressed = mouse_buttons.pressed(MouseButton::Right);
-    let middle_pressed = mouse_buttons.pressed(MouseButton::Middle);
+    let mut left_pressed =
+        interaction_mode.is_active(&mouse_buttons, MouseButton::Left, &mut left_toggled);
+    let mut right_pressed =
+        interaction_mode.is_active(&mouse_buttons, MouseButton::Right, &mut right_toggled);
+    let mut middle_pressed =
+        interaction_mode.is_active(&mouse_buttons, MouseButton::Middle, &mut middle_toggled);
 
     let mut cursor_delta = Vec2::ZERO;
     for event in mouse_motion_events.iter() {
         cursor_delta += event.delta;
     }
+    cursor_delta = apply_deadband(cursor_delta, mouse_deadband);
+
+    if let Some(pen_input) = pen_input {
+        cursor_delta = pen_input.filter_motion(cursor_delta);
+        let barrel_pressed = interaction_mode.is_active(
+            &mouse_buttons,
+            pen_input.barrel_button,
+            &mut barrel_toggled,
+        );
+        if barrel_pressed {
+            // The barrel button replaces whichever mouse button the pen reports it as.
+            match pen_input.barrel_button {
+                MouseButton::Left => left_pressed = false,
+                MouseButton::Right => right_pressed = false,
+                _ => {}
+            }
+            middle_pressed = true;
+        }
+    }
+
+    for (gesture, active, was_active) in [
+        (
+            Gesture::Rotate,
+            !left_pressed && !middle_pressed && right_pressed,
+            &mut *was_rotating,
+        ),
+        (
+            Gesture::Pan,
+            middle_pressed || (left_pressed && right_pressed),
+            &mut *was_panning,
+        ),
+        (
+            Gesture::Locomotion,
+            left_pressed && !middle_pressed && !right_pressed,
+            &mut *was_locomoting,
+        ),
+    ] {
+        send_gesture_transition(&mut feedback_events, entity, gesture, active, was_active);
+    }
 
     let mut wheel_delta = 0.0;
     for event in mouse_wheel_reader.iter() {
@@ -201,9 +505,25 @@ pub fn default_input_map(
         keyboard_mvmt_sensitivity += keyboard_mvmt_wheel_sensitivity * wheel_delta;
         controller.keyboard_mvmt_sensitivity = keyboard_mvmt_sensitivity.max(0.01);
     }
-    // Otherwise, if any scrolling is happening, do locomotion along camera view axis
+    // Otherwise, if any scrolling is happening, do locomotion along camera view axis (or the cursor's ray)
     else if wheel_delta != 0.0 {
-        locomotion.y += wheel_translate_sensitivity * wheel_delta;
+        let cursor_direction = windows
+            .as_ref()
+            .and_then(|windows| windows.get_primary())
+            .and_then(|w| w.cursor_position())
+            .zip(cursor_cameras.get(entity).ok())
+            .and_then(|(cursor, (camera, camera_transform))| {
+                cursor_ray(camera, camera_transform, cursor)
+            })
+            .map(|(_, direction)| direction)
+            .filter(|_| zoom_to_cursor);
+        match cursor_direction {
+            Some(direction) => send(ControlEvent::LocomotionToward {
+                direction,
+                distance: wheel_translate_sensitivity * wheel_delta,
+            }),
+            None => locomotion.y += wheel_translate_sensitivity * wheel_delta,
+        }
     }
 
     // You can also pan using the mouse only; add those signals to existing panning
@@ -218,60 +538,299 @@ pub fn default_input_map(
     }
 
     if !left_pressed && !middle_pressed && right_pressed {
-        events.send(ControlEvent::Rotate(
-            mouse_rotate_sensitivity * cursor_delta,
+        let rotate_delta = match input_prediction {
+            Some(settings) => predictor.predict(&settings, cursor_delta, time.delta_seconds()),
+            None => {
+                predictor.reset();
+                cursor_delta
+            }
+        };
+        send(ControlEvent::Rotate(
+            mouse_rotate_sensitivity * rotate_delta,
         ));
+    } else {
+        // A lead left when rotation ends is dropped rather than taken back, since the camera stops with the gesture.
+        predictor.reset();
     }
 
     if panning.length_squared() > 0.0 {
-        events.send(ControlEvent::TranslateEye(panning));
+        send(ControlEvent::TranslateEye(panning));
     }
 
     if locomotion.length_squared() > 0.0 {
-        events.send(ControlEvent::Locomotion(locomotion));
+        send(ControlEvent::Locomotion(locomotion));
     }
 }
 
-pub fn control_system(
-    mut events: EventReader<ControlEvent>,
-    mut cameras: Query<(&UnrealCameraController, &mut LookTransform)>,
+pub fn gamepad_input_map(
+    time: Res<Time>,
+    mut events: EventWriter<TargetedControlEvent<ControlEvent>>,
+    gamepads: Res<Gamepads>,
+    axes: Res<Axis<GamepadAxis>>,
+    button_axes: Res<Axis<GamepadButton>>,
+    controllers: Query<(Entity, &UnrealCameraController, Option<&InputDevices>)>,
 ) {
-    // Can only control one camera at a time.
-    let mut transform = if let Some((_, transform)) = cameras.iter_mut().find(|c| c.0.enabled) {
-        transform
+    let dt = time.delta_seconds();
+    for gamepad in gamepads.iter() {
+        // Each gamepad controls the first enabled camera that accepts it.
+        let (camera, controller) = if let Some((camera, controller, _)) = controllers
+            .iter()
+            .find(|c| c.1.enabled && accepts_gamepad(c.2, gamepad))
+        {
+            (camera, controller)
+        } else {
+            continue;
+        };
+        let mut send = |event| events.send(TargetedControlEvent { camera, event });
+        let GamepadInput {
+            deadzone,
+            look_sensitivity,
+            translate_sensitivity,
+            zoom_sensitivity,
+        } = controller.gamepad;
+
+        let sticks = GamepadSticks::read(gamepad, deadzone, &axes, &button_axes);
+        if sticks.is_idle() {
+            continue;
+        }
+
+        let look = sticks.right_stick;
+        if look != Vec2::ZERO {
+            // Pushing the stick up looks up, like moving the mouse up.
+            send(ControlEvent::Rotate(
+                dt * look_sensitivity * Vec2::new(look.x, -look.y),
+            ));
+        }
+
+        let forward = sticks.left_stick.y;
+        if forward != 0.0 {
+            send(ControlEvent::Locomotion(Vec2::new(
+                0.0,
+                dt * translate_sensitivity * forward,
+            )));
+        }
+
+        let pan = Vec2::new(
+            translate_sensitivity * sticks.left_stick.x,
+            zoom_sensitivity * (sticks.right_trigger - sticks.left_trigger),
+        );
+        if pan != Vec2::ZERO {
+            send(ControlEvent::TranslateEye(dt * pan));
+        }
+    }
+}
+
+pub fn touch_input_map(
+    mut events: EventWriter<TargetedControlEvent<ControlEvent>>,
+    touches: Res<Touches>,
+    controllers: Query<(Entity, &UnrealCameraController, Option<&InputDevices>)>,
+) {
+    // Touch input goes to the camera of the keyboard and mouse.
+    let (camera, controller, touch) = if let Some((camera, controller, _)) = controllers
+        .iter()
+        .find(|c| c.1.enabled && accepts_keyboard_mouse(c.2))
+    {
+        match controller.touch {
+            Some(touch) => (camera, controller, touch),
+            None => return,
+        }
     } else {
         return;
     };
+    let mut send = |event| events.send(TargetedControlEvent { camera, event });
+
+    match TouchGesture::from_touches(&touches) {
+        TouchGesture::None => (),
+        TouchGesture::Drag(delta) => {
+            send(ControlEvent::Rotate(
+                touch.rotate_scale * controller.rotate_sensitivity * delta,
+            ));
+        }
+        TouchGesture::TwoFinger {
+            pan,
+            previous_distance,
+            distance,
+        } => {
+            send(ControlEvent::TranslateEye(
+                touch.pan_scale * controller.mouse_translate_sensitivity * pan,
+            ));
+            // Spreading the fingers moves forward as far as dragging the mouse up by the same distance.
+            let spread = distance - previous_distance;
+            send(ControlEvent::Locomotion(Vec2::new(
+                0.0,
+                touch.pinch_zoom_scale * controller.mouse_translate_sensitivity.y * spread,
+            )));
+        }
+    }
+}
 
-    let look_vector = match transform.look_direction() {
-        Some(safe_look_vector) => safe_look_vector,
-        None => Default::default(),
-    };
-    let mut look_angles = LookAngles::from_vector(look_vector);
+pub fn control_system(
+    coordinates: Res<CoordinateSystem>,
+    collision_hook: Option<Res<CameraCollisionHook>>,
+    mut events: EventReader<ControlEvent>,
+    mut targeted_events: EventReader<TargetedControlEvent<ControlEvent>>,
+    precision: Option<Res<PrecisionMode>>,
+    mut cameras: Query<(Entity, &mut UnrealCameraController, &mut LookTransform)>,
+) {
+    let first_enabled = cameras.iter().find(|c| c.1.enabled).map(|c| c.0);
+    let events = routed_control_events(
+        &mut events,
+        &mut targeted_events,
+        first_enabled,
+        precision.as_deref(),
+    );
+
+    for (camera, mut controller, mut transform) in cameras.iter_mut() {
+        if !controller.enabled {
+            continue;
+        }
+        let camera_events = events.iter().filter(|e| e.0 == camera).map(|e| &e.1);
+        // Only the stored look angles of the controller change, so don't trigger change detection.
+        let new_transform = control_camera(
+            controller.bypass_change_detection(),
+            *transform,
+            camera_events,
+            collision_hook.as_deref(),
+            &coordinates,
+        );
+        // Only write when moved, so the transform isn't marked as changed every frame.
+        if new_transform != *transform {
+            *transform = new_transform;
+        }
+    }
+}
 
-    for event in events.iter() {
+/// Applies `events` to `transform` and returns the new transform, without any ECS plumbing, e.g. to unit test custom
+/// input maps. The look angles stored in the controller are updated in place.
+pub fn control_camera<'a>(
+    controller: &mut UnrealCameraController,
+    mut transform: LookTransform,
+    events: impl IntoIterator<Item = &'a ControlEvent>,
+    collision_hook: Option<&CameraCollisionHook>,
+    coordinates: &CoordinateSystem,
+) -> LookTransform {
+    let look_vector = transform.look_direction().unwrap_or_default();
+    let mut look_angles = synced_look_angles(controller.look_angles, look_vector, coordinates);
+    let look_vector = look_angles.unit_vector();
+
+    for event in events {
         match event {
             ControlEvent::Locomotion(delta) => {
                 // Translates forward/backward and rotates about the Y axis.
                 look_angles.add_yaw(-delta.x);
-                transform.eye += delta.y * look_vector;
+                transform.eye = move_eye(
+                    collision_hook,
+                    controller.collision,
+                    transform.eye,
+                    delta.y * look_vector,
+                );
+            }
+            ControlEvent::LocomotionToward {
+                direction,
+                distance,
+            } => {
+                transform.eye = move_eye(
+                    collision_hook,
+                    controller.collision,
+                    transform.eye,
+                    *distance * *direction,
+                );
             }
             ControlEvent::Rotate(delta) => {
                 // Rotates with pitch and yaw.
                 look_angles.add_yaw(-delta.x);
                 look_angles.add_pitch(-delta.y);
             }
+            ControlEvent::Roll(delta) => {
+                transform.roll += delta;
+            }
             ControlEvent::TranslateEye(delta) => {
                 let yaw_rot = Quat::from_axis_angle(Vec3::Y, look_angles.get_yaw());
-                let rot_x = yaw_rot * Vec3::X;
+                let rot_x = coordinates.to_world(yaw_rot * Vec3::X);
 
                 // Translates up/down (Y) and left/right (X).
-                transform.eye -= delta.x * rot_x - Vec3::new(0.0, delta.y, 0.0);
+                transform.eye = move_eye(
+                    collision_hook,
+                    controller.collision,
+                    transform.eye,
+                    delta.y * coordinates.up() - delta.x * rot_x,
+                );
+            }
+            ControlEvent::SetEye(eye) => {
+                transform.eye = *eye;
+            }
+            ControlEvent::SetYawPitch { yaw, pitch } => {
+                look_angles.set_yaw(*yaw);
+                look_angles.set_pitch(*pitch);
+            }
+            ControlEvent::SetTarget(target) => {
+                if let Some(direction) = (*target - transform.eye).try_normalize() {
+                    look_angles.set_direction(direction);
+                    transform.target = *target;
+                }
             }
         }
     }
 
     look_angles.assert_not_looking_up();
 
-    transform.target = transform.eye + transform.radius() * look_angles.unit_vector();
+    let radius = transform.radius().clamp(
+        controller.min_radius,
+        controller.max_radius.max(controller.min_radius),
+    );
+    transform.target = transform.eye + radius * look_angles.unit_vector();
+    transform.scale = transform.scale.clamp(
+        controller.min_scale,
+        controller.max_scale.max(controller.min_scale),
+    );
+
+    controller.look_angles = Some(look_angles);
+
+    transform
+}
+
+// ████████╗███████╗███████╗████████╗
+// ╚══██╔══╝██╔════╝██╔════╝╚══██╔══╝
+//    ██║   █████╗  ███████╗   ██║
+//    ██║   ██╔══╝  ╚════██║   ██║
+//    ██║   ███████╗███████║   ██║
+//    ╚═╝   ╚══════╝╚══════╝   ╚═╝
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_radius_limits() {
+        let mut controller = UnrealCameraController {
+            min_radius: 1.0,
+            max_radius: 10.0,
+            ..Default::default()
+        };
+        let transform = LookTransform::new(Vec3::ZERO, Vec3::new(0.0, 0.0, -5.0));
+
+        let events = [ControlEvent::SetTarget(Vec3::new(0.0, 0.0, -20.0))];
+        let far = control_camera(
+            &mut controller,
+            transform,
+            &events,
+            None,
+            &CoordinateSystem::Y_UP,
+        );
+        assert!(far.target.abs_diff_eq(Vec3::new(0.0, 0.0, -10.0), 1e-4));
+
+        let events = [ControlEvent::SetEye(Vec3::new(0.0, 0.0, -4.9))];
+        let near = control_camera(
+            &mut controller,
+            transform,
+            &events,
+            None,
+            &CoordinateSystem::Y_UP,
+        );
+        assert!((near.radius() - 1.0).abs() < 1e-4);
+        assert!(near
+            .look_direction()
+            .unwrap()
+            .abs_diff_eq(Vec3::NEG_Z, 1e-4));
+    }
 }
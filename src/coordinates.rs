@@ -0,0 +1,153 @@
+use bevy::{ecs::prelude::*, math::prelude::*};
+
+/// Which way positive yaw turns, seen from above.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum Handedness {
+    /// Counterclockwise, like bevy.
+    #[default]
+    Right,
+    /// Clockwise, like compass headings.
+    Left,
+}
+
+/// The world's up axis, the direction of zero yaw, and which way positive yaw turns. The controllers and the camera
+/// systems read it as a resource, so apps with Z-up worlds (e.g. CAD or engineering data) orbit, pan over the ground and
+/// clamp at the poles as expected without remapping input. `LookAngles` are measured in the coordinate system they are
+/// created with.
+///
+/// The `LookTransformPlugin` inserts the Y-up default unless the app already has this resource.
+///
+/// This is a convention for the camera math only. Bevy still renders the world as is.
+#[derive(Clone, Copy, Debug, PartialEq, Resource)]
+pub struct CoordinateSystem {
+    up: Vec3,
+    forward: Vec3,
+    handedness: Handedness,
+}
+
+impl Default for CoordinateSystem {
+    fn default() -> Self {
+        Self::Y_UP
+    }
+}
+
+impl CoordinateSystem {
+    /// Bevy's convention: Y up, zero yaw along +Z.
+    pub const Y_UP: Self = Self {
+        up: Vec3::Y,
+        forward: Vec3::Z,
+        handedness: Handedness::Right,
+    };
+
+    /// The CAD convention: Z up, zero yaw along +Y.
+    pub const Z_UP: Self = Self {
+        up: Vec3::Z,
+        forward: Vec3::Y,
+        handedness: Handedness::Right,
+    };
+
+    /// `forward` is made perpendicular to `up`. Returns `None` if they are parallel or zero.
+    pub fn new(up: Vec3, forward: Vec3, handedness: Handedness) -> Option<Self> {
+        let up = up.try_normalize()?;
+        let forward = (forward - forward.dot(up) * up).try_normalize()?;
+
+        Some(Self {
+            up,
+            forward,
+            handedness,
+        })
+    }
+
+    pub fn with_handedness(mut self, handedness: Handedness) -> Self {
+        self.handedness = handedness;
+        self
+    }
+
+    pub fn up(&self) -> Vec3 {
+        self.up
+    }
+
+    /// The horizontal direction of zero yaw.
+    pub fn forward(&self) -> Vec3 {
+        self.forward
+    }
+
+    pub fn handedness(&self) -> Handedness {
+        self.handedness
+    }
+
+    /// Maps bevy's Y-up directions (the convention the angle math is written in) into this coordinate system.
+    fn basis(&self) -> Mat3 {
+        let side = match self.handedness {
+            Handedness::Right => self.up.cross(self.forward),
+            Handedness::Left => self.forward.cross(self.up),
+        };
+
+        Mat3::from_cols(side, self.up, self.forward)
+    }
+
+    /// Maps a direction in the Y-up convention into this coordinate system.
+    pub fn to_world(&self, direction: Vec3) -> Vec3 {
+        self.basis() * direction
+    }
+
+    /// Maps a direction in this coordinate system into the Y-up convention.
+    pub fn from_world(&self, direction: Vec3) -> Vec3 {
+        self.basis().transpose() * direction
+    }
+
+    /// The height of `point` along the up axis.
+    pub fn height(&self, point: Vec3) -> f32 {
+        point.dot(self.up)
+    }
+
+    /// Returns `point` moved along the up axis to `height`.
+    pub fn with_height(&self, point: Vec3, height: f32) -> Vec3 {
+        point + (height - self.height(point)) * self.up
+    }
+
+    /// Returns `vector` projected onto the ground plane.
+    pub fn horizontal(&self, vector: Vec3) -> Vec3 {
+        vector - self.height(vector) * self.up
+    }
+}
+
+// ████████╗███████╗███████╗████████╗
+// ╚══██╔══╝██╔════╝██╔════╝╚══██╔══╝
+//    ██║   █████╗  ███████╗   ██║
+//    ██║   ██╔══╝  ╚════██║   ██║
+//    ██║   ███████╗███████║   ██║
+//    ╚═╝   ╚══════╝╚══════╝   ╚═╝
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_y_up_is_identity() {
+        let v = Vec3::new(1.0, 2.0, 3.0);
+        assert_eq!(CoordinateSystem::Y_UP.to_world(v), v);
+        assert_eq!(CoordinateSystem::Y_UP.from_world(v), v);
+    }
+
+    #[test]
+    fn test_z_up_maps_up_and_forward() {
+        let z_up = CoordinateSystem::Z_UP;
+        assert_eq!(z_up.to_world(Vec3::Y), Vec3::Z);
+        assert_eq!(z_up.to_world(Vec3::Z), Vec3::Y);
+        let v = Vec3::new(1.0, 2.0, 3.0);
+        assert!(z_up.from_world(z_up.to_world(v)).abs_diff_eq(v, 1e-6));
+        assert_eq!(
+            z_up.with_height(Vec3::new(1.0, 2.0, 3.0), 0.5),
+            Vec3::new(1.0, 2.0, 0.5)
+        );
+    }
+
+    #[test]
+    fn test_left_handed_mirrors_the_side() {
+        let right = CoordinateSystem::Y_UP;
+        let left = right.with_handedness(Handedness::Left);
+        assert_eq!(left.to_world(Vec3::X), -right.to_world(Vec3::X));
+        assert_eq!(left.to_world(Vec3::Y), right.to_world(Vec3::Y));
+    }
+}
@@ -0,0 +1,58 @@
+use bevy::{
+    ecs::prelude::*, math::prelude::*, render::camera::Camera,
+    transform::components::GlobalTransform,
+};
+
+/// Returns the origin and direction of the world-space ray through `cursor`, given in logical pixels from the bottom-left
+/// corner of the camera's viewport (as reported by bevy's `Window::cursor_position` for a full-window viewport).
+pub fn cursor_ray(
+    camera: &Camera,
+    camera_transform: &GlobalTransform,
+    cursor: Vec2,
+) -> Option<(Vec3, Vec3)> {
+    let viewport_size = camera.logical_viewport_size()?;
+    let ndc = cursor / viewport_size * 2.0 - Vec2::ONE;
+    let ndc_to_world = camera_transform.compute_matrix() * camera.projection_matrix().inverse();
+
+    // Bevy uses a reversed depth buffer, so the near plane is at depth 1.
+    let near = ndc_to_world.project_point3(ndc.extend(1.0));
+    let far = ndc_to_world.project_point3(ndc.extend(f32::EPSILON));
+
+    Some((near, (far - near).try_normalize()?))
+}
+
+/// Returns where the ray hits the plane through `plane_point` with `plane_normal`, if it does so in front of the origin.
+pub fn ray_plane_intersection(
+    origin: Vec3,
+    direction: Vec3,
+    plane_point: Vec3,
+    plane_normal: Vec3,
+) -> Option<Vec3> {
+    let denominator = direction.dot(plane_normal);
+    if denominator.abs() < 1e-6 {
+        return None;
+    }
+    let t = (plane_point - origin).dot(plane_normal) / denominator;
+
+    (t >= 0.0).then(|| origin + t * direction)
+}
+
+/// Finds the scene point under the cursor for controllers that pivot around it. This crate doesn't know the scene's
+/// geometry, so the app provides a function that takes the origin and direction of the cursor ray and returns the first
+/// hit, e.g. from a physics raycast or a depth buffer readback.
+#[derive(Resource)]
+pub struct PivotProvider {
+    pick: Box<dyn Fn(Vec3, Vec3) -> Option<Vec3> + Send + Sync>,
+}
+
+impl PivotProvider {
+    pub fn new(pick: impl Fn(Vec3, Vec3) -> Option<Vec3> + Send + Sync + 'static) -> Self {
+        Self {
+            pick: Box::new(pick),
+        }
+    }
+
+    pub fn pick(&self, origin: Vec3, direction: Vec3) -> Option<Vec3> {
+        (self.pick)(origin, direction)
+    }
+}
@@ -0,0 +1,101 @@
+use crate::{CameraFeedbackEvent, Gesture, LookTransformSystem};
+
+use bevy::{
+    app::prelude::*,
+    ecs::prelude::*,
+    window::{CursorIcon, Windows},
+};
+
+/// Tracks the desired cursor icon of `CursorHint` cameras, and optionally applies it to the primary window.
+#[derive(Default)]
+pub struct CursorHintPlugin {
+    pub set_window_cursor: bool,
+}
+
+impl Plugin for CursorHintPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<CameraFeedbackEvent>()
+            .add_system(cursor_hint_system.after(LookTransformSystem));
+
+        if self.set_window_cursor {
+            app.add_system(window_cursor_system.after(cursor_hint_system));
+        }
+    }
+}
+
+/// The cursor icon a camera wants, based on the gestures reported in its `CameraFeedbackEvent`s.
+#[derive(Clone, Component, Debug)]
+pub struct CursorHint {
+    /// Shown while no gesture is active, e.g. `CursorIcon::Grab` to hint that the view can be dragged.
+    pub idle: CursorIcon,
+    active_gestures: Vec<Gesture>,
+}
+
+impl Default for CursorHint {
+    fn default() -> Self {
+        Self {
+            idle: CursorIcon::Grab,
+            active_gestures: Vec::new(),
+        }
+    }
+}
+
+impl CursorHint {
+    /// The icon of the most recently started gesture that is still active, or `idle`.
+    pub fn icon(&self) -> CursorIcon {
+        self.active_gestures
+            .last()
+            .map_or(self.idle, |gesture| gesture_icon(*gesture))
+    }
+
+    pub fn is_gesture_active(&self) -> bool {
+        !self.active_gestures.is_empty()
+    }
+}
+
+pub fn gesture_icon(gesture: Gesture) -> CursorIcon {
+    match gesture {
+        Gesture::Orbit => CursorIcon::Grabbing,
+        Gesture::Pan => CursorIcon::Move,
+        Gesture::Rotate => CursorIcon::Crosshair,
+        Gesture::Locomotion => CursorIcon::ZoomIn,
+        Gesture::Zoom => CursorIcon::NsResize,
+    }
+}
+
+fn cursor_hint_system(
+    mut events: EventReader<CameraFeedbackEvent>,
+    mut cameras: Query<&mut CursorHint>,
+) {
+    for event in events.iter() {
+        match *event {
+            CameraFeedbackEvent::GestureStarted { camera, gesture } => {
+                if let Ok(mut hint) = cameras.get_mut(camera) {
+                    if !hint.active_gestures.contains(&gesture) {
+                        hint.active_gestures.push(gesture);
+                    }
+                }
+            }
+            CameraFeedbackEvent::GestureEnded { camera, gesture } => {
+                if let Ok(mut hint) = cameras.get_mut(camera) {
+                    hint.active_gestures.retain(|g| *g != gesture);
+                }
+            }
+            _ => (),
+        }
+    }
+}
+
+fn window_cursor_system(mut windows: ResMut<Windows>, cameras: Query<&CursorHint>) {
+    let icon = cameras
+        .iter()
+        .find(|hint| hint.is_gesture_active())
+        .or_else(|| cameras.iter().next())
+        .map(CursorHint::icon);
+
+    if let (Some(icon), Some(window)) = (icon, windows.get_primary_mut()) {
+        if window.cursor_icon() != icon {
+            window.set_cursor_icon(icon);
+        }
+    }
+}
@@ -0,0 +1,213 @@
+use crate::{LookTransform, LookTransformSystem};
+
+use bevy::{
+    app::prelude::*, ecs::prelude::*, math::prelude::*, transform::components::GlobalTransform,
+};
+
+/// Moves `DollyTrack` cameras along their tracks, scrubbed by `DollyScrub` events.
+pub struct DollyTrackPlugin;
+
+impl Plugin for DollyTrackPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<DollyScrub>()
+            .add_system(dolly_track_system.before(LookTransformSystem));
+    }
+}
+
+/// Moves the eye of a `DollyTrack` camera along its track by `distance` world units. Bind it to any input to let the player
+/// scrub along the track.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct DollyScrub {
+    pub camera: Entity,
+    pub distance: f32,
+}
+
+/// Restricts the eye of the `LookTransform` on the same entity to a Catmull-Rom spline through `points`, like a camera on a
+/// dolly track. The eye sits `position` world units along the track, and the target follows `target_entity` if set.
+///
+/// Disable the camera's controller, or at least its eye movement, so it doesn't fight over the `LookTransform`.
+#[derive(Clone, Component, Debug)]
+pub struct DollyTrack {
+    points: Vec<Vec3>,
+    /// Cumulative arc length at each sample of the spline.
+    arc_lengths: Vec<f32>,
+    pub position: f32,
+    pub target_entity: Option<Entity>,
+    /// Added to the translation of `target_entity`.
+    pub target_offset: Vec3,
+}
+
+impl DollyTrack {
+    const SAMPLES_PER_SEGMENT: usize = 16;
+
+    pub fn new(points: Vec<Vec3>) -> Self {
+        let mut track = Self {
+            points,
+            arc_lengths: Vec::new(),
+            position: 0.0,
+            target_entity: None,
+            target_offset: Vec3::ZERO,
+        };
+        track.arc_lengths = track.measure();
+
+        track
+    }
+
+    pub fn tracking(mut self, entity: Entity, offset: Vec3) -> Self {
+        self.target_entity = Some(entity);
+        self.target_offset = offset;
+        self
+    }
+
+    pub fn points(&self) -> &[Vec3] {
+        &self.points
+    }
+
+    /// The total length of the track, in world units.
+    pub fn length(&self) -> f32 {
+        self.arc_lengths.last().copied().unwrap_or(0.0)
+    }
+
+    /// Moves the eye along the track, stopping at its ends.
+    pub fn scrub(&mut self, distance: f32) {
+        self.position = (self.position + distance).clamp(0.0, self.length());
+    }
+
+    /// The point at `distance` world units along the track.
+    pub fn point_at(&self, distance: f32) -> Option<Vec3> {
+        match self.points.len() {
+            0 => return None,
+            1 => return Some(self.points[0]),
+            _ => (),
+        }
+
+        let distance = distance.clamp(0.0, self.length());
+        let sample = self
+            .arc_lengths
+            .partition_point(|length| *length < distance)
+            .max(1);
+        let (start, end) = (self.arc_lengths[sample - 1], self.arc_lengths[sample]);
+        let fraction = if end > start {
+            (distance - start) / (end - start)
+        } else {
+            0.0
+        };
+
+        Some(self.spline_point((sample - 1) as f32 + fraction))
+    }
+
+    /// The point at spline parameter `u`, where whole numbers of `u / SAMPLES_PER_SEGMENT` are the control points.
+    fn spline_point(&self, u: f32) -> Vec3 {
+        let u = u / Self::SAMPLES_PER_SEGMENT as f32;
+        let last = self.points.len() - 1;
+        let segment = (u.floor() as usize).min(last - 1);
+        let t = u - segment as f32;
+        let point = |i: isize| self.points[i.clamp(0, last as isize) as usize];
+        let i = segment as isize;
+
+        catmull_rom(point(i - 1), point(i), point(i + 1), point(i + 2), t)
+    }
+
+    fn measure(&self) -> Vec<f32> {
+        if self.points.len() < 2 {
+            return vec![0.0];
+        }
+
+        let samples = (self.points.len() - 1) * Self::SAMPLES_PER_SEGMENT;
+        let mut lengths = Vec::with_capacity(samples + 1);
+        let mut total = 0.0;
+        let mut previous = self.points[0];
+        lengths.push(0.0);
+        for sample in 1..=samples {
+            let point = self.spline_point(sample as f32);
+            total += previous.distance(point);
+            lengths.push(total);
+            previous = point;
+        }
+
+        lengths
+    }
+}
+
+fn catmull_rom(p0: Vec3, p1: Vec3, p2: Vec3, p3: Vec3, t: f32) -> Vec3 {
+    let t2 = t * t;
+    let t3 = t2 * t;
+
+    0.5 * (2.0 * p1
+        + (p2 - p0) * t
+        + (2.0 * p0 - 5.0 * p1 + 4.0 * p2 - p3) * t2
+        + (3.0 * p1 - p0 - 3.0 * p2 + p3) * t3)
+}
+
+pub(crate) fn dolly_track_system(
+    mut scrub_events: EventReader<DollyScrub>,
+    mut cameras: Query<(Entity, &mut DollyTrack, &mut LookTransform)>,
+    targets: Query<&GlobalTransform>,
+) {
+    let scrubs: Vec<DollyScrub> = scrub_events.iter().copied().collect();
+    for (camera, mut track, mut transform) in cameras.iter_mut() {
+        for scrub in scrubs.iter().filter(|s| s.camera == camera) {
+            track.scrub(scrub.distance);
+        }
+
+        if let Some(eye) = track.point_at(track.position) {
+            if transform.eye != eye {
+                transform.eye = eye;
+            }
+        }
+        if let Some(Ok(target)) = track.target_entity.map(|entity| targets.get(entity)) {
+            let target = target.translation() + track.target_offset;
+            if transform.target != target {
+                transform.target = target;
+            }
+        }
+    }
+}
+
+// ████████╗███████╗███████╗████████╗
+// ╚══██╔══╝██╔════╝██╔════╝╚══██╔══╝
+//    ██║   █████╗  ███████╗   ██║
+//    ██║   ██╔══╝  ╚════██║   ██║
+//    ██║   ███████╗███████║   ██║
+//    ╚═╝   ╚══════╝╚══════╝   ╚═╝
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn test_straight_track_is_parameterized_by_distance() {
+        let track = DollyTrack::new(vec![Vec3::ZERO, Vec3::X * 2.0, Vec3::X * 4.0]);
+        assert_relative_eq!(track.length(), 4.0, epsilon = 1e-4);
+        assert!(track.point_at(1.0).unwrap().abs_diff_eq(Vec3::X, 1e-3));
+        assert!(track
+            .point_at(3.0)
+            .unwrap()
+            .abs_diff_eq(Vec3::X * 3.0, 1e-3));
+    }
+
+    #[test]
+    fn test_track_passes_through_its_points() {
+        let points = vec![
+            Vec3::ZERO,
+            Vec3::new(1.0, 0.0, 1.0),
+            Vec3::new(2.0, 1.0, 0.0),
+        ];
+        let track = DollyTrack::new(points.clone());
+        for (i, point) in points.iter().enumerate() {
+            let u = (i * DollyTrack::SAMPLES_PER_SEGMENT) as f32;
+            assert!(track.spline_point(u).abs_diff_eq(*point, 1e-5));
+        }
+    }
+
+    #[test]
+    fn test_scrub_stops_at_the_ends() {
+        let mut track = DollyTrack::new(vec![Vec3::ZERO, Vec3::X]);
+        track.scrub(-1.0);
+        assert_eq!(track.position, 0.0);
+        track.scrub(5.0);
+        assert_relative_eq!(track.position, track.length());
+    }
+}
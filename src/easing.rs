@@ -0,0 +1,246 @@
+#[cfg(feature = "serialize")]
+use serde::{Deserialize, Serialize};
+use std::{fmt, str::FromStr};
+
+/// A timing curve for transitions, with the same names and semantics as CSS easing functions so camera moves can match a
+/// UI animation spec.
+///
+/// Parses from and serializes to CSS syntax, e.g. `"ease-in-out"` or `"cubic-bezier(0.4, 0, 0.2, 1)"`.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(Deserialize, Serialize))]
+#[cfg_attr(feature = "serialize", serde(try_from = "String", into = "String"))]
+pub enum Easing {
+    #[default]
+    Linear,
+    Ease,
+    EaseIn,
+    EaseOut,
+    EaseInOut,
+    /// A cubic Bézier curve from `(0, 0)` to `(1, 1)` with control points `(x1, y1)` and `(x2, y2)`. `x1` and `x2` must
+    /// be in `[0, 1]`; `y1` and `y2` may overshoot.
+    CubicBezier {
+        x1: f32,
+        y1: f32,
+        x2: f32,
+        y2: f32,
+    },
+}
+
+impl Easing {
+    /// Maps linear progress `t` in `[0, 1]` to eased progress.
+    pub fn apply(&self, t: f32) -> f32 {
+        let t = t.clamp(0.0, 1.0);
+        let (x1, y1, x2, y2) = match *self {
+            Easing::Linear => return t,
+            Easing::Ease => (0.25, 0.1, 0.25, 1.0),
+            Easing::EaseIn => (0.42, 0.0, 1.0, 1.0),
+            Easing::EaseOut => (0.0, 0.0, 0.58, 1.0),
+            Easing::EaseInOut => (0.42, 0.0, 0.58, 1.0),
+            Easing::CubicBezier { x1, y1, x2, y2 } => (x1, y1, x2, y2),
+        };
+        if t == 0.0 || t == 1.0 {
+            return t;
+        }
+
+        let s = solve_bezier_parameter(x1, x2, t);
+
+        bezier(y1, y2, s)
+    }
+}
+
+/// One coordinate of the Bézier curve with end points 0 and 1 and control points `p1` and `p2`, at parameter `s`.
+fn bezier(p1: f32, p2: f32, s: f32) -> f32 {
+    let r = 1.0 - s;
+
+    3.0 * r * r * s * p1 + 3.0 * r * s * s * p2 + s * s * s
+}
+
+fn bezier_derivative(p1: f32, p2: f32, s: f32) -> f32 {
+    let r = 1.0 - s;
+
+    3.0 * r * r * p1 + 6.0 * r * s * (p2 - p1) + 3.0 * s * s * (1.0 - p2)
+}
+
+/// Finds the parameter at which the x coordinate of the curve is `x`. Uses Newton's method, falling back to bisection
+/// where the slope is too flat; x is monotonic in the parameter since `x1` and `x2` are in `[0, 1]`.
+fn solve_bezier_parameter(x1: f32, x2: f32, x: f32) -> f32 {
+    let mut s = x;
+    for _ in 0..8 {
+        let error = bezier(x1, x2, s) - x;
+        if error.abs() < 1e-6 {
+            return s;
+        }
+        let slope = bezier_derivative(x1, x2, s);
+        if slope.abs() < 1e-6 {
+            break;
+        }
+        s -= error / slope;
+    }
+
+    let (mut low, mut high) = (0.0, 1.0);
+    s = x;
+    for _ in 0..32 {
+        let value = bezier(x1, x2, s);
+        if (value - x).abs() < 1e-6 {
+            break;
+        }
+        if value < x {
+            low = s;
+        } else {
+            high = s;
+        }
+        s = 0.5 * (low + high);
+    }
+
+    s
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ParseEasingError(String);
+
+impl fmt::Display for ParseEasingError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid easing: {:?}", self.0)
+    }
+}
+
+impl std::error::Error for ParseEasingError {}
+
+impl FromStr for Easing {
+    type Err = ParseEasingError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let error = || ParseEasingError(s.to_owned());
+        let trimmed = s.trim();
+        match trimmed {
+            "linear" => return Ok(Easing::Linear),
+            "ease" => return Ok(Easing::Ease),
+            "ease-in" => return Ok(Easing::EaseIn),
+            "ease-out" => return Ok(Easing::EaseOut),
+            "ease-in-out" => return Ok(Easing::EaseInOut),
+            _ => (),
+        }
+
+        let arguments = trimmed
+            .strip_prefix("cubic-bezier(")
+            .and_then(|rest| rest.strip_suffix(')'))
+            .ok_or_else(error)?;
+        let values = arguments
+            .split(',')
+            .map(|v| v.trim().parse::<f32>())
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|_| error())?;
+        match values[..] {
+            [x1, y1, x2, y2] if (0.0..=1.0).contains(&x1) && (0.0..=1.0).contains(&x2) => {
+                Ok(Easing::CubicBezier { x1, y1, x2, y2 })
+            }
+            _ => Err(error()),
+        }
+    }
+}
+
+impl fmt::Display for Easing {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Easing::Linear => write!(f, "linear"),
+            Easing::Ease => write!(f, "ease"),
+            Easing::EaseIn => write!(f, "ease-in"),
+            Easing::EaseOut => write!(f, "ease-out"),
+            Easing::EaseInOut => write!(f, "ease-in-out"),
+            Easing::CubicBezier { x1, y1, x2, y2 } => {
+                write!(f, "cubic-bezier({}, {}, {}, {})", x1, y1, x2, y2)
+            }
+        }
+    }
+}
+
+impl TryFrom<String> for Easing {
+    type Error = ParseEasingError;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+impl From<Easing> for String {
+    fn from(easing: Easing) -> Self {
+        easing.to_string()
+    }
+}
+
+// ████████╗███████╗███████╗████████╗
+// ╚══██╔══╝██╔════╝██╔════╝╚══██╔══╝
+//    ██║   █████╗  ███████╗   ██║
+//    ██║   ██╔══╝  ╚════██║   ██║
+//    ██║   ███████╗███████║   ██║
+//    ╚═╝   ╚══════╝╚══════╝   ╚═╝
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn test_parse_round_trip() {
+        for s in [
+            "linear",
+            "ease",
+            "ease-in",
+            "ease-out",
+            "ease-in-out",
+            "cubic-bezier(0.4, 0, 0.2, 1)",
+        ] {
+            assert_eq!(s.parse::<Easing>().unwrap().to_string(), s);
+        }
+        assert_eq!(
+            " cubic-bezier(0.4,0,0.2,1) ".parse::<Easing>(),
+            Ok(Easing::CubicBezier {
+                x1: 0.4,
+                y1: 0.0,
+                x2: 0.2,
+                y2: 1.0
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert!("bounce".parse::<Easing>().is_err());
+        assert!("cubic-bezier(0.4, 0, 0.2)".parse::<Easing>().is_err());
+        assert!("cubic-bezier(1.5, 0, 0.2, 1)".parse::<Easing>().is_err());
+    }
+
+    #[test]
+    fn test_end_points() {
+        for easing in [Easing::Linear, Easing::Ease, Easing::EaseInOut] {
+            assert_eq!(easing.apply(0.0), 0.0);
+            assert_eq!(easing.apply(1.0), 1.0);
+        }
+    }
+
+    #[test]
+    fn test_linear_bezier_is_identity() {
+        let easing = Easing::CubicBezier {
+            x1: 0.25,
+            y1: 0.25,
+            x2: 0.75,
+            y2: 0.75,
+        };
+        for t in [0.1, 0.3, 0.5, 0.9] {
+            assert_relative_eq!(easing.apply(t), t, epsilon = 1e-4);
+        }
+    }
+
+    #[test]
+    fn test_ease_in_out_is_symmetric() {
+        assert_relative_eq!(Easing::EaseInOut.apply(0.5), 0.5, epsilon = 1e-4);
+        assert_relative_eq!(
+            Easing::EaseInOut.apply(0.2),
+            1.0 - Easing::EaseInOut.apply(0.8),
+            epsilon = 1e-4
+        );
+        assert!(Easing::EaseIn.apply(0.3) < 0.3);
+        assert!(Easing::EaseOut.apply(0.3) > 0.3);
+    }
+}
@@ -0,0 +1,52 @@
+use bevy::ecs::prelude::*;
+
+/// A drag gesture of one of the built-in controllers.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Gesture {
+    Orbit,
+    Pan,
+    Rotate,
+    Locomotion,
+    Zoom,
+}
+
+/// Semantic events at gesture boundaries and constraint hits, so apps can play sounds or rumble controllers.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum CameraFeedbackEvent {
+    GestureStarted {
+        camera: Entity,
+        gesture: Gesture,
+    },
+    GestureEnded {
+        camera: Entity,
+        gesture: Gesture,
+    },
+    /// A zoom was clamped by the controller's distance limits.
+    ZoomLimitReached {
+        camera: Entity,
+    },
+    /// The look direction reached the edge of a constraint like `LookCone`.
+    ConstraintHit {
+        camera: Entity,
+    },
+}
+
+/// Sends `GestureStarted`/`GestureEnded` when `active` differs from `was_active`, then updates `was_active`.
+pub(crate) fn send_gesture_transition(
+    events: &mut EventWriter<CameraFeedbackEvent>,
+    camera: Entity,
+    gesture: Gesture,
+    active: bool,
+    was_active: &mut bool,
+) {
+    if active == *was_active {
+        return;
+    }
+    *was_active = active;
+
+    events.send(if active {
+        CameraFeedbackEvent::GestureStarted { camera, gesture }
+    } else {
+        CameraFeedbackEvent::GestureEnded { camera, gesture }
+    });
+}
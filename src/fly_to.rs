@@ -0,0 +1,157 @@
+use crate::{CameraMoveHandle, Easing, LookTransform, LookTransformSystem};
+
+use bevy::{app::prelude::*, ecs::prelude::*, math::prelude::*, time::Time};
+
+/// Flies `FlyTo` cameras along their waypoints.
+pub struct FlyToPlugin;
+
+impl Plugin for FlyToPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_system(fly_to_system.before(LookTransformSystem));
+    }
+}
+
+/// Settings of the fly-to path planner.
+#[derive(Clone, Copy, Debug)]
+pub struct FlyPathSettings {
+    /// How far (in world units) the eye should stay away from obstacles.
+    pub clearance: f32,
+    /// Number of waypoints between the start and the end.
+    pub waypoints: usize,
+    /// Number of relaxation iterations used to push waypoints away from obstacles.
+    pub iterations: usize,
+}
+
+impl Default for FlyPathSettings {
+    fn default() -> Self {
+        Self {
+            clearance: 1.0,
+            waypoints: 16,
+            iterations: 32,
+        }
+    }
+}
+
+/// Plans a curved eye path from `start` to `end` that keeps away from obstacles. `clearance_query` is supplied by the app
+/// and returns the distance from a point to the closest obstacle (e.g. from a physics engine or a signed distance field).
+///
+/// The returned waypoints include `start` and `end`.
+pub fn plan_fly_path(
+    start: Vec3,
+    end: Vec3,
+    clearance_query: impl Fn(Vec3) -> f32,
+    settings: FlyPathSettings,
+) -> Vec<Vec3> {
+    let segments = settings.waypoints + 1;
+    let mut path: Vec<Vec3> = (0..=segments)
+        .map(|i| start.lerp(end, i as f32 / segments as f32))
+        .collect();
+
+    let epsilon = 0.01 * settings.clearance.max(1e-3);
+    for _ in 0..settings.iterations {
+        for waypoint in &mut path[1..segments] {
+            let point = *waypoint;
+            let clearance = clearance_query(point);
+            if clearance < settings.clearance {
+                // Push the waypoint out along the (numerical) gradient of the clearance.
+                let gradient = Vec3::new(
+                    clearance_query(point + epsilon * Vec3::X) - clearance,
+                    clearance_query(point + epsilon * Vec3::Y) - clearance,
+                    clearance_query(point + epsilon * Vec3::Z) - clearance,
+                );
+                if let Some(away) = gradient.try_normalize() {
+                    *waypoint += (settings.clearance - clearance) * away;
+                }
+            }
+        }
+
+        // Relax the path so the pushed waypoints form a smooth curve.
+        for i in 1..segments {
+            let neighbors = 0.5 * (path[i - 1] + path[i + 1]);
+            path[i] = path[i].lerp(neighbors, 0.25);
+        }
+    }
+
+    path
+}
+
+/// Moves the eye of the `LookTransform` on the same entity along `waypoints` over `duration` seconds, while the target moves
+/// from its current position to `target`. The component removes itself when the flight is over.
+///
+/// Disable the camera's controller during the flight so it doesn't fight over the `LookTransform`.
+#[derive(Component)]
+pub struct FlyTo {
+    pub waypoints: Vec<Vec3>,
+    pub target: Vec3,
+    pub duration: f32,
+    pub easing: Easing,
+    elapsed: f32,
+    start_target: Option<Vec3>,
+    handle: CameraMoveHandle,
+}
+
+impl FlyTo {
+    pub fn new(waypoints: Vec<Vec3>, target: Vec3, duration: f32) -> Self {
+        Self {
+            waypoints,
+            target,
+            duration,
+            easing: Easing::Linear,
+            elapsed: 0.0,
+            start_target: None,
+            handle: CameraMoveHandle::default(),
+        }
+    }
+
+    pub fn with_easing(mut self, easing: Easing) -> Self {
+        self.easing = easing;
+        self
+    }
+
+    /// Resolves when the flight is over (the smoother may still be catching up).
+    pub fn handle(&self) -> CameraMoveHandle {
+        self.handle.clone()
+    }
+}
+
+/// Returns the point at `t` in `[0, 1]` along the polyline, by arc length.
+fn point_along(waypoints: &[Vec3], t: f32) -> Option<Vec3> {
+    let total: f32 = waypoints.windows(2).map(|w| w[0].distance(w[1])).sum();
+    let mut remaining = t.clamp(0.0, 1.0) * total;
+    for segment in waypoints.windows(2) {
+        let length = segment[0].distance(segment[1]);
+        if remaining <= length && length > 0.0 {
+            return Some(segment[0].lerp(segment[1], remaining / length));
+        }
+        remaining -= length;
+    }
+
+    waypoints.last().copied()
+}
+
+pub(crate) fn fly_to_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut cameras: Query<(Entity, &mut FlyTo, &mut LookTransform)>,
+) {
+    for (entity, mut fly_to, mut transform) in cameras.iter_mut() {
+        let start_target = *fly_to.start_target.get_or_insert(transform.target);
+        fly_to.elapsed += time.delta_seconds();
+        let t = if fly_to.duration > 0.0 {
+            (fly_to.elapsed / fly_to.duration).min(1.0)
+        } else {
+            1.0
+        };
+        let eased = fly_to.easing.apply(t);
+
+        if let Some(eye) = point_along(&fly_to.waypoints, eased) {
+            transform.eye = eye;
+        }
+        transform.target = start_target.lerp(fly_to.target, eased);
+
+        if t >= 1.0 {
+            fly_to.handle.complete();
+            commands.entity(entity).remove::<FlyTo>();
+        }
+    }
+}
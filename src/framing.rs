@@ -0,0 +1,315 @@
+use bevy::math::prelude::*;
+
+/// Which dimensions of the bounds have to fit in the viewport.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum FitMode {
+    /// The width of the bounds fills the width of the viewport.
+    Width,
+    /// The height of the bounds fills the height of the viewport.
+    Height,
+    /// The bounds are entirely visible ("contain").
+    Both,
+    /// The bounds cover the whole viewport, possibly cropping them ("cover").
+    Cover,
+}
+
+impl FitMode {
+    /// Combines the zoom needed to fit the width and the height, where a bigger zoom shows more.
+    fn combine(self, fit_width: f32, fit_height: f32) -> f32 {
+        match self {
+            FitMode::Width => fit_width,
+            FitMode::Height => fit_height,
+            FitMode::Both => fit_width.max(fit_height),
+            FitMode::Cover => fit_width.min(fit_height),
+        }
+    }
+}
+
+/// Returns the orthographic projection scale that fits `bounds_size` (width, height in world units) into a viewport that
+/// shows `unit_view_size` world units at scale `1.0`. `margin` is the fraction of the bounds added on each side, e.g. `0.05`
+/// for 5%.
+pub fn fit_orthographic_scale(
+    bounds_size: Vec2,
+    unit_view_size: Vec2,
+    mode: FitMode,
+    margin: f32,
+) -> f32 {
+    let size = bounds_size * (1.0 + 2.0 * margin);
+
+    mode.combine(size.x / unit_view_size.x, size.y / unit_view_size.y)
+}
+
+/// Returns the eye distance at which a perspective camera with vertical field of view `fov` (in radians) and `aspect_ratio`
+/// (width / height) fits `bounds_size` (width, height of a plane facing the camera). `margin` is the fraction of the bounds
+/// added on each side.
+pub fn fit_perspective_distance(
+    bounds_size: Vec2,
+    fov: f32,
+    aspect_ratio: f32,
+    mode: FitMode,
+    margin: f32,
+) -> f32 {
+    let half_size = 0.5 * bounds_size * (1.0 + 2.0 * margin);
+    let tan_half_fov = (fov / 2.0).tan();
+
+    mode.combine(
+        half_size.x / (tan_half_fov * aspect_ratio),
+        half_size.y / tan_half_fov,
+    )
+}
+
+/// Insets of the viewport that should stay clear of framed content, e.g. for display notches or UI bars, as fractions of the
+/// viewport size.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct SafeArea {
+    pub left: f32,
+    pub right: f32,
+    pub top: f32,
+    pub bottom: f32,
+}
+
+impl SafeArea {
+    /// The safe area of a viewport of `viewport_size` pixels with insets given in pixels.
+    pub fn from_pixels(left: f32, right: f32, top: f32, bottom: f32, viewport_size: Vec2) -> Self {
+        Self {
+            left: left / viewport_size.x,
+            right: right / viewport_size.x,
+            top: top / viewport_size.y,
+            bottom: bottom / viewport_size.y,
+        }
+    }
+
+    /// The size of the safe rect as a fraction of the viewport size.
+    pub fn size_fraction(&self) -> Vec2 {
+        Vec2::new(1.0 - self.left - self.right, 1.0 - self.top - self.bottom).max(Vec2::splat(1e-3))
+    }
+
+    /// The center of the safe rect relative to the center of the viewport, as a fraction of the viewport size (+Y up).
+    pub fn center_offset(&self) -> Vec2 {
+        0.5 * Vec2::new(self.left - self.right, self.bottom - self.top)
+    }
+}
+
+/// Like [`fit_orthographic_scale`], but fits the bounds into the `safe_area` of the viewport.
+///
+/// Also returns the offset (in world units along the camera's right and up axes) to add to the eye and target so that the
+/// bounds are centered in the safe rect.
+pub fn fit_orthographic_scale_in_safe_area(
+    bounds_size: Vec2,
+    unit_view_size: Vec2,
+    safe_area: SafeArea,
+    mode: FitMode,
+    margin: f32,
+) -> (f32, Vec2) {
+    let scale = fit_orthographic_scale(
+        bounds_size,
+        unit_view_size * safe_area.size_fraction(),
+        mode,
+        margin,
+    );
+
+    (scale, -safe_area.center_offset() * unit_view_size * scale)
+}
+
+/// Like [`fit_perspective_distance`], but fits the bounds into the `safe_area` of the viewport.
+///
+/// Also returns the offset (in world units along the camera's right and up axes) to add to the eye and target so that the
+/// bounds are centered in the safe rect.
+pub fn fit_perspective_distance_in_safe_area(
+    bounds_size: Vec2,
+    fov: f32,
+    aspect_ratio: f32,
+    safe_area: SafeArea,
+    mode: FitMode,
+    margin: f32,
+) -> (f32, Vec2) {
+    let fraction = safe_area.size_fraction();
+    let half_size = 0.5 * bounds_size * (1.0 + 2.0 * margin) / fraction;
+    let tan_half_fov = (fov / 2.0).tan();
+    let distance = mode.combine(
+        half_size.x / (tan_half_fov * aspect_ratio),
+        half_size.y / tan_half_fov,
+    );
+    let view_size = 2.0 * distance * tan_half_fov * Vec2::new(aspect_ratio, 1.0);
+
+    (distance, -safe_area.center_offset() * view_size)
+}
+
+/// Returns the eye-to-target distance at which a new target with bounding radius `new_size` occupies the same fraction of
+/// the screen as the previous target with bounding radius `prev_size` viewed from `prev_radius`, keeping the field of view.
+///
+/// This is the "travelling" half of a focus pull. Send the result to a controller (e.g. the orbit controller's
+/// `ControlEvent::SetRadius`) and the `Smoother` will transition to it.
+pub fn focus_pull_radius(prev_radius: f32, prev_size: f32, new_size: f32) -> f32 {
+    debug_assert!(prev_size > 0.0);
+
+    prev_radius * new_size / prev_size
+}
+
+/// Returns the vertical field of view (in radians) at which a new target with bounding radius `new_size` occupies the same
+/// fraction of the screen as the previous target with bounding radius `prev_size`, keeping the eye-to-target distance
+/// `radius`.
+///
+/// This is the "zoom" half of a focus pull.
+pub fn focus_pull_fov(fov: f32, radius: f32, prev_size: f32, new_size: f32) -> f32 {
+    debug_assert!(radius > 0.0);
+
+    let prev_half_angle = (prev_size / radius).min(1.0).asin();
+    let new_half_angle = (new_size / radius).min(1.0).asin();
+    let tan_half_fov = (fov / 2.0).tan() * new_half_angle.tan() / prev_half_angle.tan();
+
+    2.0 * tan_half_fov.atan()
+}
+
+/// Returns the eye-to-target distance at which a bounding sphere of radius `sphere_radius` fits in a vertical field of view
+/// `fov` (in radians).
+pub fn fit_sphere_distance(sphere_radius: f32, fov: f32) -> f32 {
+    sphere_radius / (fov / 2.0).sin()
+}
+
+/// Returns the orthographic projection scale at which one pixel of a viewport `viewport_height` pixels high covers
+/// `world_units_per_pixel`, where the projection shows `unit_view_height` world units vertically at scale `1.0`.
+///
+/// Use it as the minimum scale of a 2D camera to stop zooming in past 1:1 texel density.
+pub fn orthographic_scale_for_pixel_density(
+    world_units_per_pixel: f32,
+    unit_view_height: f32,
+    viewport_height: f32,
+) -> f32 {
+    world_units_per_pixel * viewport_height / unit_view_height
+}
+
+// ████████╗███████╗███████╗████████╗
+// ╚══██╔══╝██╔════╝██╔════╝╚══██╔══╝
+//    ██║   █████╗  ███████╗   ██║
+//    ██║   ██╔══╝  ╚════██║   ██║
+//    ██║   ███████╗███████║   ██║
+//    ╚═╝   ╚══════╝╚══════╝   ╚═╝
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::assert_relative_eq;
+
+    const PI: f32 = std::f32::consts::PI;
+
+    #[test]
+    fn test_focus_pull_radius_scales_with_size() {
+        assert_relative_eq!(focus_pull_radius(10.0, 1.0, 2.0), 20.0);
+        assert_relative_eq!(focus_pull_radius(10.0, 2.0, 1.0), 5.0);
+    }
+
+    #[test]
+    fn test_fit_orthographic_scale_modes() {
+        let bounds = Vec2::new(4.0, 1.0);
+        let view = Vec2::new(2.0, 1.0);
+        assert_relative_eq!(
+            fit_orthographic_scale(bounds, view, FitMode::Width, 0.0),
+            2.0
+        );
+        assert_relative_eq!(
+            fit_orthographic_scale(bounds, view, FitMode::Height, 0.0),
+            1.0
+        );
+        assert_relative_eq!(
+            fit_orthographic_scale(bounds, view, FitMode::Both, 0.0),
+            2.0
+        );
+        assert_relative_eq!(
+            fit_orthographic_scale(bounds, view, FitMode::Cover, 0.0),
+            1.0
+        );
+        assert_relative_eq!(
+            fit_orthographic_scale(bounds, view, FitMode::Height, 0.1),
+            1.2
+        );
+    }
+
+    #[test]
+    fn test_fit_perspective_distance_height() {
+        // With a 90 degree FOV, a plane of height 2 fits at distance 1.
+        let distance =
+            fit_perspective_distance(Vec2::new(1.0, 2.0), PI / 2.0, 1.0, FitMode::Both, 0.0);
+        assert_relative_eq!(distance, 1.0, epsilon = 1e-6f32);
+    }
+
+    #[test]
+    fn test_default_safe_area_matches_full_viewport() {
+        let bounds = Vec2::new(4.0, 1.0);
+        let view = Vec2::new(2.0, 1.0);
+        let (scale, offset) = fit_orthographic_scale_in_safe_area(
+            bounds,
+            view,
+            SafeArea::default(),
+            FitMode::Both,
+            0.1,
+        );
+        assert_relative_eq!(
+            scale,
+            fit_orthographic_scale(bounds, view, FitMode::Both, 0.1)
+        );
+        assert_eq!(offset, Vec2::ZERO);
+
+        let (distance, offset) = fit_perspective_distance_in_safe_area(
+            bounds,
+            PI / 3.0,
+            1.5,
+            SafeArea::default(),
+            FitMode::Both,
+            0.0,
+        );
+        assert_relative_eq!(
+            distance,
+            fit_perspective_distance(bounds, PI / 3.0, 1.5, FitMode::Both, 0.0)
+        );
+        assert_eq!(offset, Vec2::ZERO);
+    }
+
+    #[test]
+    fn test_safe_area_shrinks_and_shifts_the_fit() {
+        // The top quarter is covered, so the bounds fit into the lower 3/4 and the camera moves up by 1/8 of the view.
+        let safe_area = SafeArea::from_pixels(0.0, 0.0, 270.0, 0.0, Vec2::new(1920.0, 1080.0));
+        let (scale, offset) = fit_orthographic_scale_in_safe_area(
+            Vec2::new(1.0, 3.0),
+            Vec2::new(1.0, 1.0),
+            safe_area,
+            FitMode::Height,
+            0.0,
+        );
+        assert_relative_eq!(scale, 4.0);
+        assert_relative_eq!(offset.y, 0.5);
+        assert_relative_eq!(offset.x, 0.0);
+    }
+
+    #[test]
+    fn test_orthographic_scale_for_pixel_density() {
+        // A 1080 pixel high viewport showing 1080 world units at scale 1 has one unit per pixel at scale 1.
+        assert_relative_eq!(
+            orthographic_scale_for_pixel_density(1.0, 1080.0, 1080.0),
+            1.0
+        );
+        // Showing 2 world units vertically, 1 / 540 units per pixel is reached at scale 1.
+        assert_relative_eq!(
+            orthographic_scale_for_pixel_density(1.0 / 540.0, 2.0, 1080.0),
+            1.0
+        );
+    }
+
+    #[test]
+    fn test_fit_sphere_distance() {
+        // A sphere seen at 90 degrees fits when the eye is sqrt(2) radii away.
+        assert_relative_eq!(fit_sphere_distance(1.0, PI / 2.0), 2.0f32.sqrt());
+    }
+
+    #[test]
+    fn test_focus_pull_fov_same_size_is_identity() {
+        assert_relative_eq!(focus_pull_fov(PI / 4.0, 10.0, 1.0, 1.0), PI / 4.0);
+    }
+
+    #[test]
+    fn test_focus_pull_fov_widens_for_bigger_target() {
+        assert!(focus_pull_fov(PI / 4.0, 10.0, 1.0, 2.0) > PI / 4.0);
+        assert!(focus_pull_fov(PI / 4.0, 10.0, 2.0, 1.0) < PI / 4.0);
+    }
+}
@@ -0,0 +1,327 @@
+//! Round-tripping `CameraPath`s with glTF camera animations, e.g. authored in Blender.
+
+use crate::{CameraKeyframe, CameraPath, CoordinateSystem};
+
+use base64::{engine::general_purpose::STANDARD as BASE64, Engine};
+use bevy::{math::prelude::*, transform::components::Transform};
+use gltf::animation::util::ReadOutputs;
+use serde_json::json;
+use std::fmt;
+
+#[derive(Debug)]
+pub enum GltfPathError {
+    Gltf(gltf::Error),
+    /// No node with the given name, or no node with a camera if no name was given.
+    NoCameraNode,
+    /// The camera node is not animated.
+    NoAnimation,
+    /// A keyframe time of the camera node's animation is infinite or NaN.
+    NonFiniteTime,
+}
+
+impl fmt::Display for GltfPathError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GltfPathError::Gltf(e) => write!(f, "failed to read glTF: {}", e),
+            GltfPathError::NoCameraNode => write!(f, "no camera node found"),
+            GltfPathError::NoAnimation => write!(f, "the camera node is not animated"),
+            GltfPathError::NonFiniteTime => write!(f, "the camera animation has a non-finite time"),
+        }
+    }
+}
+
+impl std::error::Error for GltfPathError {}
+
+impl From<gltf::Error> for GltfPathError {
+    fn from(e: gltf::Error) -> Self {
+        GltfPathError::Gltf(e)
+    }
+}
+
+/// Reads the translation and rotation tracks of a camera node from a glTF file (`.gltf` or `.glb` bytes) into a
+/// `CameraPath`. The node is looked up by `node_name`, or is the first node with a camera. Since glTF cameras have no
+/// target, the target is placed `target_distance` in front of the camera.
+pub fn camera_path_from_gltf(
+    bytes: &[u8],
+    node_name: Option<&str>,
+    target_distance: f32,
+) -> Result<CameraPath, GltfPathError> {
+    let (document, buffers, _) = gltf::import_slice(bytes)?;
+    let node = document
+        .nodes()
+        .find(|n| match node_name {
+            Some(name) => n.name() == Some(name),
+            None => n.camera().is_some(),
+        })
+        .ok_or(GltfPathError::NoCameraNode)?;
+
+    let mut translations: Vec<(f32, Vec3)> = Vec::new();
+    let mut rotations: Vec<(f32, Quat)> = Vec::new();
+    for animation in document.animations() {
+        for channel in animation.channels() {
+            if channel.target().node().index() != node.index() {
+                continue;
+            }
+            let reader = channel.reader(|buffer| Some(&buffers[buffer.index()]));
+            let times: Vec<f32> = match reader.read_inputs() {
+                Some(inputs) => inputs.collect(),
+                None => continue,
+            };
+            if times.iter().any(|t| !t.is_finite()) {
+                return Err(GltfPathError::NonFiniteTime);
+            }
+            match reader.read_outputs() {
+                Some(ReadOutputs::Translations(outputs)) => {
+                    translations.extend(times.iter().copied().zip(outputs.map(Vec3::from)))
+                }
+                Some(ReadOutputs::Rotations(outputs)) => rotations.extend(
+                    times
+                        .iter()
+                        .copied()
+                        .zip(outputs.into_f32().map(Quat::from_array)),
+                ),
+                _ => (),
+            }
+        }
+    }
+    if translations.is_empty() && rotations.is_empty() {
+        return Err(GltfPathError::NoAnimation);
+    }
+    // The tracks may be split over several channels and animations.
+    translations.sort_by(|(a, _), (b, _)| a.total_cmp(b));
+    rotations.sort_by(|(a, _), (b, _)| a.total_cmp(b));
+
+    let (rest_translation, rest_rotation, _) = node.transform().decomposed();
+    let mut times: Vec<f32> = translations
+        .iter()
+        .map(|(t, _)| *t)
+        .chain(rotations.iter().map(|(t, _)| *t))
+        .collect();
+    times.sort_by(f32::total_cmp);
+    times.dedup();
+
+    let keyframes = times
+        .into_iter()
+        .map(|time| {
+            let eye = sample_track(&translations, time, Vec3::lerp)
+                .unwrap_or_else(|| Vec3::from(rest_translation));
+            let rotation = sample_track(&rotations, time, Quat::slerp)
+                .unwrap_or_else(|| Quat::from_array(rest_rotation));
+            CameraKeyframe::new(time, eye, eye + target_distance * (rotation * Vec3::NEG_Z))
+        })
+        .collect();
+
+    Ok(CameraPath::new(keyframes))
+}
+
+/// Samples a track sorted by time, interpolating linearly between keys.
+fn sample_track<T: Copy>(track: &[(f32, T)], time: f32, lerp: fn(T, T, f32) -> T) -> Option<T> {
+    let (first_time, first) = *track.first()?;
+    if time <= first_time {
+        return Some(first);
+    }
+    for pair in track.windows(2) {
+        let ((t0, a), (t1, b)) = (pair[0], pair[1]);
+        if time <= t1 {
+            let t = if t1 > t0 {
+                (time - t0) / (t1 - t0)
+            } else {
+                1.0
+            };
+            return Some(lerp(a, b, t));
+        }
+    }
+
+    track.last().map(|(_, value)| *value)
+}
+
+/// Writes `path` as a `.gltf` file with a single animated perspective camera named `node_name`, with the buffer embedded.
+/// glTF only interpolates linearly between keyframes, so keyframe easings are not exported. The camera is kept upright
+/// along the up axis of `coordinates`.
+pub fn camera_path_to_gltf(
+    path: &CameraPath,
+    node_name: &str,
+    coordinates: &CoordinateSystem,
+) -> Vec<u8> {
+    let keyframes = path.keyframes();
+
+    let mut buffer = Vec::new();
+    for keyframe in keyframes {
+        buffer.extend(keyframe.time.to_le_bytes());
+    }
+    let translations_offset = buffer.len();
+    for keyframe in keyframes {
+        for c in keyframe.eye.to_array() {
+            buffer.extend(c.to_le_bytes());
+        }
+    }
+    let rotations_offset = buffer.len();
+    for keyframe in keyframes {
+        let rotation = Transform::from_translation(keyframe.eye)
+            .looking_at(keyframe.target, coordinates.up())
+            .rotation;
+        for c in rotation.to_array() {
+            buffer.extend(c.to_le_bytes());
+        }
+    }
+
+    let count = keyframes.len();
+    let (min_time, max_time) = (
+        keyframes.first().map_or(0.0, |k| k.time),
+        keyframes.last().map_or(0.0, |k| k.time),
+    );
+    let document = json!({
+        "asset": { "version": "2.0", "generator": "smooth-bevy-cameras" },
+        "scene": 0,
+        "scenes": [{ "nodes": [0] }],
+        "nodes": [{ "name": node_name, "camera": 0 }],
+        "cameras": [{
+            "type": "perspective",
+            "perspective": { "yfov": std::f32::consts::FRAC_PI_4, "znear": 0.1 }
+        }],
+        "buffers": [{
+            "byteLength": buffer.len(),
+            "uri": format!("data:application/octet-stream;base64,{}", BASE64.encode(&buffer)),
+        }],
+        "bufferViews": [
+            { "buffer": 0, "byteOffset": 0, "byteLength": translations_offset },
+            {
+                "buffer": 0,
+                "byteOffset": translations_offset,
+                "byteLength": rotations_offset - translations_offset
+            },
+            {
+                "buffer": 0,
+                "byteOffset": rotations_offset,
+                "byteLength": buffer.len() - rotations_offset
+            },
+        ],
+        "accessors": [
+            {
+                "bufferView": 0, "componentType": 5126, "count": count, "type": "SCALAR",
+                "min": [min_time], "max": [max_time]
+            },
+            { "bufferView": 1, "componentType": 5126, "count": count, "type": "VEC3" },
+            { "bufferView": 2, "componentType": 5126, "count": count, "type": "VEC4" },
+        ],
+        "animations": [{
+            "name": node_name,
+            "samplers": [
+                { "input": 0, "output": 1, "interpolation": "LINEAR" },
+                { "input": 0, "output": 2, "interpolation": "LINEAR" },
+            ],
+            "channels": [
+                { "sampler": 0, "target": { "node": 0, "path": "translation" } },
+                { "sampler": 1, "target": { "node": 0, "path": "rotation" } },
+            ],
+        }],
+    });
+
+    serde_json::to_vec_pretty(&document).unwrap()
+}
+
+// ████████╗███████╗███████╗████████╗
+// ╚══██╔══╝██╔════╝██╔════╝╚══██╔══╝
+//    ██║   █████╗  ███████╗   ██║
+//    ██║   ██╔══╝  ╚════██║   ██║
+//    ██║   ███████╗███████║   ██║
+//    ╚═╝   ╚══════╝╚══════╝   ╚═╝
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn test_round_trip() {
+        let path = CameraPath::new(vec![
+            CameraKeyframe::new(0.0, Vec3::new(0.0, 1.0, 5.0), Vec3::new(0.0, 1.0, 0.0)),
+            CameraKeyframe::new(2.0, Vec3::new(5.0, 1.0, 0.0), Vec3::new(0.0, 1.0, 0.0)),
+        ]);
+
+        let bytes = camera_path_to_gltf(&path, "camera", &CoordinateSystem::Y_UP);
+        let loaded = camera_path_from_gltf(&bytes, Some("camera"), 5.0).unwrap();
+
+        assert_eq!(loaded.keyframes().len(), 2);
+        for (a, b) in path.keyframes().iter().zip(loaded.keyframes()) {
+            assert_relative_eq!(a.time, b.time);
+            assert!(a.eye.abs_diff_eq(b.eye, 1e-5));
+            assert!(a.target.abs_diff_eq(b.target, 1e-4));
+        }
+    }
+
+    #[test]
+    fn test_export_upright_in_z_up() {
+        let path = CameraPath::new(vec![
+            CameraKeyframe::new(0.0, Vec3::new(0.0, -5.0, 1.0), Vec3::new(0.0, 0.0, 1.0)),
+            CameraKeyframe::new(1.0, Vec3::new(5.0, 0.0, 1.0), Vec3::new(0.0, 0.0, 1.0)),
+        ]);
+
+        let bytes = camera_path_to_gltf(&path, "camera", &CoordinateSystem::Z_UP);
+        let (document, buffers, _) = gltf::import_slice(&bytes).unwrap();
+        let channel = document
+            .animations()
+            .next()
+            .unwrap()
+            .channels()
+            .nth(1)
+            .unwrap();
+        let reader = channel.reader(|buffer| Some(&buffers[buffer.index()]));
+        let rotations = match reader.read_outputs() {
+            Some(ReadOutputs::Rotations(outputs)) => outputs.into_f32().map(Quat::from_array),
+            _ => panic!("expected a rotation track"),
+        };
+        for rotation in rotations {
+            assert!((rotation * Vec3::Y).abs_diff_eq(Vec3::Z, 1e-5));
+        }
+    }
+
+    #[test]
+    fn test_tracks_split_over_animations() {
+        let path = CameraPath::new(
+            (0..4)
+                .map(|i| {
+                    let eye = Vec3::new(i as f32, 1.0, 5.0);
+                    CameraKeyframe::new(i as f32, eye, eye + Vec3::NEG_Z)
+                })
+                .collect(),
+        );
+        let mut document: serde_json::Value = serde_json::from_slice(&camera_path_to_gltf(
+            &path,
+            "camera",
+            &CoordinateSystem::Y_UP,
+        ))
+        .unwrap();
+
+        // Animate the last two keyframes first, then the first two, with the translation track only.
+        let mut accessors = Vec::new();
+        let mut animations = Vec::new();
+        for first in [2, 0] {
+            let input = accessors.len();
+            accessors.push(json!({
+                "bufferView": 0, "byteOffset": 4 * first, "componentType": 5126, "count": 2,
+                "type": "SCALAR", "min": [first as f32], "max": [first as f32 + 1.0]
+            }));
+            accessors.push(json!({
+                "bufferView": 1, "byteOffset": 12 * first, "componentType": 5126, "count": 2,
+                "type": "VEC3"
+            }));
+            animations.push(json!({
+                "samplers": [{ "input": input, "output": input + 1, "interpolation": "LINEAR" }],
+                "channels": [{ "sampler": 0, "target": { "node": 0, "path": "translation" } }],
+            }));
+        }
+        document["accessors"] = accessors.into();
+        document["animations"] = animations.into();
+
+        let bytes = serde_json::to_vec(&document).unwrap();
+        let loaded = camera_path_from_gltf(&bytes, Some("camera"), 1.0).unwrap();
+
+        assert_eq!(loaded.keyframes().len(), 4);
+        for (a, b) in path.keyframes().iter().zip(loaded.keyframes()) {
+            assert_relative_eq!(a.time, b.time);
+            assert!(a.eye.abs_diff_eq(b.eye, 1e-5));
+        }
+    }
+}
@@ -0,0 +1,244 @@
+use crate::{CoordinateSystem, LookTransform, LookTransformSystem};
+
+use bevy::{
+    app::prelude::*,
+    ecs::{event::ManualEventReader, prelude::*},
+    input::{
+        gamepad::{Gamepad, GamepadAxis, GamepadAxisType, GamepadButton, Gamepads},
+        mouse::{MouseMotion, MouseWheel},
+        prelude::*,
+        InputSystem,
+    },
+    math::prelude::*,
+    time::Time,
+};
+
+/// Detects when nobody has touched the input devices for `CameraIdle::timeout` seconds, sending `CameraIdleEvent`s and
+/// driving `IdleDrift` cameras, e.g. for kiosks and museum installations.
+pub struct CameraIdlePlugin;
+
+impl Plugin for CameraIdlePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<CameraIdle>()
+            .init_resource::<CoordinateSystem>()
+            .add_event::<CameraIdleEvent>()
+            .add_system_to_stage(CoreStage::PreUpdate, camera_idle_system.after(InputSystem))
+            .add_system(idle_drift_system.before(LookTransformSystem));
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum CameraIdleEvent {
+    /// Nobody has used the keyboard, mouse, touch screen or gamepads for `CameraIdle::timeout` seconds.
+    Started,
+    /// Input was received after being idle.
+    Ended,
+}
+
+/// The time since any input, which is camera input for apps where the user only moves the camera.
+#[derive(Clone, Copy, Debug, Resource)]
+pub struct CameraIdle {
+    /// Seconds without input before the app is idle.
+    pub timeout: f32,
+    idle_time: f32,
+    idle: bool,
+}
+
+impl Default for CameraIdle {
+    fn default() -> Self {
+        Self {
+            timeout: 60.0,
+            idle_time: 0.0,
+            idle: false,
+        }
+    }
+}
+
+impl CameraIdle {
+    /// Seconds since the last input.
+    pub fn idle_time(&self) -> f32 {
+        self.idle_time
+    }
+
+    pub fn is_idle(&self) -> bool {
+        self.idle
+    }
+
+    /// Advances by `dt` seconds, with or without input meanwhile. Returns the event if idling started or ended.
+    pub fn update(&mut self, input: bool, dt: f32) -> Option<CameraIdleEvent> {
+        self.idle_time = if input { 0.0 } else { self.idle_time + dt };
+
+        let idle = self.idle_time >= self.timeout;
+        if idle == self.idle {
+            return None;
+        }
+        self.idle = idle;
+        if idle {
+            Some(CameraIdleEvent::Started)
+        } else {
+            Some(CameraIdleEvent::Ended)
+        }
+    }
+}
+
+/// Slowly orbits the `LookTransform` on the same entity around its target while the app is idle, like a screensaver.
+/// The drift fades in when idling starts and stops the moment input arrives, so the controller takes over from where the
+/// drift left the camera.
+#[derive(Clone, Component, Copy, Debug)]
+pub struct IdleDrift {
+    /// Yaw speed in radians per second, around the world up axis.
+    pub speed: f32,
+    /// Seconds until the drift reaches full speed.
+    pub fade_in: f32,
+    drift_time: f32,
+}
+
+impl Default for IdleDrift {
+    fn default() -> Self {
+        Self {
+            speed: 0.1,
+            fade_in: 3.0,
+            drift_time: 0.0,
+        }
+    }
+}
+
+impl IdleDrift {
+    /// Advances the drift by `dt` seconds and returns the yaw covered meanwhile.
+    pub fn advance(&mut self, dt: f32) -> f32 {
+        // The integral of the speed ramping up linearly over `fade_in`.
+        let distance = |time: f32| {
+            if time < self.fade_in {
+                0.5 * self.speed * time * time / self.fade_in
+            } else {
+                self.speed * (time - 0.5 * self.fade_in)
+            }
+        };
+        let start = distance(self.drift_time);
+        self.drift_time += dt;
+
+        distance(self.drift_time) - start
+    }
+
+    /// Restarts the fade-in.
+    pub fn stop(&mut self) {
+        self.drift_time = 0.0;
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+fn camera_idle_system(
+    time: Res<Time>,
+    mut idle: ResMut<CameraIdle>,
+    mut idle_events: EventWriter<CameraIdleEvent>,
+    keyboard: Option<Res<Input<KeyCode>>>,
+    mouse_buttons: Option<Res<Input<MouseButton>>>,
+    mouse_motion: Option<Res<Events<MouseMotion>>>,
+    mouse_wheel: Option<Res<Events<MouseWheel>>>,
+    touches: Option<Res<Touches>>,
+    gamepads: Option<Res<Gamepads>>,
+    gamepad_buttons: Option<Res<Input<GamepadButton>>>,
+    gamepad_axes: Option<Res<Axis<GamepadAxis>>>,
+    (mut motion_reader, mut wheel_reader): (
+        Local<ManualEventReader<MouseMotion>>,
+        Local<ManualEventReader<MouseWheel>>,
+    ),
+) {
+    let sticks_moved = |gamepad: Gamepad| {
+        [
+            GamepadAxisType::LeftStickX,
+            GamepadAxisType::LeftStickY,
+            GamepadAxisType::RightStickX,
+            GamepadAxisType::RightStickY,
+        ]
+        .into_iter()
+        .filter_map(|axis_type| {
+            gamepad_axes
+                .as_ref()?
+                .get(GamepadAxis::new(gamepad, axis_type))
+        })
+        .any(|value| value.abs() > 0.1)
+    };
+    // Read all the events, so old motion doesn't count as input later. The devices are optional, so the system also
+    // runs in apps without bevy's `InputPlugin`.
+    let mouse_moved = mouse_motion.map_or(false, |events| motion_reader.iter(&events).count() > 0);
+    let mouse_scrolled = mouse_wheel.map_or(false, |events| wheel_reader.iter(&events).count() > 0);
+    let input = mouse_moved
+        || mouse_scrolled
+        || keyboard.map_or(false, |k| k.get_pressed().next().is_some())
+        || mouse_buttons.map_or(false, |b| b.get_pressed().next().is_some())
+        || touches.map_or(false, |t| t.iter().next().is_some())
+        || gamepad_buttons.map_or(false, |b| b.get_pressed().next().is_some())
+        || gamepads.map_or(false, |g| g.iter().any(sticks_moved));
+
+    if let Some(event) = idle.update(input, time.delta_seconds()) {
+        idle_events.send(event);
+    }
+}
+
+fn idle_drift_system(
+    time: Res<Time>,
+    idle: Res<CameraIdle>,
+    coordinates: Res<CoordinateSystem>,
+    mut cameras: Query<(&mut IdleDrift, &mut LookTransform)>,
+) {
+    for (mut drift, mut transform) in cameras.iter_mut() {
+        if !idle.is_idle() {
+            if drift.drift_time != 0.0 {
+                drift.stop();
+            }
+            continue;
+        }
+
+        let angle = drift.advance(time.delta_seconds());
+        *transform =
+            transform.rotated_around_target(Quat::from_axis_angle(coordinates.up(), angle));
+    }
+}
+
+// ████████╗███████╗███████╗████████╗
+// ╚══██╔══╝██╔════╝██╔════╝╚══██╔══╝
+//    ██║   █████╗  ███████╗   ██║
+//    ██║   ██╔══╝  ╚════██║   ██║
+//    ██║   ███████╗███████║   ██║
+//    ╚═╝   ╚══════╝╚══════╝   ╚═╝
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_idle_starts_after_timeout_and_ends_on_input() {
+        let mut idle = CameraIdle {
+            timeout: 1.0,
+            ..Default::default()
+        };
+        assert_eq!(idle.update(false, 0.6), None);
+        assert_eq!(idle.update(false, 0.6), Some(CameraIdleEvent::Started));
+        assert_eq!(idle.update(false, 0.6), None);
+        assert!(idle.is_idle());
+
+        assert_eq!(idle.update(true, 0.6), Some(CameraIdleEvent::Ended));
+        assert_eq!(idle.idle_time(), 0.0);
+        assert_eq!(idle.update(false, 0.6), None);
+    }
+
+    #[test]
+    fn test_drift_fades_in() {
+        let mut drift = IdleDrift {
+            speed: 1.0,
+            fade_in: 2.0,
+            ..Default::default()
+        };
+        let first = drift.advance(0.5);
+        let second = drift.advance(0.5);
+        assert!(first > 0.0 && second > first);
+
+        // At full speed after the fade-in.
+        drift.advance(1.0);
+        assert!((drift.advance(0.5) - 0.5).abs() < 1e-6);
+
+        drift.stop();
+        assert_eq!(drift.advance(0.5), first);
+    }
+}
@@ -0,0 +1,213 @@
+use crate::{
+    FlyTo, IntroShot, LookTransform, LookTransformEase, LookTransformSystem, ShotSequence,
+};
+
+use bevy::{app::prelude::*, ecs::prelude::*, time::Time};
+
+/// Sends `CameraIntentEvent`s for `CameraIntentTracking` cameras.
+pub struct CameraIntentPlugin;
+
+impl Plugin for CameraIntentPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<CameraIntentEvent>()
+            .add_system(camera_intent_system.after(LookTransformSystem));
+    }
+}
+
+/// What the user did with a camera, aggregated over whole gestures, so apps can drive tutorials ("try rotating the view")
+/// and usage analytics without interpreting each controller's `ControlEvent`s.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum CameraIntentEvent {
+    /// The view direction turned by `total_angle` radians, whether orbiting or looking around.
+    UserOrbited { camera: Entity, total_angle: f32 },
+    /// The distance between the eye and the target was multiplied by `factor`.
+    UserZoomed { camera: Entity, factor: f32 },
+    /// The target moved `distance` world units.
+    UserPanned { camera: Entity, distance: f32 },
+    /// A framing helper like `FitToSceneEvent` moved the camera.
+    UsedFraming { camera: Entity },
+}
+
+/// Aggregates the motion of the `LookTransform` on the same entity into `CameraIntentEvent`s. A gesture ends when its
+/// kind of motion stops for `idle_time` seconds. Scripted moves (`FlyTo`, `IntroShot`, `LookTransformEase`,
+/// `ShotSequence`) aren't counted.
+#[derive(Clone, Component, Copy, Debug)]
+pub struct CameraIntentTracking {
+    /// Seconds without motion that end a gesture.
+    pub idle_time: f32,
+    /// Gestures that turn less than this many radians aren't reported.
+    pub min_angle: f32,
+    /// Gestures that change the zoom by a smaller factor (in either direction) aren't reported.
+    pub min_zoom_factor: f32,
+    /// Gestures that pan less than this many world units aren't reported.
+    pub min_pan_distance: f32,
+    last: Option<LookTransform>,
+    orbit: GestureAccumulator,
+    zoom: GestureAccumulator,
+    pan: GestureAccumulator,
+}
+
+impl Default for CameraIntentTracking {
+    fn default() -> Self {
+        Self {
+            idle_time: 0.3,
+            min_angle: 0.05,
+            min_zoom_factor: 1.05,
+            min_pan_distance: 0.05,
+            last: None,
+            orbit: GestureAccumulator::default(),
+            zoom: GestureAccumulator::default(),
+            pan: GestureAccumulator::default(),
+        }
+    }
+}
+
+/// Per-frame changes below this are treated as the camera resting, so smoothing residue doesn't extend gestures.
+const EPSILON: f32 = 1e-5;
+
+/// The running total of one kind of motion. Zoom is accumulated as the logarithm of the factor.
+#[derive(Clone, Copy, Debug, Default)]
+struct GestureAccumulator {
+    total: f32,
+    idle: f32,
+    active: bool,
+}
+
+impl GestureAccumulator {
+    /// Adds this frame's motion. Returns the total of a gesture that just ended.
+    fn update(&mut self, amount: f32, dt: f32, idle_time: f32) -> Option<f32> {
+        if amount.abs() > EPSILON {
+            self.total += amount;
+            self.idle = 0.0;
+            self.active = true;
+            return None;
+        }
+        if !self.active {
+            return None;
+        }
+
+        self.idle += dt;
+        if self.idle < idle_time {
+            return None;
+        }
+        self.active = false;
+
+        Some(std::mem::take(&mut self.total))
+    }
+
+    fn reset(&mut self) {
+        *self = Self::default();
+    }
+}
+
+/// The angle between the view directions of two transforms.
+fn view_angle(from: &LookTransform, to: &LookTransform) -> f32 {
+    let (a, b) = (from.look_direction(), to.look_direction());
+    match (a, b) {
+        (Some(a), Some(b)) => a.dot(b).clamp(-1.0, 1.0).acos(),
+        _ => 0.0,
+    }
+}
+
+/// The logarithm of the ratio of the eye-target distances of two transforms.
+fn log_zoom(from: &LookTransform, to: &LookTransform) -> f32 {
+    let (a, b) = (from.radius(), to.radius());
+    if a > 0.0 && b > 0.0 {
+        (b / a).ln()
+    } else {
+        0.0
+    }
+}
+
+#[allow(clippy::type_complexity)]
+fn camera_intent_system(
+    time: Res<Time>,
+    mut events: EventWriter<CameraIntentEvent>,
+    mut cameras: Query<(
+        Entity,
+        &LookTransform,
+        &mut CameraIntentTracking,
+        Option<&FlyTo>,
+        Option<&IntroShot>,
+        Option<&ShotSequence>,
+        Option<&LookTransformEase>,
+    )>,
+) {
+    let dt = time.delta_seconds();
+    for (camera, transform, mut tracking, fly_to, intro_shot, shot_sequence, ease) in
+        cameras.iter_mut()
+    {
+        let last = tracking.last.replace(*transform);
+        if fly_to.is_some() || intro_shot.is_some() || shot_sequence.is_some() || ease.is_some() {
+            tracking.orbit.reset();
+            tracking.zoom.reset();
+            tracking.pan.reset();
+            continue;
+        }
+        let last = match last {
+            Some(last) => last,
+            None => continue,
+        };
+
+        let idle_time = tracking.idle_time;
+        let angle = view_angle(&last, transform);
+        if let Some(total_angle) = tracking.orbit.update(angle, dt, idle_time) {
+            if total_angle >= tracking.min_angle {
+                events.send(CameraIntentEvent::UserOrbited {
+                    camera,
+                    total_angle,
+                });
+            }
+        }
+
+        let zoom = log_zoom(&last, transform);
+        if let Some(log_factor) = tracking.zoom.update(zoom, dt, idle_time) {
+            if log_factor.abs() >= tracking.min_zoom_factor.ln() {
+                events.send(CameraIntentEvent::UserZoomed {
+                    camera,
+                    factor: log_factor.exp(),
+                });
+            }
+        }
+
+        let distance = last.target.distance(transform.target);
+        if let Some(distance) = tracking.pan.update(distance, dt, idle_time) {
+            if distance >= tracking.min_pan_distance {
+                events.send(CameraIntentEvent::UserPanned { camera, distance });
+            }
+        }
+    }
+}
+
+// ████████╗███████╗███████╗████████╗
+// ╚══██╔══╝██╔════╝██╔════╝╚══██╔══╝
+//    ██║   █████╗  ███████╗   ██║
+//    ██║   ██╔══╝  ╚════██║   ██║
+//    ██║   ███████╗███████║   ██║
+//    ╚═╝   ╚══════╝╚══════╝   ╚═╝
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use bevy::math::prelude::*;
+
+    #[test]
+    fn test_gesture_ends_after_idle_time() {
+        let mut acc = GestureAccumulator::default();
+        assert_eq!(acc.update(0.25, 0.1, 0.3), None);
+        assert_eq!(acc.update(0.5, 0.1, 0.3), None);
+        assert_eq!(acc.update(0.0, 0.1, 0.3), None);
+        assert_eq!(acc.update(0.0, 0.1, 0.3), None);
+        assert_eq!(acc.update(0.0, 0.1, 0.3), Some(0.75));
+        assert_eq!(acc.update(0.0, 0.1, 0.3), None);
+    }
+
+    #[test]
+    fn test_zooms_in_and_out_cancel() {
+        let near = LookTransform::new(Vec3::Z, Vec3::ZERO);
+        let far = LookTransform::new(2.0 * Vec3::Z, Vec3::ZERO);
+        assert_relative_eq!(log_zoom(&near, &far) + log_zoom(&far, &near), 0.0);
+        assert_relative_eq!(log_zoom(&near, &far).exp(), 2.0);
+    }
+}
@@ -0,0 +1,235 @@
+use crate::{
+    CameraMoveHandle, CoordinateSystem, Easing, LookTransform, LookTransformSystem, Smoother,
+};
+
+use bevy::{app::prelude::*, ecs::prelude::*, math::prelude::*, time::Time};
+
+/// Plays the opening moves of `IntroShot` cameras.
+pub struct IntroShotPlugin;
+
+impl Plugin for IntroShotPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<CoordinateSystem>()
+            .add_system(intro_shot_system.before(LookTransformSystem));
+    }
+}
+
+/// Ready-made opening moves, ending at the camera's spawned (home) view.
+#[derive(Clone, Copy, Debug)]
+pub enum IntroMove {
+    /// Starts `distance_factor` times further away from the target and dollies in.
+    DollyIn { distance_factor: f32 },
+    /// Starts `rise` world units lower and `sweep` radians around the target (about the world up axis), then rises while orbiting into
+    /// the home view.
+    RiseAndReveal { rise: f32, sweep: f32 },
+    /// Starts at an arbitrary view and moves straight to the home view.
+    From(LookTransform),
+}
+
+impl IntroMove {
+    /// The view at `remaining` in `[0, 1]`, where `1` is the start of the move and `0` is `home`. Rises and sweeps are
+    /// about the up axis of `coordinates`.
+    pub fn transform_at(
+        &self,
+        home: LookTransform,
+        remaining: f32,
+        coordinates: &CoordinateSystem,
+    ) -> LookTransform {
+        let offset = home.eye - home.target;
+        let (eye, target, scale, roll) = match *self {
+            IntroMove::DollyIn { distance_factor } => {
+                let factor = 1.0 + (distance_factor - 1.0) * remaining;
+                (
+                    home.target + factor * offset,
+                    home.target,
+                    home.scale,
+                    home.roll,
+                )
+            }
+            IntroMove::RiseAndReveal { rise, sweep } => {
+                let up = coordinates.up();
+                let target = home.target - rise * remaining * up;
+                let rotation = Quat::from_axis_angle(up, sweep * remaining);
+                (target + rotation * offset, target, home.scale, home.roll)
+            }
+            IntroMove::From(start) => (
+                home.eye.lerp(start.eye, remaining),
+                home.target.lerp(start.target, remaining),
+                home.scale + (start.scale - home.scale) * remaining,
+                home.roll + (start.roll - home.roll) * remaining,
+            ),
+        };
+
+        LookTransform {
+            eye,
+            target,
+            scale,
+            roll,
+        }
+    }
+}
+
+/// Plays an `IntroMove` into the view the camera was spawned with. The component removes itself when the move is over.
+///
+/// ```ignore
+/// commands
+///     .spawn(OrbitCameraBundle::new(controller, eye, target))
+///     .insert(IntroShot::dolly_in(2.0));
+/// ```
+///
+/// Like `FlyTo`, the move writes the `LookTransform` every frame, so ignore input during the intro if it shouldn't be
+/// interrupted.
+#[derive(Component)]
+pub struct IntroShot {
+    pub intro: IntroMove,
+    pub duration: f32,
+    pub easing: Easing,
+    elapsed: f32,
+    home: Option<LookTransform>,
+    handle: CameraMoveHandle,
+}
+
+impl IntroShot {
+    pub fn new(intro: IntroMove, duration: f32) -> Self {
+        Self {
+            intro,
+            duration,
+            easing: Easing::EaseInOut,
+            elapsed: 0.0,
+            home: None,
+            handle: CameraMoveHandle::default(),
+        }
+    }
+
+    /// Starts three times further away and dollies into the home view over `duration` seconds.
+    pub fn dolly_in(duration: f32) -> Self {
+        Self::new(
+            IntroMove::DollyIn {
+                distance_factor: 3.0,
+            },
+            duration,
+        )
+    }
+
+    /// Starts low and a quarter turn around the target, then rises into the home view over `duration` seconds.
+    pub fn rise_and_reveal(duration: f32) -> Self {
+        Self::new(
+            IntroMove::RiseAndReveal {
+                rise: 2.0,
+                sweep: std::f32::consts::FRAC_PI_2,
+            },
+            duration,
+        )
+    }
+
+    pub fn with_easing(mut self, easing: Easing) -> Self {
+        self.easing = easing;
+        self
+    }
+
+    /// Resolves when the move is over (the smoother may still be catching up).
+    pub fn handle(&self) -> CameraMoveHandle {
+        self.handle.clone()
+    }
+}
+
+pub(crate) fn intro_shot_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    coordinates: Res<CoordinateSystem>,
+    mut cameras: Query<(
+        Entity,
+        &mut IntroShot,
+        &mut LookTransform,
+        Option<&mut Smoother>,
+    )>,
+) {
+    for (entity, mut intro, mut transform, smoother) in cameras.iter_mut() {
+        let home = match intro.home {
+            Some(home) => home,
+            None => {
+                // Start smoothing from the first frame of the move instead of the home view.
+                let home = *transform;
+                intro.home = Some(home);
+                if let Some(mut smoother) = smoother {
+                    smoother.prefill(intro.intro.transform_at(home, 1.0, &coordinates));
+                }
+                home
+            }
+        };
+
+        intro.elapsed += time.delta_seconds();
+        let t = if intro.duration > 0.0 {
+            (intro.elapsed / intro.duration).min(1.0)
+        } else {
+            1.0
+        };
+        *transform = intro
+            .intro
+            .transform_at(home, 1.0 - intro.easing.apply(t), &coordinates);
+
+        if t >= 1.0 {
+            intro.handle.complete();
+            commands.entity(entity).remove::<IntroShot>();
+        }
+    }
+}
+
+// ████████╗███████╗███████╗████████╗
+// ╚══██╔══╝██╔════╝██╔════╝╚══██╔══╝
+//    ██║   █████╗  ███████╗   ██║
+//    ██║   ██╔══╝  ╚════██║   ██║
+//    ██║   ███████╗███████║   ██║
+//    ╚═╝   ╚══════╝╚══════╝   ╚═╝
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::assert_relative_eq;
+
+    fn home() -> LookTransform {
+        LookTransform::new(Vec3::new(0.0, 2.0, 5.0), Vec3::ZERO)
+    }
+
+    #[test]
+    fn test_intro_moves_end_at_home() {
+        let moves = [
+            IntroMove::DollyIn {
+                distance_factor: 3.0,
+            },
+            IntroMove::RiseAndReveal {
+                rise: 2.0,
+                sweep: 1.0,
+            },
+            IntroMove::From(LookTransform::new(Vec3::ONE, Vec3::X)),
+        ];
+        for intro in moves {
+            let end = intro.transform_at(home(), 0.0, &CoordinateSystem::Y_UP);
+            assert!(end.eye.abs_diff_eq(home().eye, 1e-5));
+            assert!(end.target.abs_diff_eq(home().target, 1e-5));
+        }
+    }
+
+    #[test]
+    fn test_dolly_in_starts_further_away() {
+        let intro = IntroMove::DollyIn {
+            distance_factor: 3.0,
+        };
+        let start = intro.transform_at(home(), 1.0, &CoordinateSystem::Y_UP);
+        assert_relative_eq!(start.radius(), 3.0 * home().radius(), epsilon = 1e-5);
+        assert_eq!(start.target, home().target);
+    }
+
+    #[test]
+    fn test_rise_and_reveal_starts_low() {
+        let intro = IntroMove::RiseAndReveal {
+            rise: 2.0,
+            sweep: 1.0,
+        };
+        let start = intro.transform_at(home(), 1.0, &CoordinateSystem::Y_UP);
+        assert_relative_eq!(start.target.y, -2.0);
+        assert_relative_eq!(start.eye.y, 0.0);
+        assert_relative_eq!(start.radius(), home().radius(), epsilon = 1e-5);
+    }
+}
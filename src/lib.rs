@@ -13,7 +13,7 @@
 //!
 //! ```rust
 //! use bevy::prelude::*;
-//! use smooth_bevy_cameras::{LookTransform, LookTransformBundle, LookTransformPlugin, Smoother};
+//! use smooth_bevy_cameras::prelude::*;
 //!
 //! fn main() {
 //!     App::new()
@@ -68,6 +68,26 @@
 //!
 //! This is how the built-in controllers implement rotation controls.
 //!
+//! # Coordinate System
+//!
+//! The angles and the built-in controllers assume bevy's Y-up convention by default. For Z-up worlds (e.g. CAD or GIS
+//! data), insert the `CoordinateSystem` resource:
+//!
+//! ```rust
+//! use bevy::prelude::*;
+//! use smooth_bevy_cameras::{CoordinateSystem, LookTransformPlugin};
+//!
+//! App::new()
+//!     .insert_resource(CoordinateSystem::Z_UP)
+//!     .add_plugin(LookTransformPlugin);
+//! ```
+//!
+//! Orbiting then turns about the Z axis, panning and height locks stay parallel to the XY plane, and pitch is clamped at
+//! the Z poles. The follow camera's `target_offset` is given in the Y-up convention and mapped the same way, and
+//! `camera_path_to_gltf` takes the coordinate system to keep exported cameras upright.
+//!
+//! `LookAngles` are measured in the coordinate system they are created with, e.g. with `LookAngles::from_vector_in`.
+//!
 //! # Built-In Controllers
 //!
 //! These plugins depend on the `LookTransformPlugin`:
@@ -75,11 +95,28 @@
 //! - `FpsCameraPlugin` + `FpsCameraBundle`
 //!   - WASD: Translate on the XZ plane
 //!   - Shift/Space: Translate along the Y axis
+//!   - C: Crouch
 //!   - Mouse: Rotate camera
+//!   - Gamepad: Right stick to look, left stick to translate, triggers to move down/up
+//! - `FollowCameraPlugin` + `FollowCameraBundle` (follows an entity, e.g. the player character)
+//!   - Mouse: Orbit around the followed entity
+//!   - Mouse wheel: Zoom
+//! - `ObservationCameraPlugin` + `ObservationCameraBundle` (spawned as a child of a vehicle's mount point)
+//!   - Mouse: Look around, limited to a cone around the mount's forward direction
+//!   - Returns to looking forward after being idle
 //! - `OrbitCameraPlugin` + `OrbitCameraBundle`
 //!   - CTRL + mouse drag: Rotate camera
 //!   - Right mouse drag: Pan camera
 //!   - Mouse wheel: Zoom
+//!   - Gamepad: Right stick to rotate, left stick to pan, triggers to zoom
+//!   - Touch: One finger to rotate, two fingers to pan, pinch to zoom
+//! - `PanCam2dPlugin` + `PanCam2dBundle` (for 2D cameras)
+//!   - Left mouse drag: Pan
+//!   - Mouse wheel: Zoom toward the cursor
+//! - `RtsCameraPlugin` + `RtsCameraBundle`
+//!   - WASD/arrow keys or cursor at the window's edge: Pan on the ground
+//!   - Q/E: Rotate around the vertical axis
+//!   - Mouse wheel: Zoom, optionally changing the pitch
 //! - `UnrealCameraPlugin` + `UnrealCameraBundle`
 //!   Best use: hold Right mouse button to orbit the view while using WASD to navigate in the scene,
 //!   using scroll wheel to accelerate/decelerate.
@@ -90,11 +127,174 @@
 //!   - While holding any mouse button, use W/S for locomotion forward/backward
 //!   - While holding any mouse button, use scroll wheel to increase/decrease locomotion and panning speeds
 //!   - While holding no mouse button, use scroll wheel for locomotion forward/backward
+//!   - Gamepad: Right stick to rotate, left stick for locomotion and panning left/right, triggers to pan down/up
+//!   - Touch: One finger to rotate, two fingers to pan, pinch for locomotion forward/backward
+//!
+//! The default input maps only run when bevy's `InputPlugin` is present, so in a headless app (e.g. a server replaying
+//! cinematics with `MinimalPlugins`) the controllers can still be driven by sending their `ControlEvent`s.
+//!
+//! To temporarily block the default input maps, e.g. while a modal dialog is open, push a reason onto the
+//! `CameraInputBlockers` resource and pop it again when done.
+//!
+//! For split-screen, several cameras of the same kind can be enabled at once. Give each an `InputDevices` component to
+//! route the keyboard/mouse or a gamepad to it, and address scripted moves to a specific camera with a
+//! `TargetedControlEvent`. Untargeted `ControlEvent`s go to the first enabled camera.
+//!
+//! # Camera Features
+//!
+//! The `LookTransformPlugin` only smooths `LookTransform`s. Each optional camera component comes with its own plugin, so an
+//! app only schedules the systems it uses:
+//!
+//! - Cinematics: `CameraPathPlugin`, `DollyTrackPlugin`, `FlyToPlugin`, `IntroShotPlugin`, `ShotSequencePlugin`
+//! - Framing: `AspectFovPlugin`, `LookAtTargetPlugin`, `OrthographicDepthPlugin`, `ResizeFramingPlugin`
+//! - Constraints and effects: `LookConePlugin`, `SpringArmPlugin`, `CameraShakePlugin` (`HandheldNoise` and `CameraShake`)
+//! - Utilities: `CameraBasisPlugin`, `CameraLinkPlugin`, `CameraMovePlugin`, `SnapshotInterpolationPlugin`, `ZoomLodPlugin`
 
 pub mod controllers;
 
+mod aspect_fov;
+mod bounds;
+mod camera_basis;
+mod camera_link;
+mod camera_move;
+mod camera_path;
+mod collision;
+mod constraints;
+mod coordinates;
+mod cursor;
+mod cursor_icon;
+mod dolly_track;
+mod easing;
+mod feedback;
+mod fly_to;
+mod framing;
+#[cfg(feature = "gltf_paths")]
+mod gltf_path;
+mod idle;
+mod intent;
+mod intro;
 mod look_angles;
+mod look_at_target;
 mod look_transform;
+mod modifiers;
+mod motion;
+mod noise;
+mod ortho_depth;
+mod reparent;
+mod resize_framing;
+mod shot_sequence;
+mod snapshot;
+mod spring_arm;
+mod stereo;
+#[cfg(all(feature = "validate", debug_assertions))]
+mod validate;
+mod zoom_lod;
 
+pub use aspect_fov::*;
+pub use bounds::*;
+pub use camera_basis::*;
+pub use camera_link::*;
+pub use camera_move::*;
+pub use camera_path::*;
+pub use collision::*;
+pub use constraints::*;
+pub use coordinates::*;
+pub use cursor::*;
+pub use cursor_icon::*;
+pub use dolly_track::*;
+pub use easing::*;
+pub use feedback::*;
+pub use fly_to::*;
+pub use framing::*;
+#[cfg(feature = "gltf_paths")]
+pub use gltf_path::*;
+pub use idle::*;
+pub use intent::*;
+pub use intro::*;
 pub use look_angles::*;
+pub use look_at_target::*;
 pub use look_transform::*;
+pub use modifiers::*;
+pub use motion::*;
+pub use noise::*;
+pub use ortho_depth::*;
+pub use reparent::*;
+pub use resize_framing::*;
+pub use shot_sequence::*;
+pub use snapshot::*;
+pub use spring_arm::*;
+pub use stereo::*;
+#[cfg(all(feature = "validate", debug_assertions))]
+pub use validate::LookTransformValidation;
+pub use zoom_lod::*;
+
+/// Everything needed to set up and drive cameras with a single import. Each controller's `ControlEvent` is exported under
+/// the controller's name, e.g. `OrbitControlEvent`.
+pub mod prelude {
+    #[cfg(feature = "settings_asset")]
+    pub use crate::controllers::settings::{CameraSettingsFile, CameraSettingsPlugin};
+    pub use crate::controllers::{
+        blockers::CameraInputBlockers,
+        bookmarks::{
+            BookmarkedCamera, CameraBookmark, CameraBookmarks, CameraBookmarksPlugin,
+            RecallBookmark, SaveBookmark,
+        },
+        calibration::{CalibrationEvent, SensitivityCalibration, SensitivityCalibrationPlugin},
+        defaults::{CameraControllerDefaults, ControllerOverrides},
+        devices::InputDevices,
+        follow::{
+            ControlEvent as FollowControlEvent, FollowCameraBundle, FollowCameraController,
+            FollowCameraPlugin, TargetPrediction,
+        },
+        fps::{
+            ControlEvent as FpsControlEvent, FpsCameraBundle, FpsCameraController, FpsCameraPlugin,
+        },
+        info::CameraControllerInfo,
+        momentum::{AutoRotate, Momentum},
+        observation::{
+            ControlEvent as ObservationControlEvent, ObservationCameraBundle,
+            ObservationCameraController, ObservationCameraPlugin,
+        },
+        orbit::{
+            ControlEvent as OrbitControlEvent, FitToSceneEvent, OrbitCameraBundle,
+            OrbitCameraController, OrbitCameraPlugin, OrbitMode, WheelTilt, ZoomSteps,
+        },
+        pan_cam_2d::{
+            ControlEvent as PanCam2dControlEvent, PanCam2dBundle, PanCam2dController,
+            PanCam2dPlugin,
+        },
+        precision::{PrecisionMode, PrecisionModePlugin},
+        prediction::{InputPrediction, MotionPredictor},
+        quad_view::{QuadView, QuadViewPlugin, QuadViewport},
+        rts::{
+            ControlEvent as RtsControlEvent, RtsCameraBundle, RtsCameraController, RtsCameraPlugin,
+        },
+        tween::ControllerTween,
+        unreal::{
+            ControlEvent as UnrealControlEvent, UnrealCameraBundle, UnrealCameraController,
+            UnrealCameraPlugin,
+        },
+        AxisLock, AxisLockState, CameraController, GamepadInput, GamepadSticks, InteractionMode,
+        MouseChord, MouseChordBindings, PenInput, TargetedControlEvent, TouchControls,
+        TouchGesture,
+    };
+    pub use crate::{
+        AspectFov, AspectFovPlugin, BoundsRegion, CameraBasisPlugin, CameraBounds,
+        CameraCollisionHook, CameraFeedbackEvent, CameraIdle, CameraIdleEvent, CameraIdlePlugin,
+        CameraIntentEvent, CameraIntentPlugin, CameraIntentTracking, CameraKeyframe,
+        CameraLinkGroup, CameraLinkPlugin, CameraModifierExt, CameraModifierOrder,
+        CameraMotionEvent, CameraMoveHandle, CameraMovePlugin, CameraPath, CameraPathEvent,
+        CameraPathPlayer, CameraPathPlugin, CameraShake, CameraShakePlugin, CameraShot,
+        CoordinateSystem, CursorHint, CursorHintPlugin, DollyScrub, DollyTrack, DollyTrackPlugin,
+        Easing, EyeInterpolation, FlyTo, FlyToPlugin, FovScaling, FramingAxis, Gesture, Handedness,
+        HandheldNoise, IdleDrift, IntroMove, IntroShot, IntroShotPlugin, LinkChannels, LookAngles,
+        LookAtTarget, LookAtTargetPlugin, LookCone, LookConePlugin, LookTransform,
+        LookTransformBundle, LookTransformEase, LookTransformPlugin, LookTransformSystem,
+        MotionIntensity, MotionIntensityPlugin, Noise, OrthographicDepth, OrthographicDepthPlugin,
+        PathInterpolation, PathPlaybackCommand, PathPlaybackEvent, PivotProvider, ReparentCamera,
+        ResizeFraming, ResizeFramingPlugin, SafeArea, ShotAdvance, ShotSequence, ShotSequenceEvent,
+        ShotSequencePlugin, ShotSignal, Smoother, SmoothingTime, SnapshotInterpolation,
+        SnapshotInterpolationPlugin, SpeedFov, SpringArm, SpringArmPlugin, StereoEye, StereoPlugin,
+        TargetSmoothingSpace, ZoomLodChanged, ZoomLodLevels, ZoomLodPlugin,
+    };
+}
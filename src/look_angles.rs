@@ -1,36 +1,61 @@
+use crate::CoordinateSystem;
+
 use approx::relative_eq;
 use bevy::math::prelude::*;
 
 const PI: f32 = std::f32::consts::PI;
 
-/// A (yaw, pitch) pair representing a direction.
+/// A (yaw, pitch) pair representing a direction. The yaw turns about the up axis of the angles' `CoordinateSystem`,
+/// starting from its forward direction, and the pitch is the elevation above the ground plane.
 #[derive(Clone, Copy, Debug, Default)]
 pub struct LookAngles {
     // The fields are protected to keep them in an allowable range for the camera transform.
     yaw: f32,
     pitch: f32,
+    coordinates: CoordinateSystem,
 }
 
 impl LookAngles {
+    /// Zero yaw and pitch, i.e. looking along the forward direction of `coordinates`.
+    pub fn new(coordinates: CoordinateSystem) -> Self {
+        Self {
+            yaw: 0.0,
+            pitch: 0.0,
+            coordinates,
+        }
+    }
+
+    /// The angles of `v` in bevy's Y-up convention.
     pub fn from_vector(v: Vec3) -> Self {
-        let mut p = Self::default();
+        Self::from_vector_in(v, CoordinateSystem::Y_UP)
+    }
+
+    /// The angles of `v` in `coordinates`.
+    pub fn from_vector_in(v: Vec3, coordinates: CoordinateSystem) -> Self {
+        let mut p = Self::new(coordinates);
         p.set_direction(v);
 
         p
     }
 
+    pub fn coordinate_system(&self) -> CoordinateSystem {
+        self.coordinates
+    }
+
     pub fn unit_vector(self) -> Vec3 {
-        unit_vector_from_yaw_and_pitch(self.yaw, self.pitch)
+        self.coordinates
+            .to_world(unit_vector_from_yaw_and_pitch(self.yaw, self.pitch))
     }
 
     pub fn set_direction(&mut self, v: Vec3) {
-        let (yaw, pitch) = yaw_and_pitch_from_vector(v);
+        let (yaw, pitch) = yaw_and_pitch_from_vector(self.coordinates.from_world(v));
         self.set_yaw(yaw);
         self.set_pitch(pitch);
     }
 
+    /// Sets the yaw, wrapped into `[-PI, PI)`.
     pub fn set_yaw(&mut self, yaw: f32) {
-        self.yaw = yaw % (2.0 * PI);
+        self.yaw = wrap_angle(yaw);
     }
 
     pub fn get_yaw(&self) -> f32 {
@@ -55,8 +80,18 @@ impl LookAngles {
         self.set_pitch(self.get_pitch() + delta);
     }
 
+    /// Interpolates from `self` to `other`, turning the yaw the short way around (e.g. across the `PI` boundary).
+    pub fn lerp(self, other: Self, t: f32) -> Self {
+        let mut p = self;
+        p.set_yaw(self.yaw + shortest_angle_delta(self.yaw, other.yaw) * t);
+        p.set_pitch(self.pitch + (other.pitch - self.pitch) * t);
+
+        p
+    }
+
     pub fn assert_not_looking_up(&self) {
-        let is_looking_up = relative_eq!(self.unit_vector().dot(Vec3::Y).abs(), 1.0);
+        let up = self.coordinates.up();
+        let is_looking_up = relative_eq!(self.unit_vector().dot(up).abs(), 1.0);
 
         assert!(
             !is_looking_up,
@@ -66,6 +101,16 @@ impl LookAngles {
     }
 }
 
+/// Wraps `angle` into `[-PI, PI)`.
+pub fn wrap_angle(angle: f32) -> f32 {
+    (angle + PI).rem_euclid(2.0 * PI) - PI
+}
+
+/// Returns the signed angle with the smallest magnitude that turns `from` into `to`.
+pub fn shortest_angle_delta(from: f32, to: f32) -> f32 {
+    wrap_angle(to - from)
+}
+
 /// Returns pitch and yaw angles that rotates z unit vector to v. The yaw is applied first to z about the y axis to get z'. Then
 /// the pitch is applied about some axis orthogonal to z' in the XZ plane to get v.
 fn yaw_and_pitch_from_vector(v: Vec3) -> (f32, f32) {
@@ -150,6 +195,41 @@ mod tests {
         assert_relative_eq!(pitch, -PI / 2.0);
     }
 
+    #[test]
+    fn test_wrap_angle() {
+        assert_relative_eq!(wrap_angle(0.0), 0.0);
+        assert_relative_eq!(wrap_angle(3.0 * PI / 2.0), -PI / 2.0);
+        assert_relative_eq!(wrap_angle(-3.0 * PI / 2.0), PI / 2.0, epsilon = 1e-6f32);
+    }
+
+    #[test]
+    fn test_lerp_takes_shortest_yaw_path() {
+        let mut from = LookAngles::default();
+        from.set_yaw(0.9 * PI);
+        let mut to = LookAngles::default();
+        to.set_yaw(-0.9 * PI);
+
+        // Halfway the short way around is PI, not 0.
+        let halfway = from.lerp(to, 0.5);
+        assert_relative_eq!(halfway.get_yaw().abs(), PI, epsilon = 1e-5f32);
+    }
+
+    #[test]
+    fn test_angles_in_z_up() {
+        let mut angles = LookAngles::new(CoordinateSystem::Z_UP);
+        assert!(angles.unit_vector().abs_diff_eq(Vec3::Y, 1e-6));
+        angles.set_pitch(PI / 4.0);
+        assert!(angles
+            .unit_vector()
+            .abs_diff_eq(Vec3::new(0.0, 0.5f32.sqrt(), 0.5f32.sqrt()), 1e-6));
+
+        let angles = LookAngles::from_vector_in(Vec3::Z, CoordinateSystem::Y_UP);
+        assert_relative_eq!(angles.get_pitch(), 0.0);
+        // Clamped short of the pole.
+        let angles = LookAngles::from_vector_in(Vec3::Z, CoordinateSystem::Z_UP);
+        assert_relative_eq!(angles.get_pitch(), PI / 2.0, epsilon = 0.011);
+    }
+
     #[test]
     fn test_yaw_and_pitch() {
         let (yaw, pitch) = yaw_and_pitch_from_vector(Vec3::new(0.5f32.sqrt(), 1.0, 0.5f32.sqrt()));
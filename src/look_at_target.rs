@@ -0,0 +1,107 @@
+use crate::{LookTransform, LookTransformSystem};
+
+use bevy::{
+    app::prelude::*, core::Name, ecs::prelude::*, hierarchy::Children, math::prelude::*,
+    transform::components::GlobalTransform,
+};
+
+/// Points `LookAtTarget` cameras at their pivots.
+pub struct LookAtTargetPlugin;
+
+impl Plugin for LookAtTargetPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_system(look_at_target_system.before(LookTransformSystem));
+    }
+}
+
+/// Points the camera's `LookTransform` at a pivot in the hierarchy of `entity`, like a character's head bone or a
+/// machine's tool tip, instead of the entity's origin.
+///
+/// The pivot is resolved every frame by following `path`, the `Name`s of the descendants from `entity` down to the pivot,
+/// so it keeps working when the hierarchy is respawned (e.g. a glTF scene loading).
+#[derive(Clone, Component, Debug)]
+pub struct LookAtTarget {
+    pub entity: Entity,
+    pub path: Vec<Name>,
+    /// Offset from the pivot, in the pivot's local space.
+    pub offset: Vec3,
+    /// Moves the eye along with the target, keeping their offset, instead of only turning the camera.
+    pub carry_eye: bool,
+}
+
+impl LookAtTarget {
+    pub fn new(entity: Entity) -> Self {
+        Self {
+            entity,
+            path: Vec::new(),
+            offset: Vec3::ZERO,
+            carry_eye: false,
+        }
+    }
+
+    /// Sets the path to the pivot from a `/`-separated string, e.g. `"Armature/Spine/Head"`.
+    pub fn with_path(mut self, path: &str) -> Self {
+        self.path = path
+            .split('/')
+            .filter(|name| !name.is_empty())
+            .map(|name| Name::new(name.to_owned()))
+            .collect();
+        self
+    }
+
+    pub fn with_offset(mut self, offset: Vec3) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    pub fn carrying_eye(mut self) -> Self {
+        self.carry_eye = true;
+        self
+    }
+}
+
+fn resolve_pivot(
+    root: Entity,
+    path: &[Name],
+    children: &Query<&Children>,
+    names: &Query<&Name>,
+) -> Option<Entity> {
+    let mut entity = root;
+    for name in path {
+        entity = children
+            .get(entity)
+            .ok()?
+            .iter()
+            .copied()
+            .find(|child| matches!(names.get(*child), Ok(n) if n == name))?;
+    }
+
+    Some(entity)
+}
+
+pub(crate) fn look_at_target_system(
+    mut cameras: Query<(&LookAtTarget, &mut LookTransform)>,
+    children: Query<&Children>,
+    names: Query<&Name>,
+    global_transforms: Query<&GlobalTransform>,
+) {
+    for (look_at, mut transform) in cameras.iter_mut() {
+        let pivot = match resolve_pivot(look_at.entity, &look_at.path, &children, &names) {
+            Some(pivot) => pivot,
+            None => continue,
+        };
+        let target = match global_transforms.get(pivot) {
+            Ok(global_transform) => global_transform.transform_point(look_at.offset),
+            Err(_) => continue,
+        };
+        if target == transform.target {
+            continue;
+        }
+
+        if look_at.carry_eye {
+            let delta = target - transform.target;
+            transform.eye += delta;
+        }
+        transform.target = target;
+    }
+}
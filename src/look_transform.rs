@@ -1,18 +1,71 @@
+use crate::{
+    modifier_anchor_system, CameraBounds, CameraFeedbackEvent, CameraModifierAnchor,
+    CameraMoveHandle, CoordinateSystem, Easing,
+};
+
 use bevy::{
     app::prelude::*,
     ecs::{bundle::Bundle, prelude::*},
     math::prelude::*,
-    transform::components::Transform, prelude::Projection,
+    prelude::Projection,
+    reflect::Reflect,
+    render::camera::OrthographicProjection,
+    time::Time,
+    transform::components::Transform,
 };
+#[cfg(feature = "serialize")]
+use serde::{Deserialize, Serialize};
+use std::ops::{Add, AddAssign, Sub, SubAssign};
 
 pub struct LookTransformPlugin;
 
 impl Plugin for LookTransformPlugin {
     fn build(&self, app: &mut App) {
-        app.add_system(look_transform_system);
+        app.init_resource::<CoordinateSystem>()
+            .register_type::<LookTransform>()
+            .register_type::<Smoother>()
+            .register_type::<EyeInterpolation>()
+            .register_type::<SmoothingTime>()
+            .register_type::<TargetSmoothingSpace>()
+            .add_event::<CameraFeedbackEvent>()
+            .add_event::<CameraMotionEvent>()
+            .add_system(
+                modifier_anchor_system
+                    .label(CameraModifierAnchor::Constraints)
+                    .before(LookTransformSystem),
+            )
+            .add_system(look_transform_ease_system.before(LookTransformSystem))
+            .add_system(look_transform_system.label(LookTransformSystem))
+            .add_system(
+                modifier_anchor_system
+                    .label(CameraModifierAnchor::Collision)
+                    .after(LookTransformSystem),
+            )
+            .add_system(
+                modifier_anchor_system
+                    .label(CameraModifierAnchor::Effects)
+                    .after(CameraModifierAnchor::Collision),
+            );
+
+        #[cfg(all(feature = "validate", debug_assertions))]
+        crate::validate::add_validation_systems(app);
     }
 }
 
+/// Label of the system that writes the (smoothed) `LookTransform` to the scene `Transform`. Systems that post-process the
+/// final `Transform` should run after it.
+#[derive(Clone, Debug, Eq, Hash, PartialEq, SystemLabel)]
+pub struct LookTransformSystem;
+
+/// Sent by the `LookTransformSystem` when the smoothed transform of a camera starts lagging behind its `LookTransform`, and
+/// when it has caught up again within `Smoother::set_settle_epsilon`. Useful for loading data or enabling expensive
+/// rendering once the camera comes to rest.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum CameraMotionEvent {
+    Started { camera: Entity },
+    Settled { camera: Entity },
+}
+
 #[derive(Bundle)]
 pub struct LookTransformBundle {
     pub transform: LookTransform,
@@ -21,22 +74,41 @@ pub struct LookTransformBundle {
 
 /// An eye and the target it's looking at. As a component, this can be modified in place of bevy's `Transform`, and the two will
 /// stay in sync.
-#[derive(Clone, Component, Copy, Debug)]
+#[derive(Clone, Component, Copy, Debug, PartialEq, Reflect)]
+#[cfg_attr(feature = "serialize", derive(Deserialize, Serialize))]
+#[reflect(Component)]
 pub struct LookTransform {
     pub eye: Vec3,
     pub target: Vec3,
     pub scale: f32,
+    /// Rotation (in radians) about the look direction, where positive values bank to the right. Smoothed like the eye and
+    /// target, so flight and cinematic cameras can bank smoothly.
+    #[cfg_attr(feature = "serialize", serde(default))]
+    pub roll: f32,
+}
+
+impl Default for LookTransform {
+    /// Looking from one unit along +Z at the origin, like a camera in bevy's default orientation.
+    fn default() -> Self {
+        Self::new(Vec3::Z, Vec3::ZERO)
+    }
 }
 
+/// Upright in bevy's Y-up convention. Use [`LookTransform::to_transform`] for other coordinate systems.
 impl From<LookTransform> for Transform {
     fn from(t: LookTransform) -> Self {
-        eye_look_at_target_transform(t.eye, t.target)
+        t.to_transform(&CoordinateSystem::Y_UP)
     }
 }
 
 impl LookTransform {
     pub fn new(eye: Vec3, target: Vec3) -> Self {
-        Self { eye, target, scale: 0.0, }
+        Self {
+            eye,
+            target,
+            scale: 0.0,
+            roll: 0.0,
+        }
     }
 
     pub fn radius(&self) -> f32 {
@@ -46,31 +118,364 @@ impl LookTransform {
     pub fn look_direction(&self) -> Option<Vec3> {
         (self.target - self.eye).try_normalize()
     }
+
+    /// The scene `Transform` of a camera at the eye looking at the target, upright in `coordinates`.
+    pub fn to_transform(&self, coordinates: &CoordinateSystem) -> Transform {
+        let mut transform = eye_look_at_target_transform(self.eye, self.target, coordinates.up());
+        if self.roll != 0.0 {
+            // The camera looks along its local -Z axis.
+            transform.rotate_local_z(-self.roll);
+        }
+
+        transform
+    }
+
+    /// Moves the eye and the target by `offset`.
+    pub fn offset_by(self, offset: Vec3) -> Self {
+        Self {
+            eye: self.eye + offset,
+            target: self.target + offset,
+            ..self
+        }
+    }
+
+    /// Transforms the eye and the target by `matrix`, e.g. from one parent's space to another's.
+    pub fn transformed(self, matrix: &Mat4) -> Self {
+        Self {
+            eye: matrix.transform_point3(self.eye),
+            target: matrix.transform_point3(self.target),
+            ..self
+        }
+    }
+
+    /// Rotates the eye and the target around `pivot`.
+    pub fn rotated_around(self, pivot: Vec3, rotation: Quat) -> Self {
+        Self {
+            eye: pivot + rotation * (self.eye - pivot),
+            target: pivot + rotation * (self.target - pivot),
+            ..self
+        }
+    }
+
+    /// Swings the eye around the target, keeping the radius.
+    pub fn rotated_around_target(self, rotation: Quat) -> Self {
+        self.rotated_around(self.target, rotation)
+    }
+
+    /// Turns the view around the eye, keeping the radius.
+    pub fn rotated_around_eye(self, rotation: Quat) -> Self {
+        self.rotated_around(self.eye, rotation)
+    }
+
+    /// Moves the eye toward or away from the target so they are `radius` apart. Unchanged if the eye is at the target.
+    pub fn with_radius(self, radius: f32) -> Self {
+        match self.look_direction() {
+            Some(direction) => Self {
+                eye: self.target - radius * direction,
+                ..self
+            },
+            None => self,
+        }
+    }
+
+    /// Moves the eye and the target `distance` along the look direction, e.g. to dolly forward.
+    pub fn translated_along_look(self, distance: f32) -> Self {
+        match self.look_direction() {
+            Some(direction) => self.offset_by(distance * direction),
+            None => self,
+        }
+    }
+
+    /// Returns a `LookTransformEase` that moves the camera from this view to `eye` and `target` (keeping the scale) over
+    /// `duration` seconds, to be inserted on the camera entity.
+    ///
+    /// ```ignore
+    /// commands
+    ///     .entity(camera)
+    ///     .insert(transform.ease_to(object - 5.0 * direction, object, 0.5, Easing::EaseInOut));
+    /// ```
+    pub fn ease_to(
+        &self,
+        eye: Vec3,
+        target: Vec3,
+        duration: f32,
+        easing: Easing,
+    ) -> LookTransformEase {
+        LookTransformEase {
+            from: *self,
+            to: LookTransform {
+                eye,
+                target,
+                ..*self
+            },
+            duration,
+            easing,
+            elapsed: 0.0,
+            handle: CameraMoveHandle::default(),
+        }
+    }
+}
+
+/// Animates the `LookTransform` on the same entity over a fixed duration, e.g. to focus an object in exactly half a
+/// second, where the exponential lag of the `Smoother` would never quite arrive. The `Smoother` is bypassed while it
+/// plays, and the component removes itself at the end.
+///
+/// Like `FlyTo`, the ease writes the `LookTransform` every frame, so disable the camera's controller while it plays.
+#[derive(Clone, Component, Debug)]
+pub struct LookTransformEase {
+    /// Where the ease starts. Replaced by the smoothed view when it starts, so the camera doesn't jump if the smoother
+    /// hasn't caught up yet.
+    pub from: LookTransform,
+    pub to: LookTransform,
+    pub duration: f32,
+    pub easing: Easing,
+    elapsed: f32,
+    handle: CameraMoveHandle,
+}
+
+impl LookTransformEase {
+    /// The view at `elapsed` seconds into the ease.
+    pub fn transform_at(&self, elapsed: f32) -> LookTransform {
+        let t = if self.duration > 0.0 {
+            (elapsed / self.duration).clamp(0.0, 1.0)
+        } else {
+            1.0
+        };
+        let t = self.easing.apply(t);
+
+        LookTransform {
+            eye: self.from.eye.lerp(self.to.eye, t),
+            target: self.from.target.lerp(self.to.target, t),
+            scale: self.from.scale + (self.to.scale - self.from.scale) * t,
+            roll: self.from.roll + (self.to.roll - self.from.roll) * t,
+        }
+    }
+
+    /// Resolves when the ease is over.
+    pub fn handle(&self) -> CameraMoveHandle {
+        self.handle.clone()
+    }
+}
+
+fn look_transform_ease_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut cameras: Query<(
+        Entity,
+        &mut LookTransformEase,
+        &mut LookTransform,
+        Option<&mut Smoother>,
+    )>,
+) {
+    for (entity, mut ease, mut transform, smoother) in cameras.iter_mut() {
+        if ease.elapsed == 0.0 {
+            if let Some(smoothed) = smoother.as_ref().and_then(|s| s.smoothed_transform()) {
+                ease.from = smoothed;
+            }
+        }
+        ease.elapsed += time.delta_seconds();
+        *transform = ease.transform_at(ease.elapsed);
+        // Starting the smoother from the eased view makes its output the eased view.
+        if let Some(mut smoother) = smoother {
+            smoother.prefill(*transform);
+        }
+
+        if ease.elapsed >= ease.duration {
+            ease.handle.complete();
+            commands.entity(entity).remove::<LookTransformEase>();
+        }
+    }
+}
+
+impl Add<Vec3> for LookTransform {
+    type Output = Self;
+
+    fn add(self, offset: Vec3) -> Self {
+        self.offset_by(offset)
+    }
 }
 
-fn eye_look_at_target_transform(eye: Vec3, target: Vec3) -> Transform {
+impl AddAssign<Vec3> for LookTransform {
+    fn add_assign(&mut self, offset: Vec3) {
+        *self = self.offset_by(offset);
+    }
+}
+
+impl Sub<Vec3> for LookTransform {
+    type Output = Self;
+
+    fn sub(self, offset: Vec3) -> Self {
+        self.offset_by(-offset)
+    }
+}
+
+impl SubAssign<Vec3> for LookTransform {
+    fn sub_assign(&mut self, offset: Vec3) {
+        *self = self.offset_by(-offset);
+    }
+}
+
+fn eye_look_at_target_transform(eye: Vec3, target: Vec3, up: Vec3) -> Transform {
     // If eye and target are very close, we avoid imprecision issues by keeping the look vector a unit vector.
     let look_vector = (target - eye).normalize();
     let look_at = eye + look_vector;
 
-    Transform::from_translation(eye).looking_at(look_at, Vec3::Y)
+    Transform::from_translation(eye).looking_at(look_at, up)
+}
+
+/// How the `Smoother` moves the eye relative to the target.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Reflect)]
+#[cfg_attr(feature = "serialize", derive(Deserialize, Serialize))]
+pub enum EyeInterpolation {
+    /// The eye moves in a straight line, which cuts through the orbit sphere when swinging around the target.
+    #[default]
+    Linear,
+    /// The direction from the target to the eye rotates and the radius is interpolated separately, so smoothed orbits stay
+    /// on the sphere around the target.
+    Spherical,
+}
+
+/// Where the `Smoother` interpolates the target.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Reflect)]
+#[cfg_attr(feature = "serialize", derive(Deserialize, Serialize))]
+pub enum TargetSmoothingSpace {
+    /// The target moves in a straight line in the world.
+    #[default]
+    World,
+    /// The target is projected onto the screen of the smoothed eye, interpolated there and unprojected at the interpolated
+    /// depth. This keeps the on-screen framing steadier during fast rotations.
+    Screen,
+}
+
+/// Where a `Smoother` gets the time it advances by on each update.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Reflect)]
+#[cfg_attr(feature = "serialize", derive(Deserialize, Serialize))]
+pub enum SmoothingTime {
+    /// The frame time from bevy's `Time` resource.
+    #[default]
+    Frame,
+    /// This many seconds per update regardless of the frame time, e.g. to match a fixed simulation step.
+    Fixed(f32),
+    /// Only the time given to `Smoother::step`, for embedders that drive the app themselves (offline renders, simulation
+    /// steps) and need smoothing to advance deterministically.
+    Manual,
 }
 
 /// Preforms exponential smoothing on a `LookTransform`. Set the `lag_weight` between `0.0` and `1.0`, where higher is smoother.
-#[derive(Component)]
+///
+/// The `lag_weight` is the fraction of the remaining distance kept per frame at `Smoother::REFERENCE_FRAME_RATE`, and is
+/// scaled by the frame time, so smoothing feels the same at any frame rate. Alternatively, give the time it takes to halve
+/// the remaining distance with `Smoother::from_half_life`.
+///
+/// The `scale` channel uses the same `lag_weight` unless it is given its own with `set_scale_lag_weight`.
+///
+/// With the `serialize` feature, only the settings are (de)serialized. A deserialized smoother starts without history,
+/// like after `reset`.
+#[derive(Component, Reflect)]
+#[cfg_attr(feature = "serialize", derive(Deserialize, Serialize))]
+#[reflect(Component)]
 pub struct Smoother {
     lag_weight: f32,
+    scale_lag_weight: Option<f32>,
+    #[cfg_attr(feature = "serialize", serde(skip))]
+    #[reflect(ignore)]
     lerp_tfm: Option<LookTransform>,
     enabled: bool,
+    smooth_rotation: bool,
+    smooth_scale: bool,
+    #[cfg_attr(feature = "serialize", serde(skip))]
+    #[reflect(ignore)]
+    frozen: bool,
+    eye_interpolation: EyeInterpolation,
+    target_smoothing_space: TargetSmoothingSpace,
+    #[cfg_attr(feature = "serialize", serde(skip))]
+    #[reflect(ignore)]
+    warm_up: Option<WarmUp>,
+    time_source: SmoothingTime,
+    #[cfg_attr(feature = "serialize", serde(skip))]
+    #[reflect(ignore)]
+    stepped_time: f32,
+    settle_epsilon: f32,
+    #[cfg_attr(feature = "serialize", serde(skip))]
+    #[reflect(ignore)]
+    moving: bool,
+}
+
+/// A timed ease from the spawned `Transform` to the `LookTransform`, see `Smoother::with_warm_up`.
+struct WarmUp {
+    duration: f32,
+    elapsed: f32,
+    from: Option<LookTransform>,
+}
+
+impl Default for Smoother {
+    /// No smoothing. The controllers set their `smoothing_weight` when enabled.
+    fn default() -> Self {
+        Self::new(0.0)
+    }
 }
 
 impl Smoother {
+    /// The frame rate (in Hz) at which the `lag_weight` is applied once per frame.
+    pub const REFERENCE_FRAME_RATE: f32 = 60.0;
+
     pub fn new(lag_weight: f32) -> Self {
         Self {
             lag_weight,
+            scale_lag_weight: None,
             lerp_tfm: None,
             enabled: true,
+            smooth_rotation: true,
+            smooth_scale: true,
+            frozen: false,
+            eye_interpolation: EyeInterpolation::Linear,
+            target_smoothing_space: TargetSmoothingSpace::World,
+            warm_up: None,
+            time_source: SmoothingTime::Frame,
+            stepped_time: 0.0,
+            settle_epsilon: 1e-3,
+            moving: false,
+        }
+    }
+
+    /// Smooths so that the remaining distance halves every `half_life` seconds.
+    pub fn from_half_life(half_life: f32) -> Self {
+        Self::new(Self::lag_weight_from_half_life(half_life))
+    }
+
+    /// The `lag_weight` that halves the remaining distance every `half_life` seconds.
+    pub fn lag_weight_from_half_life(half_life: f32) -> f32 {
+        if half_life <= 0.0 {
+            return 0.0;
         }
+
+        0.5f32.powf(1.0 / (half_life * Self::REFERENCE_FRAME_RATE))
+    }
+
+    /// The time (in seconds) it takes to halve the remaining distance.
+    pub fn half_life(&self) -> f32 {
+        if self.lag_weight <= 0.0 {
+            return 0.0;
+        }
+
+        0.5f32.ln() / (self.lag_weight.ln() * Self::REFERENCE_FRAME_RATE)
+    }
+
+    /// On the first frame, starts from the pose of the entity's `Transform` and eases into the `LookTransform` over
+    /// `duration` seconds before regular smoothing takes over, instead of jumping when the two differ.
+    pub fn with_warm_up(mut self, duration: f32) -> Self {
+        self.warm_up = Some(WarmUp {
+            duration,
+            elapsed: 0.0,
+            from: None,
+        });
+        self
+    }
+
+    /// Makes smoothing continue from `tfm`, e.g. from a startup system that knows where the camera was in a previous
+    /// session.
+    pub fn prefill(&mut self, tfm: LookTransform) {
+        self.lerp_tfm = Some(tfm);
     }
 
     pub(crate) fn set_enabled(&mut self, enabled: bool) {
@@ -82,21 +487,163 @@ impl Smoother {
         }
     }
 
+    /// When disabled, only the eye position is smoothed and the rotation follows the `LookTransform` immediately.
+    pub fn set_smooth_rotation(&mut self, smooth_rotation: bool) {
+        self.smooth_rotation = smooth_rotation;
+    }
+
+    /// When disabled, the `scale` channel follows the `LookTransform` immediately.
+    pub fn set_smooth_scale(&mut self, smooth_scale: bool) {
+        self.smooth_scale = smooth_scale;
+    }
+
+    pub fn set_eye_interpolation(&mut self, eye_interpolation: EyeInterpolation) {
+        self.eye_interpolation = eye_interpolation;
+    }
+
+    pub fn set_target_smoothing_space(&mut self, target_smoothing_space: TargetSmoothingSpace) {
+        self.target_smoothing_space = target_smoothing_space;
+    }
+
     pub fn set_lag_weight(&mut self, lag_weight: f32) {
         self.lag_weight = lag_weight;
     }
 
-    pub fn smooth_transform(&mut self, new_tfm: &LookTransform) -> LookTransform {
+    /// Sets a lag weight for the `scale` channel that is independent of the eye/target `lag_weight`. `None` makes it follow
+    /// `lag_weight` again.
+    pub fn set_scale_lag_weight(&mut self, scale_lag_weight: Option<f32>) {
+        self.scale_lag_weight = scale_lag_weight;
+    }
+
+    /// Keeps outputting the last smoothed transform exactly, while the `LookTransform` may keep changing. Useful for pause
+    /// menus that blur a still frame while the world keeps simulating.
+    pub fn freeze(&mut self) {
+        self.frozen = true;
+    }
+
+    /// Resumes smoothing after `freeze`. With `catch_up`, the output blends from the frozen transform to the current one;
+    /// otherwise it jumps.
+    pub fn unfreeze(&mut self, catch_up: bool) {
+        self.frozen = false;
+        if !catch_up {
+            self.reset();
+        }
+    }
+
+    pub fn set_time_source(&mut self, time_source: SmoothingTime) {
+        self.time_source = time_source;
+    }
+
+    pub fn time_source(&self) -> SmoothingTime {
+        self.time_source
+    }
+
+    /// Advances a `SmoothingTime::Manual` smoother by `dt` seconds on the next update. Steps add up until then.
+    pub fn step(&mut self, dt: f32) {
+        self.stepped_time += dt;
+    }
+
+    /// The seconds to advance by on this update, given the frame time.
+    fn take_delta_seconds(&mut self, frame_dt: f32) -> f32 {
+        let stepped_time = std::mem::take(&mut self.stepped_time);
+        match self.time_source {
+            SmoothingTime::Frame => frame_dt,
+            SmoothingTime::Fixed(dt) => dt,
+            SmoothingTime::Manual => stepped_time,
+        }
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    /// The distance (in world units, and projection scale for orthographic cameras) within which the smoothed transform
+    /// counts as having settled on the `LookTransform`, see `CameraMotionEvent`.
+    pub fn set_settle_epsilon(&mut self, settle_epsilon: f32) {
+        self.settle_epsilon = settle_epsilon;
+    }
+
+    pub fn settle_epsilon(&self) -> f32 {
+        self.settle_epsilon
+    }
+
+    /// Whether the smoothed transform is still catching up with the `LookTransform`.
+    pub fn is_moving(&self) -> bool {
+        self.moving
+    }
+
+    /// Compares the smoothed transform `tr` with the `LookTransform` it follows. Returns the event if it started moving
+    /// or settled.
+    fn update_motion(
+        &mut self,
+        camera: Entity,
+        tr: &LookTransform,
+        look_transform: &LookTransform,
+    ) -> Option<CameraMotionEvent> {
+        let moving = tr.eye.distance(look_transform.eye) > self.settle_epsilon
+            || tr.target.distance(look_transform.target) > self.settle_epsilon
+            || (tr.scale - look_transform.scale).abs() > self.settle_epsilon
+            || (tr.roll - look_transform.roll).abs() > self.settle_epsilon;
+        if moving == self.moving {
+            return None;
+        }
+        self.moving = moving;
+        if moving {
+            Some(CameraMotionEvent::Started { camera })
+        } else {
+            Some(CameraMotionEvent::Settled { camera })
+        }
+    }
+
+    pub fn is_frozen(&self) -> bool {
+        self.frozen
+    }
+
+    /// Advances smoothing toward `new_tfm` by `dt` seconds.
+    pub fn smooth_transform(&mut self, new_tfm: &LookTransform, dt: f32) -> LookTransform {
         debug_assert!(0.0 <= self.lag_weight);
         debug_assert!(self.lag_weight < 1.0);
 
         let old_lerp_tfm = self.lerp_tfm.unwrap_or(*new_tfm);
+        if self.frozen {
+            self.lerp_tfm = Some(old_lerp_tfm);
+            return old_lerp_tfm;
+        }
 
-        let lead_weight = 1.0 - self.lag_weight;
+        let scale_lag_weight = if self.smooth_scale {
+            self.scale_lag_weight.unwrap_or(self.lag_weight)
+        } else {
+            0.0
+        };
+        debug_assert!((0.0..1.0).contains(&scale_lag_weight));
+        let frames = dt * Self::REFERENCE_FRAME_RATE;
+        let lag_weight = self.lag_weight.powf(frames);
+        let scale_lag_weight = scale_lag_weight.powf(frames);
+        let lead_weight = 1.0 - lag_weight;
+        let target = old_lerp_tfm.target * lag_weight + new_tfm.target * lead_weight;
+        let eye = match self.eye_interpolation {
+            EyeInterpolation::Linear => old_lerp_tfm.eye * lag_weight + new_tfm.eye * lead_weight,
+            EyeInterpolation::Spherical => {
+                target
+                    + slerp_offset(
+                        old_lerp_tfm.eye - old_lerp_tfm.target,
+                        new_tfm.eye - new_tfm.target,
+                        lead_weight,
+                    )
+            }
+        };
+        let target = match self.target_smoothing_space {
+            TargetSmoothingSpace::World => target,
+            TargetSmoothingSpace::Screen => {
+                screen_space_lerp(eye, old_lerp_tfm.target, new_tfm.target, lead_weight)
+                    .unwrap_or(target)
+            }
+        };
         let lerp_tfm = LookTransform {
-            eye: old_lerp_tfm.eye * self.lag_weight + new_tfm.eye * lead_weight,
-            target: old_lerp_tfm.target * self.lag_weight + new_tfm.target * lead_weight,
-            scale: old_lerp_tfm.scale * self.lag_weight + new_tfm.scale * lead_weight,
+            eye,
+            target,
+            scale: old_lerp_tfm.scale * scale_lag_weight + new_tfm.scale * (1.0 - scale_lag_weight),
+            roll: old_lerp_tfm.roll * lag_weight + new_tfm.roll * lead_weight,
         };
 
         self.lerp_tfm = Some(lerp_tfm);
@@ -104,24 +651,367 @@ impl Smoother {
         lerp_tfm
     }
 
+    /// Advances the warm-up, if any, and returns its output.
+    fn warm_up_transform(
+        &mut self,
+        new_tfm: &LookTransform,
+        scene_transform: &Transform,
+        dt: f32,
+    ) -> Option<LookTransform> {
+        let warm_up = self.warm_up.as_mut()?;
+        let from = *warm_up.from.get_or_insert_with(|| LookTransform {
+            eye: scene_transform.translation,
+            target: scene_transform.translation + new_tfm.radius() * scene_transform.forward(),
+            scale: new_tfm.scale,
+            roll: new_tfm.roll,
+        });
+        warm_up.elapsed += dt;
+        let t = if warm_up.duration > 0.0 {
+            (warm_up.elapsed / warm_up.duration).min(1.0)
+        } else {
+            1.0
+        };
+        if t >= 1.0 {
+            self.warm_up = None;
+        }
+
+        let t = Easing::EaseInOut.apply(t);
+        let tfm = LookTransform {
+            eye: from.eye.lerp(new_tfm.eye, t),
+            target: from.target.lerp(new_tfm.target, t),
+            scale: from.scale + (new_tfm.scale - from.scale) * t,
+            roll: from.roll + (new_tfm.roll - from.roll) * t,
+        };
+        self.lerp_tfm = Some(tfm);
+
+        Some(tfm)
+    }
+
+    /// The most recent output of `smooth_transform`, if any.
+    pub fn smoothed_transform(&self) -> Option<LookTransform> {
+        self.lerp_tfm
+    }
+
     pub fn reset(&mut self) {
         self.lerp_tfm = None;
     }
+
+    /// Moves the smoothing state by `matrix`, so smoothing continues seamlessly after the `LookTransform` is moved to
+    /// another space.
+    pub(crate) fn transform_state(&mut self, matrix: &Mat4) {
+        if let Some(tfm) = &mut self.lerp_tfm {
+            *tfm = tfm.transformed(matrix);
+        }
+        if let Some(from) = self.warm_up.as_mut().and_then(|w| w.from.as_mut()) {
+            *from = from.transformed(matrix);
+        }
+    }
+}
+
+/// Interpolates from `from` to `to` as seen on the screen of a camera at `eye` looking at `to`. Returns `None` if either
+/// point is not in front of the eye.
+fn screen_space_lerp(eye: Vec3, from: Vec3, to: Vec3, t: f32) -> Option<Vec3> {
+    // Any roll about the view axis gives the same interpolation, so the up axis doesn't matter.
+    let rotation = Quat::from_rotation_arc(Vec3::NEG_Z, (to - eye).try_normalize()?);
+    let project = |point: Vec3| {
+        let view = rotation.inverse() * (point - eye);
+        let depth = -view.z;
+        (depth > 1e-4).then(|| (view.truncate() / depth, depth))
+    };
+    let (from_screen, from_depth) = project(from)?;
+    let (to_screen, to_depth) = project(to)?;
+
+    let screen = from_screen.lerp(to_screen, t);
+    let depth = from_depth + (to_depth - from_depth) * t;
+
+    Some(eye + rotation * (depth * screen.extend(-1.0)))
+}
+
+/// Interpolates between two offsets by rotating the direction and interpolating the length.
+fn slerp_offset(from: Vec3, to: Vec3, t: f32) -> Vec3 {
+    let (from_length, to_length) = (from.length(), to.length());
+    let length = from_length + (to_length - from_length) * t;
+    match (from.try_normalize(), to.try_normalize()) {
+        (Some(from_dir), Some(to_dir)) => {
+            let rotation = Quat::IDENTITY.slerp(Quat::from_rotation_arc(from_dir, to_dir), t);
+            length * (rotation * from_dir)
+        }
+        _ => from.lerp(to, t),
+    }
 }
 
+#[allow(clippy::type_complexity)]
 fn look_transform_system(
-    mut cameras: Query<(&LookTransform, &mut Transform, &mut Projection, Option<&mut Smoother>)>,
+    time: Res<Time>,
+    coordinates: Res<CoordinateSystem>,
+    mut motion_events: EventWriter<CameraMotionEvent>,
+    mut cameras: Query<(
+        Entity,
+        &mut LookTransform,
+        &mut Transform,
+        Option<&mut Projection>,
+        Option<&mut OrthographicProjection>,
+        Option<&mut Smoother>,
+        Option<&CameraBounds>,
+    )>,
 ) {
-    for (look_transform, mut scene_transform, mut projection, smoother) in cameras.iter_mut() {
+    for (
+        camera,
+        mut look_transform,
+        mut scene_transform,
+        projection,
+        orthographic,
+        smoother,
+        bounds,
+    ) in cameras.iter_mut()
+    {
+        if let Some(bounds) = bounds {
+            let bounded = bounds.apply(*look_transform);
+            // Only write when clamped, so the transform isn't marked as changed every frame.
+            if bounded != *look_transform {
+                *look_transform = bounded;
+            }
+        }
+        let look_transform = &*look_transform;
+
         match smoother {
             Some(mut s) if s.enabled => {
-                let tr = s.smooth_transform(look_transform);
-                if let Projection::Orthographic(orth) = projection.as_mut() {
-                    orth.scale =  tr.scale;
+                let dt = s.take_delta_seconds(time.delta_seconds());
+                let tr = match s.warm_up_transform(look_transform, &scene_transform, dt) {
+                    Some(tr) => tr,
+                    None => s.smooth_transform(look_transform, dt),
+                };
+                if let Some(event) = s.update_motion(camera, &tr, look_transform) {
+                    motion_events.send(event);
+                }
+                if let Some(mut projection) = projection {
+                    if let Projection::Orthographic(orth) = projection.as_mut() {
+                        orth.scale = tr.scale;
+                    }
+                }
+                // 2D cameras carry their projection as its own component.
+                if let Some(mut orthographic) = orthographic {
+                    orthographic.scale = tr.scale;
+                }
+                *scene_transform = tr.to_transform(&coordinates);
+                if !s.smooth_rotation && !s.frozen {
+                    scene_transform.rotation = look_transform.to_transform(&coordinates).rotation;
                 }
-                *scene_transform = tr.into()
             }
             _ => (),
         };
     }
 }
+
+// ████████╗███████╗███████╗████████╗
+// ╚══██╔══╝██╔════╝██╔════╝╚══██╔══╝
+//    ██║   █████╗  ███████╗   ██║
+//    ██║   ██╔══╝  ╚════██║   ██║
+//    ██║   ███████╗███████║   ██║
+//    ╚═╝   ╚══════╝╚══════╝   ╚═╝
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::assert_relative_eq;
+
+    #[cfg(feature = "serialize")]
+    #[test]
+    fn test_round_trip_ron_and_json() {
+        let mut transform = LookTransform::new(Vec3::new(1.0, 2.0, 3.0), Vec3::ZERO);
+        transform.roll = 0.5;
+
+        let ron = ron::to_string(&transform).unwrap();
+        assert_eq!(ron::from_str::<LookTransform>(&ron).unwrap(), transform);
+        let json = serde_json::to_string(&transform).unwrap();
+        assert_eq!(
+            serde_json::from_str::<LookTransform>(&json).unwrap(),
+            transform
+        );
+
+        // Saved before the roll was added.
+        let old: LookTransform =
+            serde_json::from_str(r#"{"eye":[1,2,3],"target":[0,0,0],"scale":0}"#).unwrap();
+        assert_eq!(old.roll, 0.0);
+    }
+
+    #[test]
+    fn test_ease_arrives_at_end_of_duration() {
+        let from = LookTransform::new(Vec3::new(0.0, 0.0, 5.0), Vec3::ZERO);
+        let ease = from.ease_to(Vec3::new(10.0, 0.0, 5.0), Vec3::X, 2.0, Easing::EaseInOut);
+        assert_eq!(ease.transform_at(0.0), from);
+        assert_eq!(ease.transform_at(2.0), ease.to);
+        assert_eq!(ease.transform_at(3.0), ease.to);
+        let halfway = ease.transform_at(1.0);
+        assert_relative_eq!(halfway.eye.x, 5.0, epsilon = 1e-4);
+        assert_eq!(halfway.scale, from.scale);
+    }
+
+    #[test]
+    fn test_roll_banks_right_and_is_smoothed() {
+        let level = LookTransform::new(Vec3::new(0.0, 0.0, 5.0), Vec3::ZERO);
+        let banked = LookTransform {
+            roll: std::f32::consts::FRAC_PI_2,
+            ..level
+        };
+        let transform = Transform::from(banked);
+        assert!(transform.up().abs_diff_eq(Vec3::X, 1e-5));
+        assert!(transform.forward().abs_diff_eq(Vec3::NEG_Z, 1e-5));
+
+        let mut smoother = Smoother::new(0.5);
+        smoother.prefill(level);
+        let dt = 1.0 / Smoother::REFERENCE_FRAME_RATE;
+        let halfway = smoother.smooth_transform(&banked, dt);
+        assert_relative_eq!(halfway.roll, 0.5 * banked.roll, epsilon = 1e-5);
+    }
+
+    #[test]
+    fn test_rotated_around_target_keeps_radius() {
+        let transform = LookTransform::new(Vec3::new(0.0, 0.0, 5.0), Vec3::X)
+            .rotated_around_target(Quat::from_rotation_y(1.0));
+        assert_relative_eq!(
+            transform.radius(),
+            Vec3::new(-1.0, 0.0, 5.0).length(),
+            epsilon = 1e-5
+        );
+        assert!(transform.target.abs_diff_eq(Vec3::X, 1e-6));
+    }
+
+    #[test]
+    fn test_offset_operators_move_eye_and_target() {
+        let mut transform = LookTransform::new(Vec3::Z, Vec3::ZERO) + Vec3::Y;
+        assert!(transform.eye.abs_diff_eq(Vec3::new(0.0, 1.0, 1.0), 1e-6));
+        transform -= Vec3::Y;
+        assert!(transform.target.abs_diff_eq(Vec3::ZERO, 1e-6));
+    }
+
+    #[test]
+    fn test_with_radius_and_translated_along_look() {
+        let transform = LookTransform::new(Vec3::Z, Vec3::ZERO).with_radius(3.0);
+        assert!(transform.eye.abs_diff_eq(3.0 * Vec3::Z, 1e-6));
+        let transform = transform.translated_along_look(1.0);
+        assert!(transform.eye.abs_diff_eq(2.0 * Vec3::Z, 1e-6));
+        assert!(transform.target.abs_diff_eq(-Vec3::Z, 1e-6));
+    }
+
+    fn smooth_for_one_second(frame_rate: u32) -> f32 {
+        let mut smoother = Smoother::new(0.9);
+        smoother.prefill(LookTransform::new(Vec3::ZERO, Vec3::X));
+        let goal = LookTransform::new(Vec3::Y * 10.0, Vec3::X);
+        let mut eye = Vec3::ZERO;
+        for _ in 0..frame_rate {
+            eye = smoother
+                .smooth_transform(&goal, 1.0 / frame_rate as f32)
+                .eye;
+        }
+
+        eye.y
+    }
+
+    #[test]
+    fn test_smoothing_is_frame_rate_independent() {
+        let at_60 = smooth_for_one_second(60);
+        assert_relative_eq!(at_60, smooth_for_one_second(30), epsilon = 1e-3);
+        assert_relative_eq!(at_60, smooth_for_one_second(144), epsilon = 1e-3);
+        assert_relative_eq!(at_60, 10.0 * (1.0 - 0.9f32.powi(60)), epsilon = 1e-3);
+    }
+
+    #[test]
+    fn test_spherical_interpolation_keeps_radius() {
+        let mut smoother = Smoother::new(0.5);
+        smoother.set_eye_interpolation(EyeInterpolation::Spherical);
+        smoother.prefill(LookTransform::new(Vec3::X * 2.0, Vec3::ZERO));
+        let goal = LookTransform::new(-Vec3::Z * 2.0, Vec3::ZERO);
+        let dt = 1.0 / Smoother::REFERENCE_FRAME_RATE;
+        let halfway = smoother.smooth_transform(&goal, dt);
+        assert_relative_eq!(halfway.radius(), 2.0, epsilon = 1e-5);
+        assert!(halfway
+            .eye
+            .abs_diff_eq(Vec3::new(1.0, 0.0, -1.0).normalize() * 2.0, 1e-4));
+    }
+
+    #[test]
+    fn test_screen_space_target_smoothing() {
+        let eye = Vec3::ZERO;
+        let (from, to) = (Vec3::new(1.0, 0.0, -1.0), Vec3::new(0.0, 0.0, -2.0));
+        // Halfway on screen and in depth.
+        let target = screen_space_lerp(eye, from, to, 0.5).unwrap();
+        assert!(target.abs_diff_eq(Vec3::new(0.75, 0.0, -1.5), 1e-5));
+        // Behind the eye.
+        assert!(screen_space_lerp(eye, Vec3::Z, to, 0.5).is_none());
+    }
+
+    #[test]
+    fn test_half_life_round_trip() {
+        let smoother = Smoother::from_half_life(0.25);
+        assert_relative_eq!(smoother.half_life(), 0.25, epsilon = 1e-5);
+
+        let mut smoother = Smoother::from_half_life(0.5);
+        smoother.prefill(LookTransform::new(Vec3::ZERO, Vec3::X));
+        let goal = LookTransform::new(Vec3::Y, Vec3::X);
+        assert_relative_eq!(
+            smoother.smooth_transform(&goal, 0.5).eye.y,
+            0.5,
+            epsilon = 1e-5
+        );
+    }
+
+    #[test]
+    fn test_time_sources() {
+        let mut smoother = Smoother::new(0.9);
+        assert_eq!(smoother.take_delta_seconds(0.1), 0.1);
+
+        smoother.set_time_source(SmoothingTime::Fixed(0.02));
+        assert_eq!(smoother.take_delta_seconds(0.1), 0.02);
+
+        // Manual steps add up until the next update, and are used up by it.
+        smoother.set_time_source(SmoothingTime::Manual);
+        smoother.step(0.25);
+        smoother.step(0.25);
+        assert_eq!(smoother.take_delta_seconds(0.1), 0.5);
+        assert_eq!(smoother.take_delta_seconds(0.1), 0.0);
+    }
+
+    #[test]
+    fn test_motion_events_on_start_and_settle() {
+        let camera = Entity::from_raw(0);
+        let mut smoother = Smoother::new(0.5);
+        let start = LookTransform::new(Vec3::Z, Vec3::ZERO);
+        let tr = smoother.smooth_transform(&start, 0.0);
+        assert_eq!(smoother.update_motion(camera, &tr, &start), None);
+
+        let goal = start.offset_by(Vec3::X);
+        let dt = 1.0 / Smoother::REFERENCE_FRAME_RATE;
+        let mut events = Vec::new();
+        for _ in 0..60 {
+            let tr = smoother.smooth_transform(&goal, dt);
+            events.extend(smoother.update_motion(camera, &tr, &goal));
+        }
+        assert_eq!(
+            events,
+            vec![
+                CameraMotionEvent::Started { camera },
+                CameraMotionEvent::Settled { camera },
+            ]
+        );
+        assert!(!smoother.is_moving());
+    }
+
+    #[test]
+    fn test_components_are_reflected() {
+        use bevy::reflect::{GetTypeRegistration, Struct};
+
+        let registration = LookTransform::get_type_registration();
+        assert!(registration.data::<ReflectComponent>().is_some());
+        assert!(Smoother::get_type_registration()
+            .data::<ReflectComponent>()
+            .is_some());
+
+        let transform = LookTransform::new(Vec3::X, Vec3::ZERO);
+        assert_eq!(transform.field_len(), 4);
+        let mut smoother = Smoother::new(0.5);
+        smoother.field_mut("lag_weight").unwrap().apply(&0.75f32);
+        assert_eq!(smoother.lag_weight, 0.75);
+    }
+}
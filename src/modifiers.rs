@@ -0,0 +1,152 @@
+use crate::LookTransformSystem;
+
+use bevy::{
+    app::App,
+    ecs::schedule::{IntoSystemDescriptor, SystemDescriptor, SystemLabel},
+};
+
+/// Where a camera modifier added with `add_camera_modifier` runs, relative to the systems of this crate. These anchors
+/// stay put when the crate's own systems are reorganized.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum CameraModifierOrder {
+    /// Before the crate's constraints (like `LookCone`), e.g. to drive the `LookTransform` from gameplay.
+    BeforeConstraints,
+    /// After the constraints, right before the `LookTransform` is smoothed, e.g. for a constraint that must have the last
+    /// word.
+    BeforeSmoothing,
+    /// After the smoothed `LookTransform` is written to the `Transform`, but before effects like `SpringArm` and
+    /// `HandheldNoise` modify it.
+    AfterSmoothing,
+    /// After all effects, to post-process the final `Transform`.
+    AfterEffects,
+}
+
+/// Internal anchors of the modifier orders. The `LookTransformPlugin` adds an empty system for each, so the anchors exist
+/// whichever feature plugins are added.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, SystemLabel)]
+pub(crate) enum CameraModifierAnchor {
+    Constraints,
+    /// Effects that move the eye out of obstacles, which the other effects build on.
+    Collision,
+    Effects,
+}
+
+pub(crate) fn modifier_anchor_system() {}
+
+impl CameraModifierOrder {
+    /// Orders `system` at this position.
+    pub fn apply<Params>(self, system: impl IntoSystemDescriptor<Params>) -> SystemDescriptor {
+        match self {
+            CameraModifierOrder::BeforeConstraints => system
+                .before(CameraModifierAnchor::Constraints)
+                .before(LookTransformSystem),
+            CameraModifierOrder::BeforeSmoothing => system
+                .after(CameraModifierAnchor::Constraints)
+                .before(LookTransformSystem),
+            CameraModifierOrder::AfterSmoothing => system
+                .after(LookTransformSystem)
+                .before(CameraModifierAnchor::Effects),
+            CameraModifierOrder::AfterEffects => system
+                .after(LookTransformSystem)
+                .after(CameraModifierAnchor::Effects),
+        }
+    }
+}
+
+/// Adds user systems that constrain the `LookTransform` or post-process the camera `Transform`.
+///
+/// ```ignore
+/// app.add_camera_modifier(CameraModifierOrder::AfterSmoothing, keep_above_water_system);
+/// ```
+pub trait CameraModifierExt {
+    fn add_camera_modifier<Params>(
+        &mut self,
+        order: CameraModifierOrder,
+        system: impl IntoSystemDescriptor<Params>,
+    ) -> &mut Self;
+}
+
+impl CameraModifierExt for App {
+    fn add_camera_modifier<Params>(
+        &mut self,
+        order: CameraModifierOrder,
+        system: impl IntoSystemDescriptor<Params>,
+    ) -> &mut Self {
+        self.add_system(order.apply(system))
+    }
+}
+
+// ████████╗███████╗███████╗████████╗
+// ╚══██╔══╝██╔════╝██╔════╝╚══██╔══╝
+//    ██║   █████╗  ███████╗   ██║
+//    ██║   ██╔══╝  ╚════██║   ██║
+//    ██║   ███████╗███████║   ██║
+//    ╚═╝   ╚══════╝╚══════╝   ╚═╝
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::LookTransformPlugin;
+
+    use bevy::{ecs::prelude::*, time::Time};
+
+    #[derive(Default, Resource)]
+    struct Log(Vec<CameraModifierOrder>);
+
+    #[test]
+    fn test_modifiers_run_in_order() {
+        let mut app = App::new();
+        app.init_resource::<Log>()
+            .add_system((|| ()).label(CameraModifierAnchor::Constraints))
+            .add_system((|| ()).label(LookTransformSystem))
+            .add_system((|| ()).label(CameraModifierAnchor::Effects));
+        let orders = [
+            CameraModifierOrder::AfterEffects,
+            CameraModifierOrder::BeforeSmoothing,
+            CameraModifierOrder::AfterSmoothing,
+            CameraModifierOrder::BeforeConstraints,
+        ];
+        for order in orders {
+            app.add_camera_modifier(order, move |mut log: ResMut<Log>| log.0.push(order));
+        }
+        app.update();
+
+        assert_eq!(
+            app.world.resource::<Log>().0,
+            vec![
+                CameraModifierOrder::BeforeConstraints,
+                CameraModifierOrder::BeforeSmoothing,
+                CameraModifierOrder::AfterSmoothing,
+                CameraModifierOrder::AfterEffects,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_anchors_exist_without_feature_plugins() {
+        let mut app = App::new();
+        app.init_resource::<Log>()
+            .init_resource::<Time>()
+            .add_plugin(LookTransformPlugin);
+        let orders = [
+            CameraModifierOrder::AfterEffects,
+            CameraModifierOrder::BeforeSmoothing,
+            CameraModifierOrder::AfterSmoothing,
+            CameraModifierOrder::BeforeConstraints,
+        ];
+        for order in orders {
+            app.add_camera_modifier(order, move |mut log: ResMut<Log>| log.0.push(order));
+        }
+        app.update();
+
+        assert_eq!(
+            app.world.resource::<Log>().0,
+            vec![
+                CameraModifierOrder::BeforeConstraints,
+                CameraModifierOrder::BeforeSmoothing,
+                CameraModifierOrder::AfterSmoothing,
+                CameraModifierOrder::AfterEffects,
+            ]
+        );
+    }
+}
@@ -0,0 +1,142 @@
+use crate::{AspectFov, LookTransformSystem};
+
+use bevy::{
+    app::prelude::*, ecs::prelude::*, math::prelude::*, prelude::Projection, time::Time,
+    transform::components::Transform,
+};
+
+/// Adds motion intensity tracking and the `SpeedFov` effect.
+pub struct MotionIntensityPlugin;
+
+impl Plugin for MotionIntensityPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_system(motion_intensity_system.after(LookTransformSystem))
+            .add_system(speed_fov_system.after(motion_intensity_system));
+    }
+}
+
+/// How fast a camera is moving, from `0.0` at rest to `1.0` at or above `reference_speed`, measured on the smoothed
+/// `Transform`. Apps can drive effects like motion blur, wind sounds or exposure from it.
+#[derive(Clone, Component, Copy, Debug)]
+pub struct MotionIntensity {
+    /// Speed (in world units per second) at which the intensity saturates.
+    pub reference_speed: f32,
+    /// How fast the intensity follows the speed, per second. Lower values react more slowly, but filter out jitter.
+    pub response: f32,
+    intensity: f32,
+    last_eye: Option<Vec3>,
+}
+
+impl MotionIntensity {
+    pub fn new(reference_speed: f32) -> Self {
+        Self {
+            reference_speed,
+            response: 4.0,
+            intensity: 0.0,
+            last_eye: None,
+        }
+    }
+
+    pub fn intensity(&self) -> f32 {
+        self.intensity
+    }
+
+    fn update(&mut self, eye: Vec3, dt: f32) {
+        let last_eye = self.last_eye.replace(eye);
+        if dt <= 0.0 {
+            return;
+        }
+        let speed = last_eye.map_or(0.0, |last| eye.distance(last) / dt);
+        let raw = (speed / self.reference_speed).min(1.0);
+        self.intensity += (raw - self.intensity) * (1.0 - (-self.response * dt).exp());
+    }
+}
+
+/// Widens the FOV of a perspective camera with its `MotionIntensity`, for a racing-game feel of speed. The widening
+/// decays with the intensity when the camera comes to rest.
+///
+/// With an `AspectFov` on the same entity, the base FOV follows it instead of `base_fov`.
+#[derive(Clone, Component, Copy, Debug)]
+pub struct SpeedFov {
+    pub base_fov: f32,
+    /// Added to the base FOV at full intensity, in radians.
+    pub max_widening: f32,
+}
+
+impl Default for SpeedFov {
+    fn default() -> Self {
+        Self {
+            base_fov: std::f32::consts::FRAC_PI_4,
+            max_widening: 0.15,
+        }
+    }
+}
+
+fn motion_intensity_system(
+    time: Res<Time>,
+    mut cameras: Query<(&mut MotionIntensity, &Transform)>,
+) {
+    for (mut motion, transform) in cameras.iter_mut() {
+        motion.update(transform.translation, time.delta_seconds());
+    }
+}
+
+fn speed_fov_system(
+    mut cameras: Query<(
+        &SpeedFov,
+        &MotionIntensity,
+        Option<&AspectFov>,
+        &mut Projection,
+    )>,
+) {
+    for (speed_fov, motion, aspect_fov, mut projection) in cameras.iter_mut() {
+        if let Projection::Perspective(perspective) = projection.as_mut() {
+            let base_fov = aspect_fov.map_or(speed_fov.base_fov, |a| {
+                a.vertical_fov(perspective.aspect_ratio)
+            });
+            perspective.fov = base_fov + motion.intensity() * speed_fov.max_widening;
+        }
+    }
+}
+
+// ████████╗███████╗███████╗████████╗
+// ╚══██╔══╝██╔════╝██╔════╝╚══██╔══╝
+//    ██║   █████╗  ███████╗   ██║
+//    ██║   ██╔══╝  ╚════██║   ██║
+//    ██║   ███████╗███████║   ██║
+//    ╚═╝   ╚══════╝╚══════╝   ╚═╝
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_intensity_follows_speed_and_decays_at_rest() {
+        let mut motion = MotionIntensity::new(10.0);
+        let dt = 1.0 / 60.0;
+        let mut eye = Vec3::ZERO;
+        for _ in 0..300 {
+            eye.x += 5.0 * dt;
+            motion.update(eye, dt);
+        }
+        assert!((motion.intensity() - 0.5).abs() < 1e-3);
+
+        for _ in 0..300 {
+            motion.update(eye, dt);
+        }
+        assert!(motion.intensity() < 1e-3);
+    }
+
+    #[test]
+    fn test_intensity_saturates() {
+        let mut motion = MotionIntensity::new(1.0);
+        let dt = 1.0 / 60.0;
+        let mut eye = Vec3::ZERO;
+        for _ in 0..300 {
+            eye.x += 100.0 * dt;
+            motion.update(eye, dt);
+        }
+        assert!(motion.intensity() <= 1.0);
+        assert!(motion.intensity() > 0.99);
+    }
+}
@@ -0,0 +1,330 @@
+use crate::{CameraModifierAnchor, LookTransformSystem, Smoother};
+
+use bevy::{
+    app::prelude::*, ecs::prelude::*, math::prelude::*, time::Time,
+    transform::components::Transform,
+};
+#[cfg(feature = "serialize")]
+use serde::{Deserialize, Serialize};
+
+/// Applies `HandheldNoise` and `CameraShake` on top of the smoothed `Transform`.
+pub struct CameraShakePlugin;
+
+impl Plugin for CameraShakePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_system(
+            handheld_noise_system
+                .label(CameraModifierAnchor::Effects)
+                .after(LookTransformSystem)
+                .after(CameraModifierAnchor::Collision),
+        )
+        .add_system(
+            camera_shake_system
+                .label(CameraModifierAnchor::Effects)
+                .after(LookTransformSystem)
+                .after(handheld_noise_system),
+        );
+    }
+}
+
+/// Seeded 1D gradient (Perlin) noise with independent channels.
+///
+/// It only uses integer hashing and exactly rounded float arithmetic, so a seed produces the same values on every platform
+/// and in every build. Replays and networked spectators that share the seed (and the sample times) see identical noise.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(Deserialize, Serialize))]
+pub struct Noise {
+    pub seed: u64,
+}
+
+impl Noise {
+    pub fn new(seed: u64) -> Self {
+        Self { seed }
+    }
+
+    /// Samples the noise of `channel` at time `t`, in `[-1, 1]`. Features are about one unit of `t` apart, and the noise is
+    /// zero at whole numbers.
+    pub fn sample(&self, channel: u32, t: f32) -> f32 {
+        let cell = t.floor();
+        let f = t - cell;
+        let i = cell as i64;
+
+        let g0 = self.gradient(channel, i);
+        let g1 = self.gradient(channel, i + 1);
+        let v0 = g0 * f;
+        let v1 = g1 * (f - 1.0);
+        let fade = f * f * f * (f * (f * 6.0 - 15.0) + 10.0);
+
+        // 1D gradient noise peaks at +-0.5.
+        (2.0 * (v0 + fade * (v1 - v0))).clamp(-1.0, 1.0)
+    }
+
+    /// Samples three consecutive channels starting at `channel`.
+    pub fn sample_vec3(&self, channel: u32, t: f32) -> Vec3 {
+        Vec3::new(
+            self.sample(channel, t),
+            self.sample(channel + 1, t),
+            self.sample(channel + 2, t),
+        )
+    }
+
+    /// A gradient in `[-1, 1]` for lattice point `i`.
+    fn gradient(&self, channel: u32, i: i64) -> f32 {
+        let hash = splitmix64(self.seed ^ splitmix64(((channel as u64) << 48) ^ i as u64));
+
+        // The top 24 bits are exactly representable as an f32.
+        (hash >> 40) as f32 / (1u64 << 23) as f32 - 1.0
+    }
+}
+
+fn splitmix64(x: u64) -> u64 {
+    let mut z = x.wrapping_add(0x9e37_79b9_7f4a_7c15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    z ^ (z >> 31)
+}
+
+/// Subtle procedural wobble, like a handheld camera. It is applied on top of the smoothed `Transform`, so it requires an
+/// enabled `Smoother` and never feeds back into the `LookTransform` or the controllers.
+#[derive(Clone, Component, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(Deserialize, Serialize))]
+pub struct HandheldNoise {
+    pub noise: Noise,
+    /// Noise features per second.
+    pub frequency: f32,
+    /// Maximum (pitch, yaw, roll) offsets in radians.
+    pub rotation_amplitude: Vec3,
+    /// Maximum offsets along the camera's local axes, in world units.
+    pub translation_amplitude: Vec3,
+    /// Seconds of noise played so far. Sync it together with the seed to reproduce the same jitter elsewhere.
+    #[cfg_attr(feature = "serialize", serde(skip))]
+    pub time: f32,
+}
+
+impl Default for HandheldNoise {
+    fn default() -> Self {
+        Self::new(0)
+    }
+}
+
+impl HandheldNoise {
+    pub fn new(seed: u64) -> Self {
+        Self {
+            noise: Noise::new(seed),
+            frequency: 0.5,
+            rotation_amplitude: Vec3::new(0.01, 0.01, 0.005),
+            translation_amplitude: Vec3::ZERO,
+            time: 0.0,
+        }
+    }
+
+    /// The rotation and local translation offsets at `time`.
+    pub fn offset_at(&self, time: f32) -> (Quat, Vec3) {
+        let t = time * self.frequency;
+        let angles = self.rotation_amplitude * self.noise.sample_vec3(0, t);
+        let rotation = Quat::from_euler(EulerRot::YXZ, angles.y, angles.x, angles.z);
+
+        (
+            rotation,
+            self.translation_amplitude * self.noise.sample_vec3(3, t),
+        )
+    }
+}
+
+pub(crate) fn handheld_noise_system(
+    time: Res<Time>,
+    mut cameras: Query<(&mut HandheldNoise, &mut Transform, &Smoother)>,
+) {
+    for (mut handheld, mut transform, smoother) in cameras.iter_mut() {
+        if !smoother.is_enabled() {
+            continue;
+        }
+
+        handheld.time += time.delta_seconds();
+        let (rotation, translation) = handheld.offset_at(handheld.time);
+        let transform = &mut *transform;
+        transform.translation += transform.rotation * translation;
+        transform.rotation *= rotation;
+    }
+}
+
+/// Trauma-based screen shake, e.g. for explosions and impacts. Gameplay code adds trauma with `add_trauma`, which
+/// decays over time. The shake grows with the square of the trauma, so small hits stay subtle and big ones feel violent.
+///
+/// Like `HandheldNoise`, the shake is applied on top of the smoothed `Transform`, so it requires an enabled `Smoother` and
+/// never feeds back into the `LookTransform` or the controllers.
+#[derive(Clone, Component, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(Deserialize, Serialize))]
+pub struct CameraShake {
+    pub noise: Noise,
+    /// Noise features per second. Much higher than `HandheldNoise`, for a violent shake.
+    pub frequency: f32,
+    /// (pitch, yaw, roll) offsets in radians at full trauma.
+    pub max_rotation: Vec3,
+    /// Offsets along the camera's local axes at full trauma, in world units.
+    pub max_translation: Vec3,
+    /// Trauma lost per second.
+    pub decay: f32,
+    #[cfg_attr(feature = "serialize", serde(skip))]
+    trauma: f32,
+    #[cfg_attr(feature = "serialize", serde(skip))]
+    time: f32,
+}
+
+impl Default for CameraShake {
+    fn default() -> Self {
+        Self::new(0)
+    }
+}
+
+impl CameraShake {
+    pub fn new(seed: u64) -> Self {
+        Self {
+            noise: Noise::new(seed),
+            frequency: 15.0,
+            max_rotation: Vec3::new(0.05, 0.05, 0.1),
+            max_translation: Vec3::ZERO,
+            decay: 1.0,
+            trauma: 0.0,
+            time: 0.0,
+        }
+    }
+
+    /// Adds to the trauma, which is capped at `1.0`.
+    pub fn add_trauma(&mut self, trauma: f32) {
+        self.trauma = (self.trauma + trauma).clamp(0.0, 1.0);
+    }
+
+    /// The current trauma in `[0, 1]`.
+    pub fn trauma(&self) -> f32 {
+        self.trauma
+    }
+
+    /// The rotation and local translation offsets at the current time and trauma.
+    pub fn offset(&self) -> (Quat, Vec3) {
+        let shake = self.trauma * self.trauma;
+        let t = self.time * self.frequency;
+        let angles = shake * self.max_rotation * self.noise.sample_vec3(0, t);
+        let rotation = Quat::from_euler(EulerRot::YXZ, angles.y, angles.x, angles.z);
+
+        (
+            rotation,
+            shake * self.max_translation * self.noise.sample_vec3(3, t),
+        )
+    }
+
+    /// Advances the noise and decays the trauma by `dt` seconds.
+    fn advance(&mut self, dt: f32) {
+        self.time += dt;
+        self.trauma = (self.trauma - self.decay * dt).max(0.0);
+    }
+}
+
+pub(crate) fn camera_shake_system(
+    time: Res<Time>,
+    mut cameras: Query<(&mut CameraShake, &mut Transform, &Smoother)>,
+) {
+    for (mut shake, mut transform, smoother) in cameras.iter_mut() {
+        if shake.trauma == 0.0 {
+            continue;
+        }
+        shake.advance(time.delta_seconds());
+        if !smoother.is_enabled() {
+            continue;
+        }
+
+        let (rotation, translation) = shake.offset();
+        let transform = &mut *transform;
+        transform.translation += transform.rotation * translation;
+        transform.rotation *= rotation;
+    }
+}
+
+// ████████╗███████╗███████╗████████╗
+// ╚══██╔══╝██╔════╝██╔════╝╚══██╔══╝
+//    ██║   █████╗  ███████╗   ██║
+//    ██║   ██╔══╝  ╚════██║   ██║
+//    ██║   ███████╗███████║   ██║
+//    ╚═╝   ╚══════╝╚══════╝   ╚═╝
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_same_seed_gives_identical_noise() {
+        let a = Noise::new(42);
+        let b = Noise::new(42);
+        for i in 0..1000 {
+            let t = i as f32 * 0.037 - 10.0;
+            assert_eq!(a.sample(0, t).to_bits(), b.sample(0, t).to_bits());
+        }
+    }
+
+    #[test]
+    fn test_noise_is_portable() {
+        // Pinned values, so changes to the implementation that would break replays are caught.
+        assert_eq!(splitmix64(0), 0xe220_a839_7b1d_cdaf);
+        assert_eq!(splitmix64(1), 0x910a_2dec_8902_5cc1);
+        assert_eq!(Noise::new(7).sample(0, 0.5), -1202041.0 / 16777216.0);
+    }
+
+    #[test]
+    fn test_seeds_and_channels_differ() {
+        let t = 0.5;
+        assert_ne!(Noise::new(1).sample(0, t), Noise::new(2).sample(0, t));
+        assert_ne!(Noise::new(1).sample(0, t), Noise::new(1).sample(1, t));
+    }
+
+    #[test]
+    fn test_noise_is_bounded_and_continuous() {
+        let noise = Noise::new(3);
+        let mut previous = noise.sample(0, 0.0);
+        assert_eq!(previous, 0.0);
+        for i in 1..10_000 {
+            let value = noise.sample(0, i as f32 * 0.001);
+            assert!((-1.0..=1.0).contains(&value));
+            assert!((value - previous).abs() < 0.01);
+            previous = value;
+        }
+    }
+
+    #[test]
+    fn test_handheld_offsets_are_reproducible() {
+        let a = HandheldNoise::new(9);
+        let b = HandheldNoise::new(9);
+        assert_eq!(a.offset_at(1.25), b.offset_at(1.25));
+        assert_ne!(a.offset_at(1.25), HandheldNoise::new(10).offset_at(1.25));
+    }
+
+    #[test]
+    fn test_trauma_is_capped_and_decays() {
+        let mut shake = CameraShake::default();
+        shake.add_trauma(0.7);
+        shake.add_trauma(0.7);
+        assert_eq!(shake.trauma(), 1.0);
+
+        shake.advance(0.25);
+        assert_eq!(shake.trauma(), 0.75);
+        shake.advance(10.0);
+        assert_eq!(shake.trauma(), 0.0);
+        assert_eq!(shake.offset(), (Quat::IDENTITY, Vec3::ZERO));
+    }
+
+    #[test]
+    fn test_shake_grows_with_square_of_trauma() {
+        let mut weak = CameraShake {
+            max_translation: Vec3::ONE,
+            ..Default::default()
+        };
+        weak.advance(0.01);
+        let mut strong = weak;
+        weak.add_trauma(0.5);
+        strong.add_trauma(1.0);
+
+        let (_, weak_offset) = weak.offset();
+        let (_, strong_offset) = strong.offset();
+        assert!(strong_offset.length() > 0.0);
+        assert!(weak_offset.abs_diff_eq(0.25 * strong_offset, 1e-6));
+    }
+}
@@ -0,0 +1,157 @@
+use crate::{LookTransform, LookTransformSystem, Smoother};
+
+use bevy::{
+    app::prelude::*, ecs::prelude::*, math::prelude::*, prelude::Projection,
+    render::primitives::Sphere, transform::components::Transform,
+};
+
+/// Fits the near and far planes of `OrthographicDepth` cameras after smoothing.
+pub struct OrthographicDepthPlugin;
+
+impl Plugin for OrthographicDepthPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_system(orthographic_depth_system.after(LookTransformSystem));
+    }
+}
+
+/// Keeps the near and far planes of an orthographic camera around the visible geometry, so orbiting close to (or into) it
+/// doesn't clip anything. Orthographic cameras can see behind the eye, so the near plane may become negative.
+///
+/// Without `scene_bounds`, the geometry is assumed to be within the eye-to-target distance of the target.
+///
+/// The planes expand immediately, so nothing is clipped, and contract with `smoothing_weight`, like the `Smoother`'s
+/// `lag_weight`, to avoid popping depth precision and shadows.
+#[derive(Clone, Component, Debug)]
+pub struct OrthographicDepth {
+    pub scene_bounds: Option<Sphere>,
+    /// Extra depth (in world units) kept in front of and beyond the geometry.
+    pub margin: f32,
+    pub smoothing_weight: f32,
+}
+
+impl Default for OrthographicDepth {
+    fn default() -> Self {
+        Self {
+            scene_bounds: None,
+            margin: 1.0,
+            smoothing_weight: 0.9,
+        }
+    }
+}
+
+impl OrthographicDepth {
+    pub fn with_scene_bounds(mut self, center: Vec3, radius: f32) -> Self {
+        self.scene_bounds = Some(Sphere {
+            center: center.into(),
+            radius,
+        });
+        self
+    }
+
+    /// The (near, far) planes that contain the geometry seen from `eye` looking along `forward` at a target `radius` away.
+    pub fn depth_range(&self, eye: Vec3, forward: Vec3, radius: f32) -> (f32, f32) {
+        let (center, extent) = match &self.scene_bounds {
+            Some(sphere) => (Vec3::from(sphere.center), sphere.radius),
+            None => (eye + radius * forward, radius),
+        };
+        let depth = (center - eye).dot(forward);
+
+        (depth - extent - self.margin, depth + extent + self.margin)
+    }
+
+    /// Blends `current` planes toward `desired` ones, expanding immediately and contracting smoothly.
+    fn blend(&self, current: (f32, f32), desired: (f32, f32)) -> (f32, f32) {
+        let weight = self.smoothing_weight;
+        let near = if desired.0 < current.0 {
+            desired.0
+        } else {
+            current.0 * weight + desired.0 * (1.0 - weight)
+        };
+        let far = if desired.1 > current.1 {
+            desired.1
+        } else {
+            current.1 * weight + desired.1 * (1.0 - weight)
+        };
+
+        (near, far)
+    }
+}
+
+pub(crate) fn orthographic_depth_system(
+    mut cameras: Query<(
+        &OrthographicDepth,
+        &LookTransform,
+        Option<&Smoother>,
+        &Transform,
+        &mut Projection,
+    )>,
+) {
+    for (depth, look_transform, smoother, transform, mut projection) in cameras.iter_mut() {
+        let orthographic = match projection.as_ref() {
+            Projection::Orthographic(orthographic) => orthographic,
+            Projection::Perspective(_) => continue,
+        };
+        let radius = smoother
+            .and_then(Smoother::smoothed_transform)
+            .unwrap_or(*look_transform)
+            .radius();
+        let desired = depth.depth_range(transform.translation, transform.forward(), radius);
+        let (near, far) = depth.blend((orthographic.near, orthographic.far), desired);
+        if (near, far) == (orthographic.near, orthographic.far) {
+            continue;
+        }
+
+        if let Projection::Orthographic(orthographic) = projection.as_mut() {
+            orthographic.near = near;
+            orthographic.far = far;
+        }
+    }
+}
+
+// ████████╗███████╗███████╗████████╗
+// ╚══██╔══╝██╔════╝██╔════╝╚══██╔══╝
+//    ██║   █████╗  ███████╗   ██║
+//    ██║   ██╔══╝  ╚════██║   ██║
+//    ██║   ███████╗███████║   ██║
+//    ╚═╝   ╚══════╝╚══════╝   ╚═╝
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn test_depth_range_follows_radius() {
+        let depth = OrthographicDepth {
+            margin: 0.0,
+            ..Default::default()
+        };
+        let (near, far) = depth.depth_range(Vec3::Z * 5.0, -Vec3::Z, 5.0);
+        assert_relative_eq!(near, 0.0);
+        assert_relative_eq!(far, 10.0);
+    }
+
+    #[test]
+    fn test_depth_range_covers_scene_behind_the_eye() {
+        let depth = OrthographicDepth {
+            margin: 0.5,
+            ..Default::default()
+        }
+        .with_scene_bounds(Vec3::ZERO, 4.0);
+        // The eye is inside the scene bounds.
+        let (near, far) = depth.depth_range(Vec3::Z, -Vec3::Z, 1.0);
+        assert_relative_eq!(near, -3.5);
+        assert_relative_eq!(far, 5.5);
+    }
+
+    #[test]
+    fn test_planes_expand_immediately_and_contract_smoothly() {
+        let depth = OrthographicDepth {
+            smoothing_weight: 0.5,
+            ..Default::default()
+        };
+        assert_eq!(depth.blend((0.0, 10.0), (-2.0, 20.0)), (-2.0, 20.0));
+        assert_eq!(depth.blend((-2.0, 20.0), (0.0, 10.0)), (-1.0, 15.0));
+    }
+}
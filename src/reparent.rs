@@ -0,0 +1,148 @@
+use crate::{LookTransform, LookTransformEase, Smoother};
+
+use bevy::{
+    ecs::{prelude::*, system::Command},
+    hierarchy::{AddChild, BuildWorldChildren, Parent},
+    math::prelude::*,
+    transform::components::{GlobalTransform, Transform},
+};
+
+/// Re-parents a camera (e.g. when attaching it to or detaching it from a vehicle) without a jump in its world-space view.
+/// The `LookTransform`, the `Transform`, the state of the `Smoother` and a running `LookTransformEase` are moved from the
+/// space of the old parent to the space of the new one, so smoothing continues where it was.
+///
+/// ```ignore
+/// commands.add(ReparentCamera {
+///     camera,
+///     parent: Some(vehicle),
+/// });
+/// ```
+///
+/// The parents' `GlobalTransform`s are read as they are, so they are from the last transform propagation.
+#[derive(Clone, Copy, Debug)]
+pub struct ReparentCamera {
+    pub camera: Entity,
+    /// `None` detaches the camera, so its `LookTransform` is in world space.
+    pub parent: Option<Entity>,
+}
+
+impl Command for ReparentCamera {
+    fn write(self, world: &mut World) {
+        let parent_matrix = |world: &World, parent: Option<Entity>| {
+            parent
+                .and_then(|parent| world.get::<GlobalTransform>(parent))
+                .map_or(Mat4::IDENTITY, |global| global.compute_matrix())
+        };
+        let old_parent = world.get::<Parent>(self.camera).map(|parent| parent.get());
+        let matrix = reparenting_matrix(
+            parent_matrix(world, old_parent),
+            parent_matrix(world, self.parent),
+        );
+
+        let mut camera = match world.get_entity_mut(self.camera) {
+            Some(camera) => camera,
+            None => return,
+        };
+        if let Some(mut transform) = camera.get_mut::<LookTransform>() {
+            *transform = transform.transformed(&matrix);
+        }
+        if let Some(mut smoother) = camera.get_mut::<Smoother>() {
+            smoother.transform_state(&matrix);
+        }
+        if let Some(mut ease) = camera.get_mut::<LookTransformEase>() {
+            ease.from = ease.from.transformed(&matrix);
+            ease.to = ease.to.transformed(&matrix);
+        }
+        if let Some(mut transform) = camera.get_mut::<Transform>() {
+            *transform = Transform::from_matrix(matrix * transform.compute_matrix());
+        }
+
+        match (self.parent, old_parent) {
+            (Some(parent), _) => AddChild {
+                parent,
+                child: self.camera,
+            }
+            .write(world),
+            (None, Some(old_parent)) => {
+                world.entity_mut(old_parent).remove_children(&[self.camera]);
+            }
+            (None, None) => (),
+        }
+    }
+}
+
+/// The matrix from the space of a parent with `old_parent` as its world matrix to the space of `new_parent`.
+fn reparenting_matrix(old_parent: Mat4, new_parent: Mat4) -> Mat4 {
+    new_parent.inverse() * old_parent
+}
+
+// ████████╗███████╗███████╗████████╗
+// ╚══██╔══╝██╔════╝██╔════╝╚══██╔══╝
+//    ██║   █████╗  ███████╗   ██║
+//    ██║   ██╔══╝  ╚════██║   ██║
+//    ██║   ███████╗███████║   ██║
+//    ╚═╝   ╚══════╝╚══════╝   ╚═╝
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_reparenting_keeps_world_view() {
+        let old_parent = Transform::from_xyz(10.0, 0.0, 0.0);
+        let new_parent =
+            Transform::from_xyz(0.0, 2.0, -3.0).with_rotation(Quat::from_rotation_y(1.0));
+
+        let mut world = World::new();
+        let old_parent = world
+            .spawn((old_parent, GlobalTransform::from(old_parent)))
+            .id();
+        let new_parent = world
+            .spawn((new_parent, GlobalTransform::from(new_parent)))
+            .id();
+        let view = LookTransform::new(Vec3::new(0.0, 1.0, 5.0), Vec3::ZERO);
+        let mut smoother = Smoother::new(0.5);
+        smoother.prefill(view.offset_by(Vec3::X));
+        let camera = world.spawn((view, smoother, Transform::from(view))).id();
+        AddChild {
+            parent: old_parent,
+            child: camera,
+        }
+        .write(&mut world);
+
+        let world_matrix = |world: &World, parent: Entity| {
+            world
+                .get::<GlobalTransform>(parent)
+                .unwrap()
+                .compute_matrix()
+        };
+        let old_world = view.transformed(&world_matrix(&world, old_parent));
+
+        ReparentCamera {
+            camera,
+            parent: Some(new_parent),
+        }
+        .write(&mut world);
+
+        assert_eq!(world.get::<Parent>(camera).unwrap().get(), new_parent);
+        let new_view = *world.get::<LookTransform>(camera).unwrap();
+        let new_world = new_view.transformed(&world_matrix(&world, new_parent));
+        assert!(new_world.eye.abs_diff_eq(old_world.eye, 1e-4));
+        assert!(new_world.target.abs_diff_eq(old_world.target, 1e-4));
+
+        // Detached, the view is in world space.
+        ReparentCamera {
+            camera,
+            parent: None,
+        }
+        .write(&mut world);
+        assert!(world.get::<Parent>(camera).is_none());
+        let detached = *world.get::<LookTransform>(camera).unwrap();
+        assert!(detached.eye.abs_diff_eq(old_world.eye, 1e-4));
+        let smoothed = world.get::<Smoother>(camera).unwrap().smoothed_transform();
+        assert!(smoothed
+            .unwrap()
+            .eye
+            .abs_diff_eq(old_world.eye + Vec3::X, 1e-4));
+    }
+}
@@ -0,0 +1,162 @@
+use crate::{LookTransform, LookTransformSystem};
+
+use bevy::{
+    app::prelude::*, ecs::prelude::*, prelude::Projection, render::camera::OrthographicProjection,
+};
+#[cfg(feature = "serialize")]
+use serde::{Deserialize, Serialize};
+
+/// Keeps the framing of `ResizeFraming` cameras when their viewport is resized.
+pub struct ResizeFramingPlugin;
+
+impl Plugin for ResizeFramingPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_system(resize_framing_system.before(LookTransformSystem));
+    }
+}
+
+/// Which extent of the view `ResizeFraming` preserves.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(Deserialize, Serialize))]
+pub enum FramingAxis {
+    #[default]
+    Vertical,
+    Horizontal,
+}
+
+/// Keeps the framing of a camera when its viewport is resized, so objects framed at the edge of the `axis` stay in view.
+/// Perspective cameras move the eye toward or away from the target, and orthographic cameras change their scale.
+///
+/// Perspective cameras keep their vertical FOV by default, so `Vertical` only affects orthographic cameras whose scaling
+/// mode doesn't already preserve it. Changes of the FOV itself (e.g. by `SpeedFov`) are not compensated.
+///
+/// The compensation is applied to the `LookTransform`, so it blends in with the `Smoother` like any other move. Controllers
+/// that keep their own distance (like the follow camera's `distance`) override it.
+#[derive(Clone, Component, Copy, Debug, Default)]
+#[cfg_attr(feature = "serialize", derive(Deserialize, Serialize))]
+pub struct ResizeFraming {
+    pub axis: FramingAxis,
+    /// The extent of the view along `axis` per unit of radius or scale, when last seen.
+    #[cfg_attr(feature = "serialize", serde(skip))]
+    extent: Option<f32>,
+}
+
+impl ResizeFraming {
+    pub fn new(axis: FramingAxis) -> Self {
+        Self { axis, extent: None }
+    }
+
+    /// The extent of a perspective view along `axis` per unit of distance, up to the constant FOV factor.
+    fn perspective_extent(&self, aspect_ratio: f32) -> f32 {
+        match self.axis {
+            FramingAxis::Vertical => 1.0,
+            FramingAxis::Horizontal => aspect_ratio,
+        }
+    }
+
+    /// The extent of an orthographic view along `axis` per unit of scale.
+    fn orthographic_extent(&self, projection: &OrthographicProjection) -> f32 {
+        match self.axis {
+            FramingAxis::Vertical => projection.top - projection.bottom,
+            FramingAxis::Horizontal => projection.right - projection.left,
+        }
+    }
+
+    /// Returns the factor to scale the radius or scale by to keep the previous extent. Degenerate extents (e.g. of a
+    /// minimized window) are ignored, so restoring the window compares to the size before.
+    fn compensation(&mut self, extent: f32) -> Option<f32> {
+        if !extent.is_finite() || extent <= 0.0 {
+            return None;
+        }
+        let previous = self.extent.replace(extent)?;
+
+        (previous != extent).then(|| previous / extent)
+    }
+}
+
+pub(crate) fn resize_framing_system(
+    mut cameras: Query<(
+        &mut ResizeFraming,
+        &mut LookTransform,
+        Option<&Projection>,
+        Option<&OrthographicProjection>,
+    )>,
+) {
+    for (mut framing, mut transform, projection, orthographic) in cameras.iter_mut() {
+        // 2D cameras carry their projection as its own component.
+        let (extent, is_orthographic) = match (projection, orthographic) {
+            (Some(Projection::Perspective(perspective)), _) => {
+                (framing.perspective_extent(perspective.aspect_ratio), false)
+            }
+            (Some(Projection::Orthographic(orthographic)), _) | (None, Some(orthographic)) => {
+                (framing.orthographic_extent(orthographic), true)
+            }
+            (None, None) => continue,
+        };
+        let factor = match framing.compensation(extent) {
+            Some(factor) => factor,
+            None => continue,
+        };
+
+        if is_orthographic {
+            transform.scale *= factor;
+        } else {
+            transform.eye = transform.target + factor * (transform.eye - transform.target);
+        }
+    }
+}
+
+// ████████╗███████╗███████╗████████╗
+// ╚══██╔══╝██╔════╝██╔════╝╚══██╔══╝
+//    ██║   █████╗  ███████╗   ██║
+//    ██║   ██╔══╝  ╚════██║   ██║
+//    ██║   ███████╗███████║   ██║
+//    ╚═╝   ╚══════╝╚══════╝   ╚═╝
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::assert_relative_eq;
+    use bevy::{math::prelude::*, prelude::PerspectiveProjection};
+
+    #[test]
+    fn test_narrower_viewport_keeps_horizontal_framing() {
+        let mut app = App::new();
+        app.add_system(resize_framing_system);
+        let view = LookTransform::new(Vec3::new(0.0, 0.0, 10.0), Vec3::ZERO);
+        let camera = app
+            .world
+            .spawn((
+                view,
+                ResizeFraming::new(FramingAxis::Horizontal),
+                Projection::Perspective(PerspectiveProjection {
+                    aspect_ratio: 2.0,
+                    ..Default::default()
+                }),
+            ))
+            .id();
+
+        app.update();
+        assert_eq!(*app.world.get::<LookTransform>(camera).unwrap(), view);
+
+        if let Projection::Perspective(perspective) =
+            app.world.get_mut::<Projection>(camera).unwrap().as_mut()
+        {
+            perspective.aspect_ratio = 1.0;
+        }
+        app.update();
+        // Half the aspect ratio needs twice the distance for the same width.
+        let transform = *app.world.get::<LookTransform>(camera).unwrap();
+        assert_relative_eq!(transform.radius(), 20.0, epsilon = 1e-4);
+        assert_eq!(transform.target, view.target);
+    }
+
+    #[test]
+    fn test_degenerate_extent_is_ignored() {
+        let mut framing = ResizeFraming::new(FramingAxis::Vertical);
+        assert_eq!(framing.compensation(600.0), None);
+        assert_eq!(framing.compensation(0.0), None);
+        assert_eq!(framing.compensation(300.0), Some(2.0));
+    }
+}
@@ -0,0 +1,278 @@
+use crate::{
+    CameraMoveHandle, CoordinateSystem, Easing, IntroMove, LookTransform, LookTransformSystem,
+    Smoother,
+};
+
+use bevy::{
+    app::prelude::*, ecs::prelude::*, input::prelude::*, math::prelude::*, time::Time,
+    transform::components::GlobalTransform,
+};
+
+/// Plays `ShotSequence`s, advanced by time, input or `ShotSignal` events.
+pub struct ShotSequencePlugin;
+
+impl Plugin for ShotSequencePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<ShotSignal>()
+            .add_event::<ShotSequenceEvent>()
+            .add_system(shot_sequence_system.before(LookTransformSystem));
+    }
+}
+
+/// When the shot of a `ShotSequence` advances to the next one.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ShotAdvance {
+    /// After holding the shot for this many seconds, counted from the end of the transition into it.
+    After(f32),
+    /// When a `ShotSignal` with this name is sent for the camera, e.g. when a dialogue line ends.
+    Signal(&'static str),
+    /// When `entity` comes within `radius` world units of `point`.
+    EntityReaches {
+        entity: Entity,
+        point: Vec3,
+        radius: f32,
+    },
+    /// When the key is pressed.
+    KeyPressed(KeyCode),
+    /// When any of the conditions is met, e.g. a key press that skips a timed shot.
+    Any(Vec<ShotAdvance>),
+}
+
+impl ShotAdvance {
+    /// Whether the condition is met after holding the shot for `hold` seconds (negative during the transition).
+    fn is_met(
+        &self,
+        hold: f32,
+        signals: &[&'static str],
+        keyboard: Option<&Input<KeyCode>>,
+        position: &impl Fn(Entity) -> Option<Vec3>,
+    ) -> bool {
+        match self {
+            ShotAdvance::After(duration) => hold >= *duration,
+            ShotAdvance::Signal(signal) => signals.contains(signal),
+            ShotAdvance::EntityReaches {
+                entity,
+                point,
+                radius,
+            } => position(*entity).map_or(false, |p| p.distance(*point) <= *radius),
+            ShotAdvance::KeyPressed(key) => keyboard.map_or(false, |k| k.just_pressed(*key)),
+            ShotAdvance::Any(conditions) => conditions
+                .iter()
+                .any(|c| c.is_met(hold, signals, keyboard, position)),
+        }
+    }
+}
+
+/// A view of a `ShotSequence`, and what it waits for before the next one.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CameraShot {
+    pub view: LookTransform,
+    /// Seconds of moving from the previous view into this one. `0.0` cuts, skipping the camera's smoothing.
+    pub transition: f32,
+    pub easing: Easing,
+    pub advance: ShotAdvance,
+}
+
+impl CameraShot {
+    /// A shot that is cut to.
+    pub fn new(view: LookTransform, advance: ShotAdvance) -> Self {
+        Self {
+            view,
+            transition: 0.0,
+            easing: Easing::EaseInOut,
+            advance,
+        }
+    }
+
+    pub fn with_transition(mut self, duration: f32, easing: Easing) -> Self {
+        self.transition = duration;
+        self.easing = easing;
+        self
+    }
+}
+
+/// Plays a chain of `CameraShot`s on the `LookTransform` of the same entity, e.g. for interactive cutscenes. Each shot
+/// waits for its `ShotAdvance` condition before the next one starts. The component removes itself after the last shot.
+///
+/// ```ignore
+/// commands.entity(camera).insert(ShotSequence::new(vec![
+///     CameraShot::new(door_view, ShotAdvance::Signal("door_opened")),
+///     CameraShot::new(hall_view, ShotAdvance::Any(vec![
+///         ShotAdvance::After(4.0),
+///         ShotAdvance::KeyPressed(KeyCode::Space),
+///     ]))
+///     .with_transition(2.0, Easing::EaseInOut),
+/// ]));
+/// ```
+///
+/// Like `FlyTo`, the sequence writes the `LookTransform` every frame, so disable the camera's controller while it plays.
+#[derive(Component)]
+pub struct ShotSequence {
+    pub shots: Vec<CameraShot>,
+    current: usize,
+    elapsed: f32,
+    from: Option<LookTransform>,
+    handle: CameraMoveHandle,
+}
+
+impl ShotSequence {
+    pub fn new(shots: Vec<CameraShot>) -> Self {
+        Self {
+            shots,
+            current: 0,
+            elapsed: 0.0,
+            from: None,
+            handle: CameraMoveHandle::default(),
+        }
+    }
+
+    /// The index of the playing shot.
+    pub fn current(&self) -> usize {
+        self.current
+    }
+
+    /// Resolves after the last shot (the smoother may still be catching up).
+    pub fn handle(&self) -> CameraMoveHandle {
+        self.handle.clone()
+    }
+}
+
+/// Satisfies `ShotAdvance::Signal` conditions of the sequence on `camera`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ShotSignal {
+    pub camera: Entity,
+    pub signal: &'static str,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ShotSequenceEvent {
+    ShotStarted { camera: Entity, index: usize },
+    Finished { camera: Entity },
+}
+
+pub(crate) fn shot_sequence_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    keyboard: Option<Res<Input<KeyCode>>>,
+    mut signals: EventReader<ShotSignal>,
+    mut sequence_events: EventWriter<ShotSequenceEvent>,
+    positions: Query<&GlobalTransform>,
+    mut cameras: Query<(
+        Entity,
+        &mut ShotSequence,
+        &mut LookTransform,
+        Option<&mut Smoother>,
+    )>,
+) {
+    let signals: Vec<ShotSignal> = signals.iter().copied().collect();
+    let position = |entity: Entity| positions.get(entity).ok().map(GlobalTransform::translation);
+
+    for (camera, mut sequence, mut transform, mut smoother) in cameras.iter_mut() {
+        let sequence = &mut *sequence;
+        if sequence.from.is_none() {
+            sequence.from = Some(*transform);
+            sequence_events.send(ShotSequenceEvent::ShotStarted { camera, index: 0 });
+        }
+        let shot = match sequence.shots.get(sequence.current) {
+            Some(shot) => shot,
+            None => {
+                sequence.handle.complete();
+                sequence_events.send(ShotSequenceEvent::Finished { camera });
+                commands.entity(camera).remove::<ShotSequence>();
+                continue;
+            }
+        };
+
+        if sequence.elapsed == 0.0 && shot.transition <= 0.0 {
+            if let Some(smoother) = &mut smoother {
+                smoother.reset();
+            }
+        }
+        sequence.elapsed += time.delta_seconds();
+        let t = if shot.transition > 0.0 {
+            (sequence.elapsed / shot.transition).min(1.0)
+        } else {
+            1.0
+        };
+        let from = sequence.from.unwrap_or(shot.view);
+        // A cut from one view to another doesn't depend on the up axis.
+        *transform = IntroMove::From(from).transform_at(
+            shot.view,
+            1.0 - shot.easing.apply(t),
+            &CoordinateSystem::Y_UP,
+        );
+
+        let camera_signals: Vec<&'static str> = signals
+            .iter()
+            .filter(|s| s.camera == camera)
+            .map(|s| s.signal)
+            .collect();
+        let hold = sequence.elapsed - shot.transition;
+        if !shot
+            .advance
+            .is_met(hold, &camera_signals, keyboard.as_deref(), &position)
+        {
+            continue;
+        }
+
+        // Advancing during a transition starts the next one from where the camera is.
+        sequence.from = Some(*transform);
+        sequence.elapsed = 0.0;
+        sequence.current += 1;
+        if sequence.current < sequence.shots.len() {
+            sequence_events.send(ShotSequenceEvent::ShotStarted {
+                camera,
+                index: sequence.current,
+            });
+        }
+    }
+}
+
+// ████████╗███████╗███████╗████████╗
+// ╚══██╔══╝██╔════╝██╔════╝╚══██╔══╝
+//    ██║   █████╗  ███████╗   ██║
+//    ██║   ██╔══╝  ╚════██║   ██║
+//    ██║   ███████╗███████║   ██║
+//    ╚═╝   ╚══════╝╚══════╝   ╚═╝
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn no_position(_: Entity) -> Option<Vec3> {
+        None
+    }
+
+    #[test]
+    fn test_after_counts_from_end_of_transition() {
+        let advance = ShotAdvance::After(1.0);
+        assert!(!advance.is_met(-0.5, &[], None, &no_position));
+        assert!(!advance.is_met(0.5, &[], None, &no_position));
+        assert!(advance.is_met(1.0, &[], None, &no_position));
+    }
+
+    #[test]
+    fn test_any_condition_advances() {
+        let advance = ShotAdvance::Any(vec![
+            ShotAdvance::After(10.0),
+            ShotAdvance::Signal("door_opened"),
+        ]);
+        assert!(!advance.is_met(0.0, &["lights_on"], None, &no_position));
+        assert!(advance.is_met(0.0, &["door_opened"], None, &no_position));
+    }
+
+    #[test]
+    fn test_entity_reaches_point() {
+        let entity = Entity::from_raw(1);
+        let advance = ShotAdvance::EntityReaches {
+            entity,
+            point: Vec3::X,
+            radius: 0.5,
+        };
+        let near = |_: Entity| Some(Vec3::new(1.2, 0.0, 0.0));
+        let far = |_: Entity| Some(Vec3::new(3.0, 0.0, 0.0));
+        assert!(advance.is_met(0.0, &[], None, &near));
+        assert!(!advance.is_met(0.0, &[], None, &far));
+        assert!(!advance.is_met(0.0, &[], None, &no_position));
+    }
+}
@@ -0,0 +1,203 @@
+use crate::{LookTransform, LookTransformSystem};
+
+use bevy::{app::prelude::*, ecs::prelude::*, math::prelude::*, time::Time};
+#[cfg(feature = "serialize")]
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+
+/// Plays back the snapshots buffered by `SnapshotInterpolation`s.
+pub struct SnapshotInterpolationPlugin;
+
+impl Plugin for SnapshotInterpolationPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_system(snapshot_interpolation_system.before(LookTransformSystem));
+    }
+}
+
+/// A compact, serializable `LookTransform` at a point in (sender) time, for replicating cameras over the network.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(Deserialize, Serialize))]
+pub struct LookTransformSnapshot {
+    /// Seconds on the sender's clock.
+    pub timestamp: f64,
+    pub eye: Vec3,
+    pub target: Vec3,
+    pub scale: f32,
+    #[cfg_attr(feature = "serialize", serde(default))]
+    pub roll: f32,
+}
+
+impl LookTransformSnapshot {
+    pub fn new(transform: &LookTransform, timestamp: f64) -> Self {
+        Self {
+            timestamp,
+            eye: transform.eye,
+            target: transform.target,
+            scale: transform.scale,
+            roll: transform.roll,
+        }
+    }
+
+    pub fn look_transform(&self) -> LookTransform {
+        LookTransform {
+            eye: self.eye,
+            target: self.target,
+            scale: self.scale,
+            roll: self.roll,
+        }
+    }
+
+    pub fn lerp(&self, other: &Self, t: f32) -> LookTransform {
+        LookTransform {
+            eye: self.eye.lerp(other.eye, t),
+            target: self.target.lerp(other.target, t),
+            scale: self.scale + (other.scale - self.scale) * t,
+            roll: self.roll + (other.roll - self.roll) * t,
+        }
+    }
+}
+
+/// Buffers remotely received snapshots and plays them back `delay` seconds behind the newest one, interpolating between
+/// snapshots and writing the result to the `LookTransform` of the same entity. A `Smoother` on the entity then smooths the
+/// output as usual.
+#[derive(Component)]
+pub struct SnapshotInterpolation {
+    /// How far playback stays behind the newest snapshot. Should cover a few network send intervals to absorb jitter.
+    pub delay: f64,
+    /// Maximum number of buffered snapshots; the oldest ones are dropped first.
+    pub capacity: usize,
+    snapshots: VecDeque<LookTransformSnapshot>,
+    playback_time: Option<f64>,
+}
+
+impl SnapshotInterpolation {
+    pub fn new(delay: f64) -> Self {
+        Self {
+            delay,
+            capacity: 32,
+            snapshots: VecDeque::new(),
+            playback_time: None,
+        }
+    }
+
+    /// Adds a received snapshot. Snapshots that arrive out of order and older than the newest one are dropped.
+    pub fn push(&mut self, snapshot: LookTransformSnapshot) {
+        if let Some(newest) = self.snapshots.back() {
+            if snapshot.timestamp <= newest.timestamp {
+                return;
+            }
+        }
+        self.snapshots.push_back(snapshot);
+        while self.snapshots.len() > self.capacity.max(2) {
+            self.snapshots.pop_front();
+        }
+    }
+
+    /// The playback time on the sender's clock, once the first snapshot has arrived.
+    pub fn playback_time(&self) -> Option<f64> {
+        self.playback_time
+    }
+
+    /// Interpolates the buffered snapshots at `time`, holding the first or last snapshot outside of the buffered range.
+    pub fn sample(&self, time: f64) -> Option<LookTransform> {
+        let first = self.snapshots.front()?;
+        if time <= first.timestamp {
+            return Some(first.look_transform());
+        }
+        for (a, b) in self.snapshots.iter().zip(self.snapshots.iter().skip(1)) {
+            if time <= b.timestamp {
+                let t = (time - a.timestamp) / (b.timestamp - a.timestamp);
+                return Some(a.lerp(b, t as f32));
+            }
+        }
+
+        self.snapshots
+            .back()
+            .map(LookTransformSnapshot::look_transform)
+    }
+
+    fn advance(&mut self, dt: f64) -> Option<LookTransform> {
+        let newest = self.snapshots.back()?.timestamp;
+        let target_time = newest - self.delay;
+        let time = match self.playback_time {
+            // Resync if we fell too far behind, e.g. after a stall.
+            Some(time) if newest - time <= 2.0 * self.delay => time + dt,
+            _ => target_time,
+        };
+        self.playback_time = Some(time);
+
+        // Drop snapshots we have played past, keeping one to interpolate from.
+        while self.snapshots.len() > 2 && self.snapshots[1].timestamp <= time {
+            self.snapshots.pop_front();
+        }
+
+        self.sample(time)
+    }
+}
+
+pub(crate) fn snapshot_interpolation_system(
+    time: Res<Time>,
+    mut cameras: Query<(&mut SnapshotInterpolation, &mut LookTransform)>,
+) {
+    for (mut interpolation, mut transform) in cameras.iter_mut() {
+        if let Some(sampled) = interpolation.advance(time.delta_seconds_f64()) {
+            *transform = sampled;
+        }
+    }
+}
+
+// ████████╗███████╗███████╗████████╗
+// ╚══██╔══╝██╔════╝██╔════╝╚══██╔══╝
+//    ██║   █████╗  ███████╗   ██║
+//    ██║   ██╔══╝  ╚════██║   ██║
+//    ██║   ███████╗███████║   ██║
+//    ╚═╝   ╚══════╝╚══════╝   ╚═╝
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn snapshot(timestamp: f64, x: f32) -> LookTransformSnapshot {
+        LookTransformSnapshot {
+            timestamp,
+            eye: Vec3::new(x, 0.0, 0.0),
+            target: Vec3::ZERO,
+            scale: 1.0,
+            roll: 0.0,
+        }
+    }
+
+    #[test]
+    fn test_sample_interpolates_between_snapshots() {
+        let mut interpolation = SnapshotInterpolation::new(0.1);
+        interpolation.push(snapshot(0.0, 0.0));
+        interpolation.push(snapshot(1.0, 2.0));
+
+        assert_eq!(interpolation.sample(0.5).unwrap().eye.x, 1.0);
+        assert_eq!(interpolation.sample(-1.0).unwrap().eye.x, 0.0);
+        assert_eq!(interpolation.sample(2.0).unwrap().eye.x, 2.0);
+    }
+
+    #[test]
+    fn test_out_of_order_snapshots_are_dropped() {
+        let mut interpolation = SnapshotInterpolation::new(0.1);
+        interpolation.push(snapshot(1.0, 1.0));
+        interpolation.push(snapshot(0.5, 5.0));
+
+        assert_eq!(interpolation.sample(0.5).unwrap().eye.x, 1.0);
+    }
+
+    #[test]
+    fn test_playback_stays_behind_newest_snapshot() {
+        let mut interpolation = SnapshotInterpolation::new(0.5);
+        interpolation.push(snapshot(0.0, 0.0));
+        interpolation.push(snapshot(1.0, 1.0));
+
+        let sampled = interpolation.advance(0.0).unwrap();
+        assert_eq!(interpolation.playback_time(), Some(0.5));
+        assert_eq!(sampled.eye.x, 0.5);
+
+        let sampled = interpolation.advance(0.25).unwrap();
+        assert_eq!(sampled.eye.x, 0.75);
+    }
+}
@@ -0,0 +1,221 @@
+use crate::{
+    CameraCollisionHook, CameraModifierAnchor, CollisionHit, LookTransform, LookTransformSystem,
+    Smoother,
+};
+
+use bevy::{
+    app::prelude::*, ecs::prelude::*, math::prelude::*, time::Time,
+    transform::components::Transform,
+};
+#[cfg(feature = "physics")]
+use bevy_rapier3d::prelude::{Collider, CollisionGroups, Group, QueryFilter, RapierContext};
+
+/// Pulls the eyes of `SpringArm` cameras in front of obstacles after smoothing.
+pub struct SpringArmPlugin;
+
+impl Plugin for SpringArmPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_system(
+            spring_arm_system
+                .label(CameraModifierAnchor::Collision)
+                .label(CameraModifierAnchor::Effects)
+                .after(LookTransformSystem),
+        );
+    }
+}
+
+/// Keeps obstacles between the target and the eye out of view, e.g. walls behind the player of a third-person orbit or
+/// follow camera. Each frame a sphere is swept from the target toward the smoothed eye, and the eye is pulled in front of
+/// the first obstacle. When the obstacle clears, the eye moves back out smoothly.
+///
+/// The sweep uses the `CameraCollisionHook` resource, or with the `physics` feature, the `RapierContext` of
+/// `bevy_rapier3d` when there is no hook. Only the scene `Transform` is moved, so the controller keeps its zoom distance.
+///
+/// Since the sweep starts at the target, leave the collider the target sits in (e.g. the player's) out of `mask`.
+#[derive(Clone, Copy, Component, Debug)]
+pub struct SpringArm {
+    /// Radius of the sphere swept from the target, keeping the near plane off obstacles.
+    pub radius: f32,
+    /// Which colliders block the arm, interpreted by the `CameraCollisionHook` (or as rapier collision groups).
+    pub mask: u32,
+    /// The eye is never pulled closer to the target than this.
+    pub min_length: f32,
+    /// How fast the arm extends again once the obstacle clears, in `1 / seconds`: the remaining length shrinks by a
+    /// factor of `e` every `1 / recovery_rate` seconds. Pulling in is always immediate.
+    pub recovery_rate: f32,
+    /// The current arm length, or `None` when fully extended.
+    length: Option<f32>,
+}
+
+impl Default for SpringArm {
+    fn default() -> Self {
+        Self {
+            radius: 0.2,
+            mask: u32::MAX,
+            min_length: 0.5,
+            recovery_rate: 5.0,
+            length: None,
+        }
+    }
+}
+
+impl SpringArm {
+    /// The current arm length, or `None` when fully extended.
+    pub fn length(&self) -> Option<f32> {
+        self.length
+    }
+
+    /// Returns the arm length after `dt` seconds, where the eye is `full_length` from the target and an obstacle is
+    /// `blocked_length` from it (if any).
+    pub fn update(&mut self, full_length: f32, blocked_length: Option<f32>, dt: f32) -> f32 {
+        let desired = blocked_length
+            .map_or(full_length, |blocked| blocked.min(full_length))
+            .max(self.min_length.min(full_length));
+        let current = self.length.unwrap_or(full_length).min(full_length);
+
+        let length = if desired <= current {
+            desired
+        } else {
+            current + (desired - current) * (1.0 - (-self.recovery_rate * dt).exp())
+        };
+        // Snap out once recovered, so the arm follows zooming without lag.
+        self.length = (full_length - length > 1e-3).then_some(length);
+
+        length
+    }
+
+    pub fn reset(&mut self) {
+        self.length = None;
+    }
+
+    /// Moves `transform` (the smoothed eye) toward `target` to keep obstacles found by `sweep` out of view.
+    fn apply(
+        &mut self,
+        target: Vec3,
+        transform: &mut Transform,
+        dt: f32,
+        sweep: impl Fn(Vec3, Vec3, f32, u32) -> Option<CollisionHit>,
+    ) {
+        let arm = transform.translation - target;
+        let full_length = arm.length();
+        let direction = match arm.try_normalize() {
+            Some(direction) => direction,
+            None => return,
+        };
+        let blocked_length = sweep(target, arm, self.radius, self.mask)
+            .map(|hit| hit.fraction.clamp(0.0, 1.0) * full_length);
+        let length = self.update(full_length, blocked_length, dt);
+        if length < full_length {
+            transform.translation = target + length * direction;
+        }
+    }
+}
+
+pub(crate) fn spring_arm_system(
+    time: Res<Time>,
+    hook: Option<Res<CameraCollisionHook>>,
+    #[cfg(feature = "physics")] rapier: Option<Res<RapierContext>>,
+    mut cameras: Query<(
+        &mut SpringArm,
+        &LookTransform,
+        &mut Transform,
+        Option<&Smoother>,
+    )>,
+) {
+    let dt = time.delta_seconds();
+    for (mut arm, look_transform, mut transform, smoother) in cameras.iter_mut() {
+        // The transform is only rewritten each frame by the smoother, so don't pull it in again otherwise.
+        if !smoother.map_or(false, Smoother::is_enabled) {
+            arm.reset();
+            continue;
+        }
+
+        if let Some(hook) = &hook {
+            arm.apply(
+                look_transform.target,
+                &mut transform,
+                dt,
+                |start, displacement, radius, mask| hook.sweep(start, displacement, radius, mask),
+            );
+            continue;
+        }
+        #[cfg(feature = "physics")]
+        if let Some(rapier) = &rapier {
+            arm.apply(
+                look_transform.target,
+                &mut transform,
+                dt,
+                |start, displacement, radius, mask| {
+                    let groups = CollisionGroups::new(Group::ALL, Group::from_bits_truncate(mask));
+                    let filter = QueryFilter::new().groups(groups.into());
+                    rapier
+                        .cast_shape(
+                            start,
+                            Quat::IDENTITY,
+                            displacement,
+                            &Collider::ball(radius),
+                            1.0,
+                            filter,
+                        )
+                        .map(|(_, toi)| CollisionHit {
+                            fraction: toi.toi,
+                            normal: toi.normal1,
+                        })
+                },
+            );
+        }
+    }
+}
+
+// ████████╗███████╗███████╗████████╗
+// ╚══██╔══╝██╔════╝██╔════╝╚══██╔══╝
+//    ██║   █████╗  ███████╗   ██║
+//    ██║   ██╔══╝  ╚════██║   ██║
+//    ██║   ███████╗███████║   ██║
+//    ╚═╝   ╚══════╝╚══════╝   ╚═╝
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pulls_in_immediately_and_recovers_smoothly() {
+        let mut arm = SpringArm::default();
+        assert_eq!(arm.update(10.0, Some(4.0), 1.0 / 60.0), 4.0);
+
+        // The obstacle clears.
+        let first = arm.update(10.0, None, 1.0 / 60.0);
+        assert!(first > 4.0 && first < 10.0);
+        let mut length = first;
+        for _ in 0..600 {
+            let next = arm.update(10.0, None, 1.0 / 60.0);
+            assert!(next >= length);
+            length = next;
+        }
+        assert_eq!(length, 10.0);
+        assert_eq!(arm.length(), None);
+    }
+
+    #[test]
+    fn test_min_length() {
+        let mut arm = SpringArm::default();
+        assert_eq!(arm.update(10.0, Some(0.0), 1.0 / 60.0), arm.min_length);
+        // Zooming in closer than the minimum isn't pushed back out.
+        arm.reset();
+        assert_eq!(arm.update(0.25, Some(0.0), 1.0 / 60.0), 0.25);
+    }
+
+    #[test]
+    fn test_apply_keeps_eye_on_arm() {
+        let mut arm = SpringArm::default();
+        let mut transform = Transform::from_xyz(0.0, 0.0, 10.0);
+        let wall = |_: Vec3, _: Vec3, _: f32, _: u32| {
+            Some(CollisionHit {
+                fraction: 0.5,
+                normal: Vec3::NEG_Z,
+            })
+        };
+        arm.apply(Vec3::ZERO, &mut transform, 1.0 / 60.0, wall);
+        assert_eq!(transform.translation, Vec3::new(0.0, 0.0, 5.0));
+    }
+}
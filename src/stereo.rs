@@ -0,0 +1,62 @@
+use crate::{CoordinateSystem, LookTransform, LookTransformSystem, Smoother};
+
+use bevy::{app::prelude::*, ecs::prelude::*, transform::components::Transform};
+
+/// Positions `StereoEye` cameras around their rig after smoothing.
+pub struct StereoPlugin;
+
+impl Plugin for StereoPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<CoordinateSystem>()
+            .add_system(stereo_eye_system.after(LookTransformSystem));
+    }
+}
+
+/// One camera of a stereo pair. The rig entity owns the shared `LookTransform` and `Smoother`; each eye is offset
+/// horizontally from the rig's smoothed position and converges on the smoothed target.
+///
+/// Eye entities should not be children of the rig, since their `Transform` is written in world space.
+#[derive(Clone, Component, Copy, Debug)]
+pub struct StereoEye {
+    pub rig: Entity,
+    /// Signed offset along the rig's right axis. Negative for the left eye.
+    pub offset: f32,
+}
+
+impl StereoEye {
+    /// Returns the (left, right) eyes for a rig with the given eye separation.
+    pub fn pair(rig: Entity, separation: f32) -> (Self, Self) {
+        (
+            Self {
+                rig,
+                offset: -0.5 * separation,
+            },
+            Self {
+                rig,
+                offset: 0.5 * separation,
+            },
+        )
+    }
+}
+
+fn stereo_eye_system(
+    coordinates: Res<CoordinateSystem>,
+    rigs: Query<(&LookTransform, &Transform, Option<&Smoother>), Without<StereoEye>>,
+    mut eyes: Query<(&StereoEye, &mut Transform)>,
+) {
+    for (eye, mut eye_transform) in eyes.iter_mut() {
+        let (look_transform, rig_transform, smoother) = if let Ok(rig) = rigs.get(eye.rig) {
+            rig
+        } else {
+            continue;
+        };
+        let target = smoother
+            .and_then(Smoother::smoothed_transform)
+            .unwrap_or(*look_transform)
+            .target;
+
+        let eye_position = rig_transform.translation + eye.offset * rig_transform.right();
+        *eye_transform =
+            Transform::from_translation(eye_position).looking_at(target, coordinates.up());
+    }
+}
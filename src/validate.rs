@@ -0,0 +1,109 @@
+//! Per-frame validation of `LookTransform`s in debug builds with the `validate` feature.
+//!
+//! bevy doesn't record which system mutated a component, so the values are checked at a few checkpoints per frame, and a
+//! violation reports between which checkpoints the `LookTransform` was last changed.
+
+use crate::{LookTransform, LookTransformSystem};
+
+use bevy::{app::prelude::*, ecs::prelude::*, transform::components::Transform, utils::HashMap};
+
+/// Limits checked by the validation, in addition to the values being finite.
+#[derive(Clone, Copy, Debug, Resource)]
+pub struct LookTransformValidation {
+    pub min_radius: f32,
+    pub max_radius: f32,
+}
+
+impl Default for LookTransformValidation {
+    fn default() -> Self {
+        Self {
+            min_radius: 1e-6,
+            max_radius: 1e6,
+        }
+    }
+}
+
+#[derive(Resource)]
+struct LastMutations {
+    /// The last seen value of each `LookTransform`, and the checkpoints before and after which it changed.
+    by_entity: HashMap<Entity, (LookTransform, &'static str, &'static str)>,
+    previous_checkpoint: &'static str,
+}
+
+impl Default for LastMutations {
+    fn default() -> Self {
+        Self {
+            by_entity: HashMap::default(),
+            previous_checkpoint: BEFORE_LOOK_TRANSFORM_SYSTEM,
+        }
+    }
+}
+
+const FRAME_START: &str = "the start of the frame";
+const BEFORE_LOOK_TRANSFORM_SYSTEM: &str = "LookTransformSystem";
+
+pub(crate) fn add_validation_systems(app: &mut App) {
+    app.init_resource::<LookTransformValidation>()
+        .init_resource::<LastMutations>()
+        .add_system_to_stage(CoreStage::First, validation_system(FRAME_START))
+        .add_system(validation_system(BEFORE_LOOK_TRANSFORM_SYSTEM).before(LookTransformSystem));
+}
+
+fn same_value(a: &LookTransform, b: &LookTransform) -> bool {
+    a.eye == b.eye && a.target == b.target && a.scale == b.scale
+}
+
+type ValidatedCameras<'w, 's> =
+    Query<'w, 's, (Entity, &'static LookTransform, Option<&'static Transform>)>;
+
+fn validation_system(
+    checkpoint: &'static str,
+) -> impl FnMut(Res<LookTransformValidation>, ResMut<LastMutations>, ValidatedCameras<'_, '_>) {
+    move |limits, mut mutations, cameras| {
+        let previous_checkpoint = mutations.previous_checkpoint;
+        mutations.previous_checkpoint = checkpoint;
+
+        for (entity, look_transform, transform) in cameras.iter() {
+            let (after, before) = match mutations.by_entity.get(&entity) {
+                Some((last_seen, after, before)) if same_value(last_seen, look_transform) => {
+                    (*after, *before)
+                }
+                _ => (previous_checkpoint, checkpoint),
+            };
+            mutations
+                .by_entity
+                .insert(entity, (*look_transform, after, before));
+
+            let culprit = || {
+                format!(
+                    "{:?}: {:?}, last changed after {} and before {}",
+                    entity, look_transform, after, before
+                )
+            };
+            assert!(
+                look_transform.eye.is_finite()
+                    && look_transform.target.is_finite()
+                    && look_transform.scale.is_finite(),
+                "LookTransform is not finite. {}",
+                culprit()
+            );
+            let radius = look_transform.radius();
+            assert!(
+                (limits.min_radius..=limits.max_radius).contains(&radius),
+                "LookTransform radius {} is outside of [{}, {}]. {}",
+                radius,
+                limits.min_radius,
+                limits.max_radius,
+                culprit()
+            );
+            if let Some(transform) = transform {
+                assert!(
+                    transform.rotation.is_normalized(),
+                    "Camera rotation {:?} is not normalized. {}",
+                    transform.rotation,
+                    culprit()
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,137 @@
+use crate::{LookTransform, LookTransformSystem, Smoother};
+
+use bevy::{app::prelude::*, ecs::prelude::*, prelude::Projection};
+
+/// Sends `ZoomLodChanged` events for `ZoomLodLevels` cameras.
+pub struct ZoomLodPlugin;
+
+impl Plugin for ZoomLodPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<ZoomLodChanged>()
+            .add_system(zoom_lod_system.after(LookTransformSystem));
+    }
+}
+
+/// Sent when the zoom level of a camera with `ZoomLodLevels` changes, e.g. so data-loading systems can switch LODs.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct ZoomLodChanged {
+    pub camera: Entity,
+    pub previous: Option<usize>,
+    pub level: usize,
+}
+
+/// Divides the zoom of a camera into levels by ascending `thresholds`. The zoom is the eye-to-target distance for perspective
+/// cameras and the projection scale for orthographic ones, as output by the smoother.
+///
+/// Level `i` means the zoom is above `i` of the thresholds. A threshold is only considered crossed once the zoom is past it
+/// by the relative `hysteresis`, so the level doesn't flap while the camera settles.
+#[derive(Clone, Component, Debug)]
+pub struct ZoomLodLevels {
+    pub thresholds: Vec<f32>,
+    pub hysteresis: f32,
+    level: Option<usize>,
+}
+
+impl ZoomLodLevels {
+    pub fn new(thresholds: Vec<f32>, hysteresis: f32) -> Self {
+        Self {
+            thresholds,
+            hysteresis,
+            level: None,
+        }
+    }
+
+    /// The current level, if the zoom has been measured yet.
+    pub fn level(&self) -> Option<usize> {
+        self.level
+    }
+
+    /// Returns the level for `zoom`, given the current level.
+    fn next_level(&self, zoom: f32) -> usize {
+        let raw_level = self.thresholds.iter().filter(|t| zoom > **t).count();
+        let current = match self.level {
+            Some(current) => current,
+            None => return raw_level,
+        };
+
+        if raw_level > current {
+            let boundary = self.thresholds[raw_level - 1];
+            if zoom > boundary * (1.0 + self.hysteresis) {
+                return raw_level;
+            }
+        } else if raw_level < current {
+            let boundary = self.thresholds[raw_level];
+            if zoom < boundary * (1.0 - self.hysteresis) {
+                return raw_level;
+            }
+        }
+
+        current
+    }
+}
+
+#[allow(clippy::type_complexity)]
+pub(crate) fn zoom_lod_system(
+    mut lod_events: EventWriter<ZoomLodChanged>,
+    mut cameras: Query<(
+        Entity,
+        &mut ZoomLodLevels,
+        &LookTransform,
+        Option<&Smoother>,
+        Option<&Projection>,
+    )>,
+) {
+    for (camera, mut levels, look_transform, smoother, projection) in cameras.iter_mut() {
+        let transform = smoother
+            .and_then(Smoother::smoothed_transform)
+            .unwrap_or(*look_transform);
+        let zoom = match projection {
+            Some(Projection::Orthographic(_)) => transform.scale,
+            _ => transform.radius(),
+        };
+
+        let level = levels.next_level(zoom);
+        if levels.level != Some(level) {
+            lod_events.send(ZoomLodChanged {
+                camera,
+                previous: levels.level,
+                level,
+            });
+            levels.level = Some(level);
+        }
+    }
+}
+
+// ████████╗███████╗███████╗████████╗
+// ╚══██╔══╝██╔════╝██╔════╝╚══██╔══╝
+//    ██║   █████╗  ███████╗   ██║
+//    ██║   ██╔══╝  ╚════██║   ██║
+//    ██║   ███████╗███████║   ██║
+//    ╚═╝   ╚══════╝╚══════╝   ╚═╝
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_initial_level() {
+        let levels = ZoomLodLevels::new(vec![10.0, 100.0], 0.1);
+        assert_eq!(levels.next_level(5.0), 0);
+        assert_eq!(levels.next_level(50.0), 1);
+        assert_eq!(levels.next_level(500.0), 2);
+    }
+
+    #[test]
+    fn test_hysteresis() {
+        let mut levels = ZoomLodLevels::new(vec![10.0, 100.0], 0.1);
+        levels.level = Some(0);
+
+        // Just past the threshold isn't enough.
+        assert_eq!(levels.next_level(10.5), 0);
+        assert_eq!(levels.next_level(11.5), 1);
+
+        levels.level = Some(1);
+        assert_eq!(levels.next_level(9.5), 1);
+        assert_eq!(levels.next_level(8.5), 0);
+    }
+}
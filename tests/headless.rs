@@ -0,0 +1,128 @@
+//! Runs every example scenario without a window, driving the default input maps with synthetic mouse and keyboard input.
+//! Bevy upgrades have historically broken the controllers at runtime, which these tests catch without opening a window.
+
+#[path = "../examples/common/mod.rs"]
+mod common;
+
+use bevy::{
+    input::{
+        keyboard::KeyboardInput,
+        mouse::{MouseButtonInput, MouseMotion, MouseScrollUnit, MouseWheel},
+        ButtonState, InputPlugin,
+    },
+    prelude::*,
+};
+use common::Scenario;
+use smooth_bevy_cameras::prelude::*;
+
+const FRAMES: usize = 120;
+
+/// Held and released in turns, covering the bindings of all controllers.
+const BUTTONS: [MouseButton; 3] = [MouseButton::Left, MouseButton::Right, MouseButton::Middle];
+const KEYS: [KeyCode; 8] = [
+    KeyCode::W,
+    KeyCode::A,
+    KeyCode::Q,
+    KeyCode::E,
+    KeyCode::Space,
+    KeyCode::LShift,
+    KeyCode::LControl,
+    KeyCode::C,
+];
+
+fn headless_app(scenario: Scenario, with_input: bool) -> App {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins)
+        .add_plugin(TransformPlugin)
+        .add_plugin(HierarchyPlugin);
+    if with_input {
+        app.add_plugin(InputPlugin);
+    }
+    app.add_plugin(LookTransformPlugin);
+    scenario.add_plugins(&mut app);
+    app.add_startup_system(move |mut commands: Commands| scenario.spawn_camera(&mut commands));
+
+    app
+}
+
+/// Sends one frame of synthetic input and updates the app.
+fn step(app: &mut App, frame: usize) {
+    // Switch between holding and releasing everything every 10 frames, so gestures start and end.
+    let state = if frame % 20 < 10 {
+        ButtonState::Pressed
+    } else {
+        ButtonState::Released
+    };
+
+    let world = &mut app.world;
+    for button in BUTTONS {
+        world
+            .resource_mut::<Events<MouseButtonInput>>()
+            .send(MouseButtonInput { button, state });
+    }
+    for key_code in KEYS {
+        world
+            .resource_mut::<Events<KeyboardInput>>()
+            .send(KeyboardInput {
+                scan_code: 0,
+                key_code: Some(key_code),
+                state,
+            });
+    }
+    world
+        .resource_mut::<Events<MouseMotion>>()
+        .send(MouseMotion {
+            delta: Vec2::new(7.0, -3.0),
+        });
+    world.resource_mut::<Events<MouseWheel>>().send(MouseWheel {
+        unit: MouseScrollUnit::Line,
+        x: 0.0,
+        y: if frame % 40 < 20 { 1.0 } else { -1.0 },
+    });
+
+    app.update();
+}
+
+fn assert_sane(app: &mut App, scenario: Scenario) {
+    let mut cameras = app.world.query::<(&LookTransform, &Transform)>();
+    let mut count = 0;
+    for (look_transform, transform) in cameras.iter(&app.world) {
+        count += 1;
+        assert!(
+            look_transform.eye.is_finite() && look_transform.target.is_finite(),
+            "{scenario:?}: {look_transform:?}"
+        );
+        assert!(
+            look_transform.radius() > 0.0,
+            "{scenario:?}: eye and target coincide"
+        );
+        assert!(
+            transform.translation.is_finite() && transform.rotation.is_normalized(),
+            "{scenario:?}: {transform:?}"
+        );
+    }
+    assert_eq!(count, 1, "{scenario:?}: expected exactly one camera");
+}
+
+#[test]
+fn test_scenarios_survive_synthetic_input() {
+    for scenario in Scenario::ALL {
+        let mut app = headless_app(scenario, true);
+        for frame in 0..FRAMES {
+            step(&mut app, frame);
+        }
+        assert_sane(&mut app, scenario);
+    }
+}
+
+#[test]
+fn test_scenarios_run_without_input() {
+    // Without bevy's `InputPlugin`, the default input maps are skipped and the controllers only follow `ControlEvent`s.
+    for scenario in Scenario::ALL {
+        let mut app = headless_app(scenario, false);
+        for _ in 0..10 {
+            app.update();
+        }
+        assert_sane(&mut app, scenario);
+    }
+}